@@ -7,6 +7,8 @@
 
 #![warn(missing_docs)]
 
+use regex::Regex;
+use ruma_common::{RoomAliasId, RoomId, UserId};
 use serde::{Deserialize, Serialize};
 
 pub mod event;
@@ -32,6 +34,19 @@ impl Namespace {
     pub fn new(exclusive: bool, regex: String) -> Self {
         Namespace { exclusive, regex }
     }
+
+    /// Compiles the regex of this namespace, anchored at the start of the value it is matched
+    /// against.
+    fn compile(&self) -> Result<CompiledNamespace, RegistrationValidationError> {
+        let regex = Regex::new(&format!("^(?:{})", self.regex)).map_err(|error| {
+            RegistrationValidationError::InvalidNamespaceRegex {
+                regex: self.regex.clone(),
+                message: error.to_string(),
+            }
+        })?;
+
+        Ok(CompiledNamespace { exclusive: self.exclusive, regex })
+    }
 }
 
 /// Namespaces defined by an application service.
@@ -59,6 +74,77 @@ impl Namespaces {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Compiles the regexes of all the namespaces, to check whether IDs match them.
+    ///
+    /// Returns an error if one of the regexes doesn't compile.
+    pub fn compile(&self) -> Result<CompiledNamespaces, RegistrationValidationError> {
+        fn compile_all(
+            namespaces: &[Namespace],
+        ) -> Result<Vec<CompiledNamespace>, RegistrationValidationError> {
+            namespaces.iter().map(Namespace::compile).collect()
+        }
+
+        Ok(CompiledNamespaces {
+            users: compile_all(&self.users)?,
+            aliases: compile_all(&self.aliases)?,
+            rooms: compile_all(&self.rooms)?,
+        })
+    }
+}
+
+/// [`Namespaces`] with compiled regexes.
+///
+/// A regex of a namespace matches an ID if it matches from the start of the ID.
+///
+/// To create an instance of this type, use [`Namespaces::compile()`].
+#[derive(Clone, Debug)]
+pub struct CompiledNamespaces {
+    users: Vec<CompiledNamespace>,
+    aliases: Vec<CompiledNamespace>,
+    rooms: Vec<CompiledNamespace>,
+}
+
+impl CompiledNamespaces {
+    /// Whether the given user ID matches any of the `users` namespaces.
+    pub fn matches_user(&self, user_id: &UserId) -> bool {
+        any_matches(&self.users, user_id.as_str(), false)
+    }
+
+    /// Whether the given user ID matches any of the exclusive `users` namespaces.
+    pub fn exclusively_matches_user(&self, user_id: &UserId) -> bool {
+        any_matches(&self.users, user_id.as_str(), true)
+    }
+
+    /// Whether the given room alias matches any of the `aliases` namespaces.
+    pub fn matches_room_alias(&self, alias: &RoomAliasId) -> bool {
+        any_matches(&self.aliases, alias.as_str(), false)
+    }
+
+    /// Whether the given room alias matches any of the exclusive `aliases` namespaces.
+    pub fn exclusively_matches_room_alias(&self, alias: &RoomAliasId) -> bool {
+        any_matches(&self.aliases, alias.as_str(), true)
+    }
+
+    /// Whether the given room ID matches any of the `rooms` namespaces.
+    pub fn matches_room_id(&self, room_id: &RoomId) -> bool {
+        any_matches(&self.rooms, room_id.as_str(), false)
+    }
+
+    /// Whether the given room ID matches any of the exclusive `rooms` namespaces.
+    pub fn exclusively_matches_room_id(&self, room_id: &RoomId) -> bool {
+        any_matches(&self.rooms, room_id.as_str(), true)
+    }
+}
+
+#[derive(Clone, Debug)]
+struct CompiledNamespace {
+    exclusive: bool,
+    regex: Regex,
+}
+
+fn any_matches(namespaces: &[CompiledNamespace], value: &str, exclusive_only: bool) -> bool {
+    namespaces.iter().any(|ns| (!exclusive_only || ns.exclusive) && ns.regex.is_match(value))
 }
 
 /// Information required in the registration yaml file that a homeserver needs.
@@ -101,6 +187,104 @@ pub struct Registration {
     pub protocols: Option<Vec<String>>,
 }
 
+impl Registration {
+    /// Parses and validates a `Registration` from the contents of a registration YAML file.
+    ///
+    /// This is equivalent to deserializing the YAML with `serde_yaml` and calling
+    /// [`Registration::validate()`] on the result.
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml(s: &str) -> Result<Self, RegistrationError> {
+        let registration: Self = serde_yaml::from_str(s)?;
+        registration.validate()?;
+        Ok(registration)
+    }
+
+    /// Checks that this registration can be used by a homeserver.
+    ///
+    /// This makes sure that:
+    ///
+    /// * `as_token` and `hs_token` are not empty, their length is not checked otherwise,
+    /// * `url`, if set, is an absolute `http` or `https` URL,
+    /// * the regexes of all the namespaces compile.
+    pub fn validate(&self) -> Result<(), RegistrationValidationError> {
+        if self.as_token.is_empty() {
+            return Err(RegistrationValidationError::EmptyAsToken);
+        }
+        if self.hs_token.is_empty() {
+            return Err(RegistrationValidationError::EmptyHsToken);
+        }
+
+        if let Some(url) = &self.url {
+            let parsed =
+                url::Url::parse(url).map_err(|error| RegistrationValidationError::InvalidUrl {
+                    url: url.clone(),
+                    message: error.to_string(),
+                })?;
+
+            if !matches!(parsed.scheme(), "http" | "https") {
+                return Err(RegistrationValidationError::InvalidUrlScheme(
+                    parsed.scheme().to_owned(),
+                ));
+            }
+        }
+
+        self.namespaces.compile()?;
+
+        Ok(())
+    }
+}
+
+/// An error encountered when loading a [`Registration`] from YAML.
+#[cfg(feature = "yaml")]
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum RegistrationError {
+    /// The YAML could not be deserialized into a `Registration`.
+    #[error("failed to deserialize registration: {0}")]
+    Yaml(#[from] serde_yaml::Error),
+
+    /// The registration was deserialized but is not valid.
+    #[error(transparent)]
+    Validation(#[from] RegistrationValidationError),
+}
+
+/// An error encountered when validating a [`Registration`].
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum RegistrationValidationError {
+    /// The `as_token` is empty.
+    #[error("as_token must not be empty")]
+    EmptyAsToken,
+
+    /// The `hs_token` is empty.
+    #[error("hs_token must not be empty")]
+    EmptyHsToken,
+
+    /// The `url` could not be parsed as an absolute URL.
+    #[error("invalid url `{url}`: {message}")]
+    InvalidUrl {
+        /// The URL that failed to parse.
+        url: String,
+
+        /// The error message of the URL parser.
+        message: String,
+    },
+
+    /// The `url` uses a scheme other than `http` or `https`.
+    #[error("url scheme must be `http` or `https`, found `{0}`")]
+    InvalidUrlScheme(String),
+
+    /// The regex of a namespace does not compile.
+    #[error("invalid namespace regex `{regex}`: {message}")]
+    InvalidNamespaceRegex {
+        /// The regex that failed to compile.
+        regex: String,
+
+        /// The error message of the regex compiler.
+        message: String,
+    },
+}
+
 /// Initial set of fields of `Registration`.
 ///
 /// This struct will not be updated even if additional fields are added to `Registration` in a new
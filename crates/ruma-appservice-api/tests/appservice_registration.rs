@@ -1,5 +1,6 @@
 use assert_matches2::assert_matches;
-use ruma_appservice_api::Registration;
+use ruma_appservice_api::{Namespace, Namespaces, Registration, RegistrationValidationError};
+use ruma_common::{room_alias_id, room_id, user_id};
 
 #[test]
 fn registration_deserialization() {
@@ -61,3 +62,188 @@ fn config_with_optional_url() {
     assert_matches!(serde_yaml::from_str(registration_config).unwrap(), Registration { url, .. });
     assert_eq!(url, None);
 }
+
+#[cfg(feature = "yaml")]
+mod yaml {
+    use assert_matches2::assert_matches;
+    use ruma_appservice_api::{Registration, RegistrationError, RegistrationValidationError};
+
+    const IRC_BRIDGE: &str = r##"
+        id: "IRC Bridge"
+        url: "http://127.0.0.1:1234"
+        as_token: "30c05ae90a248a4188e620216fa72e349803310ec83e2a77b34fe90be6081f46"
+        hs_token: "312df522183efd404ec1cd22d2ffa4bbc76a8c1ccf541dd692eef281356bb74e"
+        sender_localpart: "_irc_bot"
+        rate_limited: false
+        protocols: ["irc"]
+        namespaces:
+          users:
+            - exclusive: true
+              regex: "@_irc_bridge_.*"
+          aliases:
+            - exclusive: false
+              regex: "#_irc_bridge_.*"
+          rooms: []
+        "##;
+
+    #[test]
+    fn from_yaml_valid() {
+        let registration = Registration::from_yaml(IRC_BRIDGE).unwrap();
+
+        assert_eq!(registration.id, "IRC Bridge");
+        assert_eq!(registration.sender_localpart, "_irc_bot");
+        assert_eq!(registration.rate_limited, Some(false));
+        assert_eq!(registration.protocols.as_deref(), Some(&["irc".to_owned()][..]));
+        assert_eq!(registration.namespaces.users[0].regex, "@_irc_bridge_.*");
+    }
+
+    #[test]
+    fn from_yaml_without_url() {
+        let config = r#"
+            id: "telegram"
+            url: null
+            as_token: "as_secret"
+            hs_token: "hs_secret"
+            sender_localpart: "telegrambot"
+            namespaces:
+              users:
+                - exclusive: true
+                  regex: "@telegram_[0-9]+:example\\.org"
+            "#;
+        let registration = Registration::from_yaml(config).unwrap();
+
+        assert_eq!(registration.url, None);
+        assert!(registration.namespaces.aliases.is_empty());
+    }
+
+    #[test]
+    fn from_yaml_perl_character_classes() {
+        let config = r#"
+            id: "discord"
+            url: "http://localhost:29334"
+            as_token: "as_secret"
+            hs_token: "hs_secret"
+            sender_localpart: "_discord_bot"
+            namespaces:
+              users:
+                - exclusive: true
+                  regex: "@_discord_\\d+:example\\.org"
+              aliases:
+                - exclusive: true
+                  regex: "(?i)#_discord_\\w+:example\\.org"
+            "#;
+        let registration = Registration::from_yaml(config).unwrap();
+
+        assert_eq!(registration.namespaces.users[0].regex, r"@_discord_\d+:example\.org");
+        assert_eq!(registration.namespaces.aliases[0].regex, r"(?i)#_discord_\w+:example\.org");
+    }
+
+    #[test]
+    fn from_yaml_invalid_yaml() {
+        assert_matches!(Registration::from_yaml("id: [unclosed"), Err(RegistrationError::Yaml(_)));
+        assert_matches!(
+            Registration::from_yaml("id: \"missing fields\""),
+            Err(RegistrationError::Yaml(_))
+        );
+    }
+
+    #[test]
+    fn from_yaml_empty_token() {
+        let config = IRC_BRIDGE
+            .replace("30c05ae90a248a4188e620216fa72e349803310ec83e2a77b34fe90be6081f46", "");
+        assert_matches!(
+            Registration::from_yaml(&config),
+            Err(RegistrationError::Validation(RegistrationValidationError::EmptyAsToken))
+        );
+    }
+
+    #[test]
+    fn from_yaml_invalid_url() {
+        let config = IRC_BRIDGE.replace("http://127.0.0.1:1234", "ftp://127.0.0.1:1234");
+        assert_matches!(
+            Registration::from_yaml(&config),
+            Err(RegistrationError::Validation(RegistrationValidationError::InvalidUrlScheme(
+                scheme
+            )))
+        );
+        assert_eq!(scheme, "ftp");
+
+        let config = IRC_BRIDGE.replace("http://127.0.0.1:1234", "127.0.0.1:1234");
+        assert_matches!(
+            Registration::from_yaml(&config),
+            Err(RegistrationError::Validation(RegistrationValidationError::InvalidUrl { .. }))
+        );
+    }
+
+    #[test]
+    fn from_yaml_invalid_regex() {
+        let config = IRC_BRIDGE.replace("@_irc_bridge_.*", "@_irc_bridge_(.*");
+        assert_matches!(
+            Registration::from_yaml(&config),
+            Err(RegistrationError::Validation(
+                RegistrationValidationError::InvalidNamespaceRegex { regex, .. }
+            ))
+        );
+        assert_eq!(regex, "@_irc_bridge_(.*");
+    }
+}
+
+#[test]
+fn namespace_matching() {
+    let registration_config = r##"
+        id: "IRC Bridge"
+        url: "http://127.0.0.1:1234"
+        as_token: "as_token"
+        hs_token: "hs_token"
+        sender_localpart: "_irc_bot"
+        namespaces:
+          users:
+            - exclusive: false
+              regex: "@_irc_.*:example\\.org"
+            - exclusive: true
+              regex: "@_irc_bridge_.*:example\\.org"
+          aliases:
+            - exclusive: false
+              regex: "#_irc_.*"
+          rooms:
+            - exclusive: true
+              regex: "!irc[a-z]+:example\\.org"
+        "##;
+    let registration: Registration = serde_yaml::from_str(registration_config).unwrap();
+    let namespaces = registration.namespaces.compile().unwrap();
+
+    let bridged = user_id!("@_irc_bridge_alice:example.org");
+    assert!(namespaces.matches_user(bridged));
+    assert!(namespaces.exclusively_matches_user(bridged));
+
+    let shared = user_id!("@_irc_alice:example.org");
+    assert!(namespaces.matches_user(shared));
+    assert!(!namespaces.exclusively_matches_user(shared));
+
+    let other = user_id!("@alice_irc_bridge_:example.org");
+    assert!(!namespaces.matches_user(other));
+    assert!(!namespaces.exclusively_matches_user(other));
+
+    let alias = room_alias_id!("#_irc_matrix:example.org");
+    assert!(namespaces.matches_room_alias(alias));
+    assert!(!namespaces.exclusively_matches_room_alias(alias));
+    assert!(!namespaces.matches_room_alias(room_alias_id!("#matrix:example.org")));
+
+    let room_id = room_id!("!ircabc:example.org");
+    assert!(namespaces.matches_room_id(room_id));
+    assert!(namespaces.exclusively_matches_room_id(room_id));
+    assert!(!namespaces.matches_room_id(room_id!("!ircabc:example.com")));
+}
+
+#[test]
+fn compile_invalid_namespace_regex() {
+    let mut namespaces = Namespaces::new();
+    namespaces.users.push(Namespace::new(true, "@_irc_bridge_.*".to_owned()));
+    namespaces.rooms.push(Namespace::new(true, "!irc(".to_owned()));
+
+    assert_matches!(
+        namespaces.compile(),
+        Err(RegistrationValidationError::InvalidNamespaceRegex { regex, .. })
+    );
+    assert_eq!(regex, "!irc(");
+}
@@ -13,7 +13,11 @@ pub mod v3 {
         serde::Raw,
         OwnedUserId,
     };
-    use ruma_events::{AnyGlobalAccountDataEventContent, GlobalAccountDataEventType};
+    use ruma_events::{
+        AnyGlobalAccountDataEventContent, GlobalAccountDataEventContent,
+        GlobalAccountDataEventType, StaticEventContent,
+    };
+    use serde::de::DeserializeOwned;
 
     const METADATA: Metadata = metadata! {
         method: GET,
@@ -57,6 +61,17 @@ pub mod v3 {
         pub fn new(user_id: OwnedUserId, event_type: GlobalAccountDataEventType) -> Self {
             Self { user_id, event_type }
         }
+
+        /// Creates a new `Request` with the given user ID, for the event type of `C`.
+        ///
+        /// The `account_data` of the response can then be deserialized with
+        /// [`Response::deserialize_content()`].
+        pub fn new_for<C>(user_id: OwnedUserId) -> Self
+        where
+            C: GlobalAccountDataEventContent + StaticEventContent,
+        {
+            Self::new(user_id, C::TYPE.into())
+        }
     }
 
     impl Response {
@@ -64,5 +79,82 @@ pub mod v3 {
         pub fn new(account_data: Raw<AnyGlobalAccountDataEventContent>) -> Self {
             Self { account_data }
         }
+
+        /// Deserialize the account data as the content type `C`.
+        ///
+        /// This is meant to be used with a response to a request created with
+        /// [`Request::new_for()`].
+        pub fn deserialize_content<C>(&self) -> serde_json::Result<C>
+        where
+            C: GlobalAccountDataEventContent + StaticEventContent + DeserializeOwned,
+        {
+            self.account_data.deserialize_as()
+        }
+    }
+}
+
+#[cfg(all(test, feature = "client"))]
+mod tests {
+    use assert_matches2::assert_matches;
+    use ruma_common::{
+        api::{IncomingResponse, MatrixVersion, OutgoingRequest, SendAccessToken},
+        owned_user_id,
+        push::Ruleset,
+    };
+    use ruma_events::{
+        ignored_user_list::IgnoredUserListEventContent, push_rules::PushRulesEventContent,
+    };
+    use serde_json::{json, to_vec as to_json_vec};
+
+    use super::v3::{Request, Response};
+
+    #[test]
+    fn serialize_typed_request() {
+        let req = Request::new_for::<PushRulesEventContent>(owned_user_id!("@alice:example.org"))
+            .try_into_http_request::<Vec<u8>>(
+                "https://homeserver.tld",
+                SendAccessToken::IfRequired("auth_tok"),
+                &[MatrixVersion::V1_1],
+            )
+            .unwrap();
+
+        assert_eq!(
+            req.uri(),
+            "https://homeserver.tld/_matrix/client/v3/user/@alice:example.org/account_data/m.push_rules"
+        );
+    }
+
+    #[test]
+    fn deserialize_push_rules_content() {
+        let body = json!({
+            "global": {
+                "content": [],
+                "override": [
+                    {
+                        "actions": [],
+                        "conditions": [],
+                        "default": true,
+                        "enabled": false,
+                        "rule_id": ".m.rule.master",
+                    },
+                ],
+                "room": [],
+                "sender": [],
+                "underride": [],
+            },
+        });
+        let response = Response::try_from_http_response(
+            http::Response::builder().body(to_json_vec(&body).unwrap()).unwrap(),
+        )
+        .unwrap();
+
+        let content = response.deserialize_content::<PushRulesEventContent>().unwrap();
+        let Ruleset { override_, .. } = content.global;
+        assert_eq!(override_.len(), 1);
+        let rule = override_.iter().next().unwrap();
+        assert_eq!(rule.rule_id, ".m.rule.master");
+        assert!(!rule.enabled);
+
+        assert_matches!(response.deserialize_content::<IgnoredUserListEventContent>(), Err(_));
     }
 }
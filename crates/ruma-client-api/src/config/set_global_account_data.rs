@@ -90,3 +90,40 @@ pub mod v3 {
         }
     }
 }
+
+#[cfg(all(test, feature = "client"))]
+mod tests {
+    use ruma_common::{
+        api::{MatrixVersion, OutgoingRequest, SendAccessToken},
+        owned_user_id,
+        push::Ruleset,
+    };
+    use ruma_events::push_rules::PushRulesEventContent;
+    use serde_json::{from_slice as from_json_slice, json, Value as JsonValue};
+
+    use super::v3::Request;
+
+    #[test]
+    fn serialize_push_rules_request() {
+        let content = PushRulesEventContent::new(Ruleset::new());
+        let req = Request::new(owned_user_id!("@alice:example.org"), &content)
+            .unwrap()
+            .try_into_http_request::<Vec<u8>>(
+                "https://homeserver.tld",
+                SendAccessToken::IfRequired("auth_tok"),
+                &[MatrixVersion::V1_1],
+            )
+            .unwrap();
+
+        assert_eq!(
+            req.uri(),
+            "https://homeserver.tld/_matrix/client/v3/user/@alice:example.org/account_data/m.push_rules"
+        );
+        assert_eq!(
+            from_json_slice::<JsonValue>(req.body()).unwrap(),
+            json!({
+                "global": {},
+            })
+        );
+    }
+}
@@ -100,3 +100,43 @@ pub mod v3 {
         }
     }
 }
+
+#[cfg(all(test, feature = "client"))]
+mod tests {
+    use ruma_common::{
+        api::{MatrixVersion, OutgoingRequest, SendAccessToken},
+        owned_event_id, owned_room_id, owned_user_id,
+    };
+    use ruma_events::fully_read::FullyReadEventContent;
+    use serde_json::{from_slice as from_json_slice, json, Value as JsonValue};
+
+    use super::v3::Request;
+
+    #[test]
+    fn serialize_fully_read_request() {
+        let content = FullyReadEventContent::new(owned_event_id!("$someplace:example.org"));
+        let req = Request::new(
+            owned_user_id!("@alice:example.org"),
+            owned_room_id!("!room:example.org"),
+            &content,
+        )
+        .unwrap()
+        .try_into_http_request::<Vec<u8>>(
+            "https://homeserver.tld",
+            SendAccessToken::IfRequired("auth_tok"),
+            &[MatrixVersion::V1_1],
+        )
+        .unwrap();
+
+        assert_eq!(
+            req.uri(),
+            "https://homeserver.tld/_matrix/client/v3/user/@alice:example.org/rooms/!room:example.org/account_data/m.fully_read"
+        );
+        assert_eq!(
+            from_json_slice::<JsonValue>(req.body()).unwrap(),
+            json!({
+                "event_id": "$someplace:example.org",
+            })
+        );
+    }
+}
@@ -102,6 +102,27 @@ pub mod v3 {
         pub fn new() -> Self {
             Default::default()
         }
+
+        /// The requested event, if it was returned.
+        pub fn requested_event(&self) -> Option<&Raw<AnyTimelineEvent>> {
+            self.event.as_ref()
+        }
+
+        /// Deserialize the events that happened just before the requested event, in
+        /// reverse-chronological order.
+        pub fn events_before_typed(
+            &self,
+        ) -> impl Iterator<Item = serde_json::Result<AnyTimelineEvent>> + '_ {
+            self.events_before.iter().map(Raw::deserialize)
+        }
+
+        /// Deserialize the events that happened just after the requested event, in chronological
+        /// order.
+        pub fn events_after_typed(
+            &self,
+        ) -> impl Iterator<Item = serde_json::Result<AnyTimelineEvent>> + '_ {
+            self.events_after.iter().map(Raw::deserialize)
+        }
     }
 
     fn default_limit() -> UInt {
@@ -112,4 +133,96 @@ pub mod v3 {
     fn is_default_limit(val: &UInt) -> bool {
         *val == default_limit()
     }
+
+    #[cfg(all(test, feature = "client"))]
+    mod tests {
+        use ruma_common::api::IncomingResponse as _;
+        use serde_json::{json, to_vec as to_json_vec};
+
+        use super::Response;
+
+        fn message_event(event_id: &str, body: &str) -> serde_json::Value {
+            json!({
+                "content": {
+                    "body": body,
+                    "msgtype": "m.text",
+                },
+                "event_id": event_id,
+                "origin_server_ts": 1_432_735_824_653_u64,
+                "room_id": "!room:example.org",
+                "sender": "@example:example.org",
+                "type": "m.room.message",
+            })
+        }
+
+        #[test]
+        fn deserialize_response() {
+            let body = json!({
+                "start": "t27-54_2_0_2",
+                "end": "t29-57_2_0_2",
+                "events_before": [
+                    message_event("$before2", "Before 2"),
+                    message_event("$before1", "Before 1"),
+                ],
+                "event": message_event("$requested", "Requested"),
+                "events_after": [
+                    message_event("$after1", "After 1"),
+                    {
+                        "content": {},
+                        "event_id": "$invalid",
+                        "type": "m.room.message",
+                    },
+                ],
+                "state": [
+                    {
+                        "content": {
+                            "creator": "@example:example.org",
+                            "m.federate": true,
+                            "room_version": "1",
+                        },
+                        "event_id": "$create",
+                        "origin_server_ts": 1_432_735_824_653_u64,
+                        "room_id": "!room:example.org",
+                        "sender": "@example:example.org",
+                        "state_key": "",
+                        "type": "m.room.create",
+                    },
+                ],
+            });
+            let response = Response::try_from_http_response(
+                http::Response::builder().body(to_json_vec(&body).unwrap()).unwrap(),
+            )
+            .unwrap();
+
+            assert_eq!(response.start.as_deref(), Some("t27-54_2_0_2"));
+            assert_eq!(response.end.as_deref(), Some("t29-57_2_0_2"));
+            assert_eq!(response.state.len(), 1);
+
+            let requested = response.requested_event().unwrap().deserialize().unwrap();
+            assert_eq!(requested.event_id(), "$requested");
+
+            let before = response
+                .events_before_typed()
+                .map(|ev| ev.unwrap().event_id().to_owned())
+                .collect::<Vec<_>>();
+            assert_eq!(before, ["$before2", "$before1"]);
+
+            let mut after = response.events_after_typed();
+            assert_eq!(after.next().unwrap().unwrap().event_id(), "$after1");
+            after.next().unwrap().unwrap_err();
+            assert!(after.next().is_none());
+        }
+
+        #[test]
+        fn deserialize_empty_response() {
+            let response = Response::try_from_http_response(
+                http::Response::builder().body(b"{}" as &[u8]).unwrap(),
+            )
+            .unwrap();
+
+            assert!(response.requested_event().is_none());
+            assert_eq!(response.events_before_typed().count(), 0);
+            assert_eq!(response.events_after_typed().count(), 0);
+        }
+    }
 }
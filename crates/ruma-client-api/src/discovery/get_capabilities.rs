@@ -118,6 +118,19 @@ impl Capabilities {
     pub fn iter(&self) -> CapabilitiesIter<'_> {
         CapabilitiesIter::new(self)
     }
+
+    /// Returns the stability of the given room version, if the server supports it.
+    pub fn supports_room_version(&self, version: &RoomVersionId) -> Option<RoomVersionStability> {
+        self.room_versions.available.get(version).cloned()
+    }
+
+    /// Returns the room version the server uses by default for new rooms.
+    ///
+    /// Returns `None` if the default room version is not listed in the available room versions.
+    pub fn recommended_room_version(&self) -> Option<&RoomVersionId> {
+        let default = &self.room_versions.default;
+        self.room_versions.available.contains_key(default).then_some(default)
+    }
 }
 
 impl<'a> IntoIterator for &'a Capabilities {
@@ -297,9 +310,10 @@ mod tests {
     use std::borrow::Cow;
 
     use assert_matches2::assert_matches;
-    use serde_json::json;
+    use ruma_common::RoomVersionId;
+    use serde_json::{from_value as from_json_value, json};
 
-    use super::Capabilities;
+    use super::{Capabilities, RoomVersionStability};
 
     #[test]
     fn capabilities_iter() -> serde_json::Result<()> {
@@ -340,4 +354,41 @@ mod tests {
         assert_matches!(caps_iter.next(), None);
         Ok(())
     }
+
+    #[test]
+    fn room_versions() {
+        let caps = from_json_value::<Capabilities>(json!({
+            "m.room_versions": {
+                "default": "10",
+                "available": {
+                    "9": "stable",
+                    "10": "stable",
+                    "org.example.unstable": "unstable",
+                },
+            },
+        }))
+        .unwrap();
+
+        assert_eq!(
+            caps.supports_room_version(&RoomVersionId::V10),
+            Some(RoomVersionStability::Stable)
+        );
+        assert_eq!(
+            caps.supports_room_version(&"org.example.unstable".try_into().unwrap()),
+            Some(RoomVersionStability::Unstable)
+        );
+        assert_eq!(caps.supports_room_version(&RoomVersionId::V11), None);
+        assert_eq!(caps.recommended_room_version(), Some(&RoomVersionId::V10));
+
+        let caps = from_json_value::<Capabilities>(json!({
+            "m.room_versions": {
+                "default": "11",
+                "available": {
+                    "10": "stable",
+                },
+            },
+        }))
+        .unwrap();
+        assert_eq!(caps.recommended_room_version(), None);
+    }
 }
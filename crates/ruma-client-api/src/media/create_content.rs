@@ -7,7 +7,9 @@ pub mod v3 {
     //!
     //! [spec]: https://spec.matrix.org/latest/client-server-api/#post_matrixmediav3upload
 
-    use http::header::CONTENT_TYPE;
+    use std::{error::Error, fmt};
+
+    use http::{header::CONTENT_TYPE, HeaderValue};
     use ruma_common::{
         api::{request, response, Metadata},
         metadata, OwnedMxcUri,
@@ -83,6 +85,22 @@ pub mod v3 {
                 generate_blurhash: false,
             }
         }
+
+        /// Creates a new `Request` with the given content type and file contents.
+        ///
+        /// Returns an error if the content type is not a well-formed MIME type.
+        pub fn from_bytes(
+            content_type: &str,
+            file: Vec<u8>,
+        ) -> Result<Self, InvalidContentTypeError> {
+            if !is_valid_mime_type(content_type) {
+                return Err(InvalidContentTypeError);
+            }
+
+            let mut request = Self::new(file);
+            request.content_type = Some(content_type.to_owned());
+            Ok(request)
+        }
     }
 
     impl Response {
@@ -95,4 +113,81 @@ pub mod v3 {
             }
         }
     }
+
+    /// An error that happens when the content type of a `Request` is not a well-formed MIME type.
+    #[derive(Debug)]
+    #[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+    pub struct InvalidContentTypeError;
+
+    impl fmt::Display for InvalidContentTypeError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "Content type is not a valid MIME type.")
+        }
+    }
+
+    impl Error for InvalidContentTypeError {}
+
+    /// Whether the given string is a MIME type of the form `type/subtype`, optionally followed by
+    /// parameters.
+    fn is_valid_mime_type(mime_type: &str) -> bool {
+        // See the allowed characters in RFC 6838.
+        fn is_restricted_name(name: &str) -> bool {
+            name.bytes().next().is_some_and(|b| b.is_ascii_alphanumeric())
+                && name.bytes().all(|b| b.is_ascii_alphanumeric() || b"!#$&-^_.+".contains(&b))
+        }
+
+        let essence = mime_type.split(';').next().unwrap_or_default().trim_end();
+        let Some((type_, subtype)) = essence.split_once('/') else {
+            return false;
+        };
+
+        is_restricted_name(type_)
+            && is_restricted_name(subtype)
+            && HeaderValue::from_str(mime_type).is_ok()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::Request;
+
+        #[test]
+        fn from_bytes_valid_content_type() {
+            for content_type in
+                ["image/png", "application/vnd.api+json", "text/plain; charset=utf-8"]
+            {
+                let request = Request::from_bytes(content_type, b"data".to_vec()).unwrap();
+                assert_eq!(request.content_type.as_deref(), Some(content_type));
+                assert_eq!(request.file, b"data");
+            }
+        }
+
+        #[test]
+        fn from_bytes_invalid_content_type() {
+            for content_type in
+                ["", "image", "image/", "/png", "image/png/apng", "im age/png", "image/png\n"]
+            {
+                Request::from_bytes(content_type, b"data".to_vec()).unwrap_err();
+            }
+        }
+
+        #[cfg(feature = "client")]
+        #[test]
+        fn serialize_request() {
+            use http::header::CONTENT_TYPE;
+            use ruma_common::api::{MatrixVersion, OutgoingRequest as _, SendAccessToken};
+
+            let file = vec![0; 1024];
+            let http_req = Request::from_bytes("application/octet-stream", file)
+                .unwrap()
+                .try_into_http_request::<Vec<u8>>(
+                    "https://homeserver.tld",
+                    SendAccessToken::IfRequired("auth_tok"),
+                    &[MatrixVersion::V1_1],
+                )
+                .unwrap();
+
+            assert_eq!(http_req.headers().get(CONTENT_TYPE).unwrap(), "application/octet-stream");
+            assert_eq!(http_req.body().len(), 1024);
+        }
+    }
 }
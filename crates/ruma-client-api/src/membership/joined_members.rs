@@ -12,7 +12,7 @@ pub mod v3 {
 
     use ruma_common::{
         api::{request, response, Metadata},
-        metadata, OwnedMxcUri, OwnedRoomId, OwnedUserId,
+        metadata, OwnedMxcUri, OwnedRoomId, OwnedUserId, UserId,
     };
     use serde::{Deserialize, Serialize};
 
@@ -37,8 +37,7 @@ pub mod v3 {
     /// Response type for the `joined_members` endpoint.
     #[response(error = crate::Error)]
     pub struct Response {
-        /// A list of the rooms the user is in, i.e.
-        /// the ID of each room in which the user has joined membership.
+        /// A map from the user ID to the information about each joined member of the room.
         pub joined: BTreeMap<OwnedUserId, RoomMember>,
     }
 
@@ -50,10 +49,15 @@ pub mod v3 {
     }
 
     impl Response {
-        /// Creates a new `Response` with the given joined rooms.
+        /// Creates a new `Response` with the given joined members.
         pub fn new(joined: BTreeMap<OwnedUserId, RoomMember>) -> Self {
             Self { joined }
         }
+
+        /// The display name of the given user, if they are a member of the room and have one.
+        pub fn display_name_for(&self, user_id: &UserId) -> Option<&str> {
+            self.joined.get(user_id)?.display_name.as_deref()
+        }
     }
 
     /// Information about a room member.
@@ -114,5 +118,39 @@ pub mod v3 {
                 assert_eq!(member.avatar_url, None);
             }
         }
+
+        #[cfg(feature = "client")]
+        #[test]
+        fn deserialize_response() {
+            use ruma_common::{api::IncomingResponse as _, user_id};
+            use serde_json::to_vec as to_json_vec;
+
+            use super::Response;
+
+            let body = json!({
+                "joined": {
+                    "@bar:example.com": {
+                        "avatar_url": "mxc://riot.ovh/printErCATzZijQsSDWorRaK",
+                        "display_name": "Bar",
+                    },
+                    "@baz:example.com": {},
+                },
+            });
+            let response = Response::try_from_http_response(
+                http::Response::builder().body(to_json_vec(&body).unwrap()).unwrap(),
+            )
+            .unwrap();
+
+            assert_eq!(response.joined.len(), 2);
+            let member = &response.joined[user_id!("@bar:example.com")];
+            assert_eq!(
+                member.avatar_url.as_deref(),
+                Some(mxc_uri!("mxc://riot.ovh/printErCATzZijQsSDWorRaK"))
+            );
+
+            assert_eq!(response.display_name_for(user_id!("@bar:example.com")), Some("Bar"));
+            assert_eq!(response.display_name_for(user_id!("@baz:example.com")), None);
+            assert_eq!(response.display_name_for(user_id!("@qux:example.com")), None);
+        }
     }
 }
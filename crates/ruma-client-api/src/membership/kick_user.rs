@@ -55,4 +55,46 @@ pub mod v3 {
             Self {}
         }
     }
+
+    #[cfg(all(test, feature = "client"))]
+    mod tests {
+        use assign::assign;
+        use ruma_common::{
+            api::{MatrixVersion, OutgoingRequest as _, SendAccessToken},
+            owned_room_id, owned_user_id,
+        };
+        use serde_json::{from_slice as from_json_slice, json, Value as JsonValue};
+
+        use super::Request;
+
+        #[test]
+        fn serialize_request_with_reason() {
+            let request = assign!(
+                Request::new(
+                    owned_room_id!("!room:matrix.org"),
+                    owned_user_id!("@cheeky_monkey:matrix.org"),
+                ),
+                { reason: Some("Telling unfunny jokes".to_owned()) }
+            );
+            let http_req = request
+                .try_into_http_request::<Vec<u8>>(
+                    "https://homeserver.tld",
+                    SendAccessToken::IfRequired("auth_tok"),
+                    &[MatrixVersion::V1_1],
+                )
+                .unwrap();
+
+            assert_eq!(
+                http_req.uri(),
+                "https://homeserver.tld/_matrix/client/v3/rooms/!room:matrix.org/kick"
+            );
+            assert_eq!(
+                from_json_slice::<JsonValue>(http_req.body()).unwrap(),
+                json!({
+                    "reason": "Telling unfunny jokes",
+                    "user_id": "@cheeky_monkey:matrix.org",
+                })
+            );
+        }
+    }
 }
@@ -7,6 +7,8 @@ pub mod v3 {
     //!
     //! [spec]: https://spec.matrix.org/latest/client-server-api/#put_matrixclientv3profileuseriddisplayname
 
+    use std::{error::Error, fmt};
+
     use ruma_common::{
         api::{request, response, Metadata},
         metadata, OwnedUserId,
@@ -44,6 +46,20 @@ pub mod v3 {
         pub fn new(user_id: OwnedUserId, displayname: Option<String>) -> Self {
             Self { user_id, displayname }
         }
+
+        /// The maximum length of the display name, in bytes.
+        pub const MAX_DISPLAYNAME_LENGTH: usize = 255;
+
+        /// Check that the display name, if any, is not longer than
+        /// [`Self::MAX_DISPLAYNAME_LENGTH`] bytes.
+        pub fn validate(&self) -> Result<(), DisplayNameTooLongError> {
+            match &self.displayname {
+                Some(displayname) if displayname.len() > Self::MAX_DISPLAYNAME_LENGTH => {
+                    Err(DisplayNameTooLongError)
+                }
+                _ => Ok(()),
+            }
+        }
     }
 
     impl Response {
@@ -52,4 +68,96 @@ pub mod v3 {
             Self {}
         }
     }
+
+    /// An error that happens when the display name of a `Request` is longer than
+    /// [`Request::MAX_DISPLAYNAME_LENGTH`] bytes.
+    #[derive(Debug)]
+    #[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+    pub struct DisplayNameTooLongError;
+
+    impl fmt::Display for DisplayNameTooLongError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "Display name exceeds {} bytes.", Request::MAX_DISPLAYNAME_LENGTH)
+        }
+    }
+
+    impl Error for DisplayNameTooLongError {}
+
+    #[cfg(test)]
+    mod tests {
+        use ruma_common::owned_user_id;
+
+        use super::Request;
+
+        #[test]
+        fn validate_displayname_length() {
+            let user_id = owned_user_id!("@alice:example.org");
+
+            Request::new(user_id.clone(), None).validate().unwrap();
+            Request::new(user_id.clone(), Some("Alice".to_owned())).validate().unwrap();
+            Request::new(user_id.clone(), Some("a".repeat(Request::MAX_DISPLAYNAME_LENGTH)))
+                .validate()
+                .unwrap();
+
+            Request::new(user_id.clone(), Some("a".repeat(Request::MAX_DISPLAYNAME_LENGTH + 1)))
+                .validate()
+                .unwrap_err();
+            // The length is in bytes, not characters.
+            Request::new(user_id, Some("é".repeat(128))).validate().unwrap_err();
+        }
+
+        #[cfg(feature = "client")]
+        #[test]
+        fn serialize_request() {
+            use ruma_common::api::{MatrixVersion, OutgoingRequest as _, SendAccessToken};
+            use serde_json::{from_slice as from_json_slice, json, Value as JsonValue};
+
+            let http_req =
+                Request::new(owned_user_id!("@alice:example.org"), Some("Alice".to_owned()))
+                    .try_into_http_request::<Vec<u8>>(
+                        "https://homeserver.tld",
+                        SendAccessToken::IfRequired("auth_tok"),
+                        &[MatrixVersion::V1_1],
+                    )
+                    .unwrap();
+
+            assert_eq!(
+                http_req.uri(),
+                "https://homeserver.tld/_matrix/client/v3/profile/@alice:example.org/displayname"
+            );
+            assert_eq!(
+                from_json_slice::<JsonValue>(http_req.body()).unwrap(),
+                json!({ "displayname": "Alice" })
+            );
+        }
+
+        #[cfg(feature = "server")]
+        #[test]
+        fn deserialize_request() {
+            use ruma_common::api::IncomingRequest as _;
+
+            let req = Request::try_from_http_request(
+                http::Request::builder()
+                    .method("PUT")
+                    .uri("https://homeserver.tld/_matrix/client/v3/profile/@alice:example.org/displayname")
+                    .body(br#"{ "displayname": "Alice" }"# as &[u8])
+                    .unwrap(),
+                &["@alice:example.org"],
+            )
+            .unwrap();
+            assert_eq!(req.user_id, "@alice:example.org");
+            assert_eq!(req.displayname.as_deref(), Some("Alice"));
+
+            let req = Request::try_from_http_request(
+                http::Request::builder()
+                    .method("PUT")
+                    .uri("https://homeserver.tld/_matrix/client/v3/profile/@alice:example.org/displayname")
+                    .body(b"{}" as &[u8])
+                    .unwrap(),
+                &["@alice:example.org"],
+            )
+            .unwrap();
+            assert_eq!(req.displayname, None);
+        }
+    }
 }
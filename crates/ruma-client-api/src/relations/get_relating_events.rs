@@ -144,4 +144,112 @@ pub mod v1 {
             Self { chunk, next_batch: None, prev_batch: None, recursion_depth: None }
         }
     }
+
+    #[cfg(test)]
+    mod tests {
+        #[cfg(feature = "client")]
+        #[test]
+        fn serialize_request() {
+            use js_int::uint;
+            use ruma_common::{
+                api::{Direction, MatrixVersion, OutgoingRequest as _, SendAccessToken},
+                owned_event_id, owned_room_id,
+            };
+
+            use super::Request;
+
+            let mut req =
+                Request::new(owned_room_id!("!room:example.org"), owned_event_id!("$parent"));
+            req.from = Some("page1".to_owned());
+            req.to = Some("page3".to_owned());
+            req.dir = Direction::Forward;
+            req.limit = Some(uint!(10));
+
+            let http_req = req
+                .try_into_http_request::<Vec<u8>>(
+                    "https://homeserver.tld",
+                    SendAccessToken::IfRequired("auth_tok"),
+                    &[MatrixVersion::V1_3],
+                )
+                .unwrap();
+            assert_eq!(
+                http_req.uri().to_string(),
+                "https://homeserver.tld/_matrix/client/v1/rooms/!room:example.org/relations/$parent?from=page1&dir=f&to=page3&limit=10"
+            );
+        }
+
+        #[cfg(feature = "server")]
+        #[test]
+        fn deserialize_request() {
+            use js_int::uint;
+            use ruma_common::api::{Direction, IncomingRequest as _};
+
+            use super::Request;
+
+            let req = Request::try_from_http_request(
+                http::Request::builder()
+                    .method("GET")
+                    .uri("https://homeserver.tld/_matrix/client/v1/rooms/!room:example.org/relations/$parent?from=page1&limit=10")
+                    .body(&[] as &[u8])
+                    .unwrap(),
+                &["!room:example.org", "$parent"],
+            )
+            .unwrap();
+
+            assert_eq!(req.room_id, "!room:example.org");
+            assert_eq!(req.event_id, "$parent");
+            assert_eq!(req.from.as_deref(), Some("page1"));
+            assert_eq!(req.to, None);
+            assert_eq!(req.dir, Direction::Backward);
+            assert_eq!(req.limit, Some(uint!(10)));
+            assert!(!req.recurse);
+        }
+
+        #[cfg(feature = "client")]
+        #[test]
+        fn deserialize_response() {
+            use assert_matches2::assert_matches;
+            use ruma_common::api::IncomingResponse as _;
+            use ruma_events::{AnyMessageLikeEvent, MessageLikeEvent};
+            use serde_json::{json, to_vec as to_json_vec};
+
+            use super::Response;
+
+            let body = json!({
+                "chunk": [
+                    {
+                        "content": {
+                            "body": "In reply",
+                            "msgtype": "m.text",
+                            "m.relates_to": {
+                                "rel_type": "m.thread",
+                                "event_id": "$parent",
+                            },
+                        },
+                        "event_id": "$reply",
+                        "origin_server_ts": 1_432_735_824_653_u64,
+                        "room_id": "!room:example.org",
+                        "sender": "@example:example.org",
+                        "type": "m.room.message",
+                    },
+                ],
+                "next_batch": "page2_token",
+                "prev_batch": "page1_token",
+            });
+            let response = Response::try_from_http_response(
+                http::Response::builder().body(to_json_vec(&body).unwrap()).unwrap(),
+            )
+            .unwrap();
+
+            assert_eq!(response.chunk.len(), 1);
+            assert_matches!(
+                response.chunk[0].deserialize().unwrap(),
+                AnyMessageLikeEvent::RoomMessage(MessageLikeEvent::Original(event))
+            );
+            assert_eq!(event.event_id, "$reply");
+            assert_eq!(response.next_batch.as_deref(), Some("page2_token"));
+            assert_eq!(response.prev_batch.as_deref(), Some("page1_token"));
+            assert_eq!(response.recursion_depth, None);
+        }
+    }
 }
@@ -165,4 +165,36 @@ pub mod v1 {
             Self { chunk, next_batch: None, prev_batch: None, recursion_depth: None }
         }
     }
+
+    #[cfg(all(test, feature = "client"))]
+    mod tests {
+        use ruma_common::{
+            api::{MatrixVersion, OutgoingRequest as _, SendAccessToken},
+            owned_event_id, owned_room_id,
+        };
+        use ruma_events::{relation::RelationType, TimelineEventType};
+
+        use super::Request;
+
+        #[test]
+        fn serialize_request() {
+            let req = Request::new(
+                owned_room_id!("!room:example.org"),
+                owned_event_id!("$parent"),
+                RelationType::Thread,
+                TimelineEventType::RoomMessage,
+            )
+            .try_into_http_request::<Vec<u8>>(
+                "https://homeserver.tld",
+                SendAccessToken::IfRequired("auth_tok"),
+                &[MatrixVersion::V1_3],
+            )
+            .unwrap();
+
+            assert_eq!(
+                req.uri().to_string(),
+                "https://homeserver.tld/_matrix/client/v1/rooms/!room:example.org/relations/$parent/m.thread/m.room.message"
+            );
+        }
+    }
 }
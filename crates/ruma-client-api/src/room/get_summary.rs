@@ -9,9 +9,12 @@ pub mod msc3266 {
     //!
     //! [MSC]: https://github.com/matrix-org/matrix-spec-proposals/pull/3266
 
+    use std::{error::Error, fmt};
+
     use js_int::UInt;
     use ruma_common::{
         api::{request, response, Metadata},
+        directory::{PublicRoomJoinRule, PublicRoomsChunk, PublicRoomsChunkInit},
         metadata,
         room::RoomType,
         space::SpaceRoomJoinRule,
@@ -132,4 +135,173 @@ pub mod msc3266 {
             }
         }
     }
+
+    impl TryFrom<Response> for PublicRoomsChunk {
+        type Error = UnsupportedJoinRuleError;
+
+        /// Convert the summary to a public rooms chunk.
+        ///
+        /// The fields that are not part of a `PublicRoomsChunk` are dropped.
+        ///
+        /// Returns an error if the join rule of the room is neither `public` nor `knock`, since
+        /// such a room cannot be listed in a public rooms directory.
+        fn try_from(value: Response) -> Result<Self, Self::Error> {
+            let Response {
+                room_id,
+                canonical_alias,
+                avatar_url,
+                guest_can_join,
+                name,
+                num_joined_members,
+                topic,
+                world_readable,
+                join_rule,
+                room_type,
+                ..
+            } = value;
+
+            let join_rule = match join_rule {
+                SpaceRoomJoinRule::Public => PublicRoomJoinRule::Public,
+                SpaceRoomJoinRule::Knock => PublicRoomJoinRule::Knock,
+                _ => return Err(UnsupportedJoinRuleError),
+            };
+
+            let mut chunk = PublicRoomsChunk::from(PublicRoomsChunkInit {
+                num_joined_members,
+                room_id,
+                world_readable,
+                guest_can_join,
+            });
+            chunk.canonical_alias = canonical_alias;
+            chunk.name = name;
+            chunk.topic = topic;
+            chunk.avatar_url = avatar_url;
+            chunk.join_rule = join_rule;
+            chunk.room_type = room_type;
+
+            Ok(chunk)
+        }
+    }
+
+    /// An error that happens when converting a room summary whose join rule cannot appear in a
+    /// public rooms directory to a `PublicRoomsChunk`.
+    #[derive(Debug)]
+    #[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+    pub struct UnsupportedJoinRuleError;
+
+    impl fmt::Display for UnsupportedJoinRuleError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "Join rule is not supported in a public rooms directory.")
+        }
+    }
+
+    impl Error for UnsupportedJoinRuleError {}
+
+    #[cfg(all(test, feature = "client"))]
+    mod tests {
+        use js_int::uint;
+        use ruma_common::{
+            api::{IncomingResponse as _, MatrixVersion, OutgoingRequest as _, SendAccessToken},
+            directory::{PublicRoomJoinRule, PublicRoomsChunk},
+            owned_room_alias_id, owned_server_name,
+            room::RoomType,
+            space::SpaceRoomJoinRule,
+            EventEncryptionAlgorithm, RoomVersionId,
+        };
+        use ruma_events::room::member::MembershipState;
+        use serde_json::{json, to_vec as to_json_vec};
+
+        use super::{Request, Response};
+
+        fn response() -> Response {
+            let body = json!({
+                "room_id": "!ol19s:bleecker.street",
+                "avatar_url": "mxc://bleecker.street/CHEDDARandBRIE",
+                "guest_can_join": false,
+                "name": "CHEESE",
+                "num_joined_members": 37,
+                "topic": "Tasty tasty cheese",
+                "world_readable": true,
+                "join_rule": "public",
+                "room_type": "m.space",
+                "membership": "invite",
+                "encryption": "m.megolm.v1.aes-sha2",
+                "room_version": "9001",
+            });
+
+            Response::try_from_http_response(
+                http::Response::builder().body(to_json_vec(&body).unwrap()).unwrap(),
+            )
+            .unwrap()
+        }
+
+        #[test]
+        fn serialize_request() {
+            let req = Request::new(
+                owned_room_alias_id!("#cheese:bleecker.street").into(),
+                vec![owned_server_name!("bleecker.street")],
+            )
+            .try_into_http_request::<Vec<u8>>(
+                "https://homeserver.tld",
+                SendAccessToken::None,
+                &[MatrixVersion::V1_1],
+            )
+            .unwrap();
+
+            assert_eq!(
+                req.uri().to_string(),
+                "https://homeserver.tld/_matrix/client/unstable/im.nheko.summary/rooms/%23cheese:bleecker.street/summary?via=bleecker.street"
+            );
+        }
+
+        #[test]
+        fn deserialize_response() {
+            let response = response();
+
+            assert_eq!(response.room_id, "!ol19s:bleecker.street");
+            assert_eq!(response.canonical_alias, None);
+            assert_eq!(
+                response.avatar_url.as_deref().map(|url| url.as_str()),
+                Some("mxc://bleecker.street/CHEDDARandBRIE")
+            );
+            assert!(!response.guest_can_join);
+            assert_eq!(response.name.as_deref(), Some("CHEESE"));
+            assert_eq!(response.num_joined_members, uint!(37));
+            assert_eq!(response.topic.as_deref(), Some("Tasty tasty cheese"));
+            assert!(response.world_readable);
+            assert_eq!(response.join_rule, SpaceRoomJoinRule::Public);
+            assert_eq!(response.room_type, Some(RoomType::Space));
+            assert_eq!(response.membership, Some(MembershipState::Invite));
+            assert_eq!(response.encryption, Some(EventEncryptionAlgorithm::MegolmV1AesSha2));
+            assert_eq!(response.room_version, Some(RoomVersionId::try_from("9001").unwrap()));
+        }
+
+        #[test]
+        fn response_to_public_rooms_chunk() {
+            let chunk = PublicRoomsChunk::try_from(response()).unwrap();
+
+            assert_eq!(chunk.room_id, "!ol19s:bleecker.street");
+            assert_eq!(chunk.canonical_alias, None);
+            assert_eq!(
+                chunk.avatar_url.as_deref().map(|url| url.as_str()),
+                Some("mxc://bleecker.street/CHEDDARandBRIE")
+            );
+            assert!(!chunk.guest_can_join);
+            assert_eq!(chunk.name.as_deref(), Some("CHEESE"));
+            assert_eq!(chunk.num_joined_members, uint!(37));
+            assert_eq!(chunk.topic.as_deref(), Some("Tasty tasty cheese"));
+            assert!(chunk.world_readable);
+            assert_eq!(chunk.join_rule, PublicRoomJoinRule::Public);
+            assert_eq!(chunk.room_type, Some(RoomType::Space));
+
+            let mut knock = response();
+            knock.join_rule = SpaceRoomJoinRule::Knock;
+            let chunk = PublicRoomsChunk::try_from(knock).unwrap();
+            assert_eq!(chunk.join_rule, PublicRoomJoinRule::Knock);
+
+            let mut invite = response();
+            invite.join_rule = SpaceRoomJoinRule::Invite;
+            PublicRoomsChunk::try_from(invite).unwrap_err();
+        }
+    }
 }
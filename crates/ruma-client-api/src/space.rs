@@ -4,10 +4,12 @@
 //!
 //! [spec]: https://spec.matrix.org/latest/client-server-api/#spaces
 
+use std::collections::{BTreeMap, BTreeSet};
+
 use js_int::UInt;
 use ruma_common::{
     room::RoomType, serde::Raw, space::SpaceRoomJoinRule, OwnedMxcUri, OwnedRoomAliasId,
-    OwnedRoomId,
+    OwnedRoomId, RoomId,
 };
 use ruma_events::space::child::HierarchySpaceChildEvent;
 use serde::{Deserialize, Serialize};
@@ -132,3 +134,170 @@ impl From<SpaceHierarchyRoomsChunkInit> for SpaceHierarchyRoomsChunk {
         }
     }
 }
+
+/// The hierarchy of a space, built from the rooms returned by the [`get_hierarchy`] endpoint.
+///
+/// The server already applies the `max_depth` and `suggested_only` parameters of the request, so
+/// this only follows the `m.space.child` events of the rooms it contains, starting at the root
+/// space. Children that are not part of the hierarchy, or that were removed from their parent, are
+/// ignored.
+#[derive(Clone, Debug)]
+pub struct SpaceHierarchy {
+    room_id: OwnedRoomId,
+    rooms: BTreeMap<OwnedRoomId, SpaceHierarchyRoomsChunk>,
+}
+
+impl SpaceHierarchy {
+    /// Creates an empty `SpaceHierarchy` for the space with the given room ID.
+    ///
+    /// Rooms can be added with the [`Extend`] implementation, for example with the `rooms` of each
+    /// [`get_hierarchy::v1::Response`] while paginating.
+    pub fn new(room_id: OwnedRoomId) -> Self {
+        Self { room_id, rooms: BTreeMap::new() }
+    }
+
+    /// The room ID of the root space of this hierarchy.
+    pub fn room_id(&self) -> &RoomId {
+        &self.room_id
+    }
+
+    /// All the rooms reachable from the root space, including itself.
+    ///
+    /// The hierarchy is traversed depth-first, with the children of each space sorted according to
+    /// [`HierarchySpaceChildEvent::cmp_order()`]. Each room is only returned once.
+    pub fn rooms(&self) -> impl Iterator<Item = &SpaceHierarchyRoomsChunk> {
+        self.traverse(&self.room_id).into_iter()
+    }
+
+    /// All the rooms reachable from the room with the given ID, including itself.
+    ///
+    /// The subtree is traversed in the same order as [`SpaceHierarchy::rooms()`]. Returns an empty
+    /// iterator if the room is not part of this hierarchy.
+    pub fn subtree_of(&self, room_id: &RoomId) -> impl Iterator<Item = &SpaceHierarchyRoomsChunk> {
+        self.traverse(room_id).into_iter()
+    }
+
+    /// Whether the room with the given ID is reachable from the root space.
+    pub fn is_in_space(&self, room_id: &RoomId) -> bool {
+        self.rooms().any(|room| room.room_id == room_id)
+    }
+
+    fn traverse(&self, room_id: &RoomId) -> Vec<&SpaceHierarchyRoomsChunk> {
+        let mut visited = BTreeSet::new();
+        let mut rooms = Vec::new();
+        let mut stack: Vec<_> = self.rooms.get(room_id).into_iter().collect();
+
+        while let Some(room) = stack.pop() {
+            if !visited.insert(&room.room_id) {
+                continue;
+            }
+            rooms.push(room);
+
+            let mut children: Vec<_> = room
+                .children_state
+                .iter()
+                .filter_map(|child| child.deserialize().ok())
+                .filter(|child| child.content.is_canonical())
+                .collect();
+            children.sort_by(HierarchySpaceChildEvent::cmp_order);
+
+            // Push in reverse order so the first child is visited first.
+            stack
+                .extend(children.iter().rev().filter_map(|child| self.rooms.get(&child.state_key)));
+        }
+
+        rooms
+    }
+}
+
+impl Extend<SpaceHierarchyRoomsChunk> for SpaceHierarchy {
+    fn extend<T: IntoIterator<Item = SpaceHierarchyRoomsChunk>>(&mut self, iter: T) {
+        self.rooms.extend(iter.into_iter().map(|room| (room.room_id.clone(), room)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ruma_common::{owned_room_id, room_id};
+    use serde_json::{from_value as from_json_value, json};
+
+    use super::{SpaceHierarchy, SpaceHierarchyRoomsChunk};
+
+    fn room(room_id: &str, children: &[(&str, Option<&str>)]) -> SpaceHierarchyRoomsChunk {
+        let children_state: Vec<_> = children
+            .iter()
+            .map(|(child_id, order)| {
+                json!({
+                    "content": {
+                        "via": ["example.org"],
+                        "order": order,
+                    },
+                    "origin_server_ts": 1_629_413_349,
+                    "sender": "@alice:example.org",
+                    "state_key": child_id,
+                    "type": "m.space.child",
+                })
+            })
+            .collect();
+
+        from_json_value(json!({
+            "room_id": room_id,
+            "num_joined_members": 1,
+            "world_readable": false,
+            "guest_can_join": false,
+            "children_state": children_state,
+        }))
+        .unwrap()
+    }
+
+    fn hierarchy() -> SpaceHierarchy {
+        let mut hierarchy = SpaceHierarchy::new(owned_room_id!("!root:example.org"));
+        hierarchy.extend([
+            room(
+                "!root:example.org",
+                &[
+                    ("!b:example.org", None),
+                    ("!subspace:example.org", Some("a")),
+                    ("!missing:example.org", None),
+                ],
+            ),
+            room("!subspace:example.org", &[("!a:example.org", None), ("!root:example.org", None)]),
+            room("!a:example.org", &[]),
+        ]);
+        hierarchy.extend([room("!b:example.org", &[]), room("!unrelated:example.org", &[])]);
+        hierarchy
+    }
+
+    #[test]
+    fn traversal_order() {
+        let hierarchy = hierarchy();
+
+        let room_ids: Vec<_> = hierarchy.rooms().map(|room| room.room_id.as_str()).collect();
+        assert_eq!(
+            room_ids,
+            ["!root:example.org", "!subspace:example.org", "!a:example.org", "!b:example.org"]
+        );
+
+        let room_ids: Vec<_> = hierarchy
+            .subtree_of(room_id!("!subspace:example.org"))
+            .map(|room| room.room_id.as_str())
+            .collect();
+        assert_eq!(
+            room_ids,
+            ["!subspace:example.org", "!a:example.org", "!root:example.org", "!b:example.org"]
+        );
+
+        assert_eq!(hierarchy.subtree_of(room_id!("!missing:example.org")).count(), 0);
+    }
+
+    #[test]
+    fn membership() {
+        let hierarchy = hierarchy();
+
+        assert!(hierarchy.is_in_space(room_id!("!root:example.org")));
+        assert!(hierarchy.is_in_space(room_id!("!a:example.org")));
+        assert!(hierarchy.is_in_space(room_id!("!b:example.org")));
+        assert!(!hierarchy.is_in_space(room_id!("!missing:example.org")));
+        assert!(!hierarchy.is_in_space(room_id!("!unrelated:example.org")));
+    }
+}
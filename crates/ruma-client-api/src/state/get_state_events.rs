@@ -7,13 +7,19 @@ pub mod v3 {
     //!
     //! [spec]: https://spec.matrix.org/latest/client-server-api/#get_matrixclientv3roomsroomidstate
 
+    use std::collections::BTreeMap;
+
     use ruma_common::{
         api::{request, response, Metadata},
         metadata,
         serde::Raw,
         OwnedRoomId,
     };
-    use ruma_events::AnyStateEvent;
+    use ruma_events::{
+        room::member::RoomMemberEventContent, AnyStateEvent, StateEventType, StaticEventContent,
+        StaticStateEventContent,
+    };
+    use serde::{de::DeserializeOwned, Deserialize};
 
     const METADATA: Metadata = metadata! {
         method: GET,
@@ -57,5 +63,153 @@ pub mod v3 {
         pub fn new(room_state: Vec<Raw<AnyStateEvent>>) -> Self {
             Self { room_state }
         }
+
+        /// Index the room state by event type and state key.
+        pub fn into_state_map(self) -> RoomStateMap {
+            self.room_state.into()
+        }
+    }
+
+    /// The state of a room, indexed by event type and state key.
+    ///
+    /// The events are only deserialized when they are accessed.
+    #[derive(Clone, Debug, Default)]
+    pub struct RoomStateMap {
+        events: BTreeMap<StateEventType, BTreeMap<String, Raw<AnyStateEvent>>>,
+    }
+
+    impl RoomStateMap {
+        /// Get the content of the state event with the type of `C` and the given state key.
+        ///
+        /// Returns `None` if there is no such event, or an error if its content could not be
+        /// deserialized, for example because the event was redacted.
+        pub fn get<C>(&self, state_key: &C::StateKey) -> Option<serde_json::Result<C>>
+        where
+            C: StaticStateEventContent + StaticEventContent + DeserializeOwned,
+        {
+            self.events
+                .get(&StateEventType::from(C::TYPE))?
+                .get(state_key.as_ref())
+                .map(deserialize_content)
+        }
+
+        /// Get the contents of all the `m.room.member` events.
+        pub fn members(
+            &self,
+        ) -> impl Iterator<Item = serde_json::Result<RoomMemberEventContent>> + '_ {
+            self.events
+                .get(&StateEventType::RoomMember)
+                .into_iter()
+                .flat_map(|events| events.values())
+                .map(deserialize_content)
+        }
+    }
+
+    impl From<Vec<Raw<AnyStateEvent>>> for RoomStateMap {
+        /// Index the given state events.
+        ///
+        /// Events whose type or state key can't be deserialized are ignored. If there are several
+        /// events with the same type and state key, the last one is kept.
+        fn from(room_state: Vec<Raw<AnyStateEvent>>) -> Self {
+            #[derive(Deserialize)]
+            struct StateEventKey {
+                #[serde(rename = "type")]
+                event_type: StateEventType,
+                state_key: String,
+            }
+
+            let mut events = BTreeMap::<_, BTreeMap<_, _>>::new();
+
+            for raw in room_state {
+                let Ok(key) = raw.deserialize_as::<StateEventKey>() else {
+                    continue;
+                };
+
+                events.entry(key.event_type).or_default().insert(key.state_key, raw);
+            }
+
+            Self { events }
+        }
+    }
+
+    fn deserialize_content<C: DeserializeOwned>(raw: &Raw<AnyStateEvent>) -> serde_json::Result<C> {
+        raw.get_field("content")?.ok_or_else(|| serde::de::Error::missing_field("content"))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use ruma_common::{owned_user_id, serde::Raw};
+        use ruma_events::{
+            room::{
+                create::RoomCreateEventContent,
+                member::{MembershipState, RoomMemberEventContent},
+                name::RoomNameEventContent,
+                topic::RoomTopicEventContent,
+            },
+            AnyStateEvent, EmptyStateKey,
+        };
+        use serde_json::{from_value as from_json_value, json};
+
+        use super::RoomStateMap;
+
+        fn state_event(
+            event_type: &str,
+            state_key: &str,
+            content: serde_json::Value,
+        ) -> serde_json::Value {
+            json!({
+                "content": content,
+                "event_id": "$event",
+                "origin_server_ts": 1,
+                "room_id": "!room:example.org",
+                "sender": "@alice:example.org",
+                "state_key": state_key,
+                "type": event_type,
+            })
+        }
+
+        fn state_map() -> RoomStateMap {
+            from_json_value::<Vec<Raw<AnyStateEvent>>>(json!([
+                state_event("m.room.name", "", json!({ "name": "Old name" })),
+                state_event("m.room.name", "", json!({ "name": "Room name" })),
+                state_event("m.room.topic", "", json!({})),
+                state_event("m.room.member", "@alice:example.org", json!({ "membership": "join" })),
+                state_event("m.room.member", "@bob:example.org", json!({ "membership": "invite" })),
+                { "type": "m.room.member" },
+            ]))
+            .unwrap()
+            .into()
+        }
+
+        #[test]
+        fn get_typed_state() {
+            let state = state_map();
+
+            let name = state.get::<RoomNameEventContent>(&EmptyStateKey).unwrap().unwrap();
+            assert_eq!(name.name, "Room name");
+
+            // Redacted events don't have the required fields.
+            state.get::<RoomTopicEventContent>(&EmptyStateKey).unwrap().unwrap_err();
+
+            let member = state
+                .get::<RoomMemberEventContent>(&owned_user_id!("@bob:example.org"))
+                .unwrap()
+                .unwrap();
+            assert_eq!(member.membership, MembershipState::Invite);
+
+            assert!(state
+                .get::<RoomMemberEventContent>(&owned_user_id!("@carl:example.org"))
+                .is_none());
+            assert!(state.get::<RoomCreateEventContent>(&EmptyStateKey).is_none());
+        }
+
+        #[test]
+        fn members() {
+            let memberships = state_map()
+                .members()
+                .map(|content| content.unwrap().membership)
+                .collect::<Vec<_>>();
+            assert_eq!(memberships, [MembershipState::Join, MembershipState::Invite]);
+        }
     }
 }
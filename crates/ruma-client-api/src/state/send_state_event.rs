@@ -57,6 +57,10 @@ pub mod v3 {
     impl Request {
         /// Creates a new `Request` with the given room id, state key and event content.
         ///
+        /// The type of the state key is checked against the [`StateEventContent::StateKey`] of
+        /// the content at compile time, e.g. the state key of an `m.room.member` event must be a
+        /// [`UserId`](ruma_common::UserId).
+        ///
         /// # Errors
         ///
         /// Since `Request` stores the request body in serialized form, this function can fail if
@@ -232,4 +236,39 @@ pub mod v3 {
             "https://server.tld/_matrix/client/v3/rooms/!room:server.tld/state/m.room.name/"
         );
     }
+
+    #[cfg(feature = "client")]
+    #[test]
+    fn serialize_with_user_id_state_key() {
+        use ruma_common::{
+            api::{MatrixVersion, OutgoingRequest as _, SendAccessToken},
+            owned_room_id, user_id,
+        };
+        use ruma_events::room::member::{MembershipState, RoomMemberEventContent};
+
+        let req = Request::new(
+            owned_room_id!("!room:server.tld"),
+            user_id!("@user:server.tld"),
+            &RoomMemberEventContent::new(MembershipState::Join),
+        )
+        .unwrap();
+        assert_eq!(req.event_type.to_string(), "m.room.member");
+        assert_eq!(req.state_key, "@user:server.tld");
+
+        let http_req = req
+            .try_into_http_request::<Vec<u8>>(
+                "https://server.tld",
+                SendAccessToken::IfRequired("access_token"),
+                &[MatrixVersion::V1_1],
+            )
+            .unwrap();
+        assert_eq!(
+            http_req.uri(),
+            "https://server.tld/_matrix/client/v3/rooms/!room:server.tld/state/m.room.member/@user:server.tld"
+        );
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(http_req.body()).unwrap(),
+            serde_json::json!({ "membership": "join" })
+        );
+    }
 }
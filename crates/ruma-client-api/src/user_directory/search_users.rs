@@ -107,4 +107,141 @@ pub mod v3 {
             Self { user_id, display_name: None, avatar_url: None }
         }
     }
+
+    #[cfg(all(test, any(feature = "client", feature = "server")))]
+    mod tests {
+        use serde_json::{from_slice as from_json_slice, json, Value as JsonValue};
+
+        #[cfg(feature = "client")]
+        #[test]
+        fn serialize_request() {
+            use js_int::uint;
+            use ruma_common::api::{MatrixVersion, OutgoingRequest as _, SendAccessToken};
+
+            use super::Request;
+
+            let mut req = Request::new("foo".to_owned());
+            let http_req = req
+                .clone()
+                .try_into_http_request::<Vec<u8>>(
+                    "https://homeserver.tld",
+                    SendAccessToken::IfRequired("auth_tok"),
+                    &[MatrixVersion::V1_1],
+                )
+                .unwrap();
+            assert_eq!(
+                http_req.uri(),
+                "https://homeserver.tld/_matrix/client/v3/user_directory/search"
+            );
+            assert_eq!(
+                from_json_slice::<JsonValue>(http_req.body()).unwrap(),
+                json!({ "search_term": "foo" })
+            );
+
+            req.limit = uint!(20);
+            let http_req = req
+                .try_into_http_request::<Vec<u8>>(
+                    "https://homeserver.tld",
+                    SendAccessToken::IfRequired("auth_tok"),
+                    &[MatrixVersion::V1_1],
+                )
+                .unwrap();
+            assert_eq!(
+                from_json_slice::<JsonValue>(http_req.body()).unwrap(),
+                json!({ "search_term": "foo", "limit": 20 })
+            );
+        }
+
+        #[cfg(feature = "server")]
+        #[test]
+        fn deserialize_request() {
+            use js_int::uint;
+            use ruma_common::api::IncomingRequest as _;
+            use serde_json::to_vec as to_json_vec;
+
+            use super::Request;
+
+            let req = Request::try_from_http_request(
+                http::Request::builder()
+                    .method("POST")
+                    .uri("https://homeserver.tld/_matrix/client/v3/user_directory/search")
+                    .body(to_json_vec(&json!({ "limit": 10, "search_term": "foo" })).unwrap())
+                    .unwrap(),
+                &[] as &[String],
+            )
+            .unwrap();
+            assert_eq!(req.search_term, "foo");
+            assert_eq!(req.limit, uint!(10));
+            assert_eq!(req.language, None);
+        }
+
+        #[cfg(feature = "client")]
+        #[test]
+        fn deserialize_response() {
+            use ruma_common::api::IncomingResponse as _;
+            use serde_json::to_vec as to_json_vec;
+
+            use super::Response;
+
+            let body = json!({
+                "limited": false,
+                "results": [
+                    {
+                        "avatar_url": "mxc://bar.com/foo",
+                        "display_name": "Foo",
+                        "user_id": "@foo:bar.com",
+                    },
+                    {
+                        "user_id": "@foobar:bar.com",
+                    },
+                ],
+            });
+            let response = Response::try_from_http_response(
+                http::Response::builder().body(to_json_vec(&body).unwrap()).unwrap(),
+            )
+            .unwrap();
+
+            assert!(!response.limited);
+            assert_eq!(response.results.len(), 2);
+            let user = &response.results[0];
+            assert_eq!(user.user_id, "@foo:bar.com");
+            assert_eq!(user.display_name.as_deref(), Some("Foo"));
+            assert_eq!(
+                user.avatar_url.as_deref().map(|url| url.as_str()),
+                Some("mxc://bar.com/foo")
+            );
+            let user = &response.results[1];
+            assert_eq!(user.user_id, "@foobar:bar.com");
+            assert_eq!(user.display_name, None);
+            assert_eq!(user.avatar_url, None);
+        }
+
+        #[cfg(feature = "server")]
+        #[test]
+        fn serialize_response() {
+            use ruma_common::{api::OutgoingResponse as _, owned_mxc_uri, owned_user_id};
+
+            use super::{Response, User};
+
+            let mut user = User::new(owned_user_id!("@foo:bar.com"));
+            user.display_name = Some("Foo".to_owned());
+            user.avatar_url = Some(owned_mxc_uri!("mxc://bar.com/foo"));
+            let http_response =
+                Response::new(vec![user], true).try_into_http_response::<Vec<u8>>().unwrap();
+
+            assert_eq!(
+                from_json_slice::<JsonValue>(http_response.body()).unwrap(),
+                json!({
+                    "limited": true,
+                    "results": [
+                        {
+                            "avatar_url": "mxc://bar.com/foo",
+                            "display_name": "Foo",
+                            "user_id": "@foo:bar.com",
+                        },
+                    ],
+                })
+            );
+        }
+    }
 }
@@ -737,6 +737,45 @@ impl MatrixVersion {
             | MatrixVersion::V1_11  => RoomVersionId::V10,
         }
     }
+
+    /// Whether the given feature is supported in this version of the Matrix specification.
+    ///
+    /// Features that are not stable yet are not supported by any version.
+    pub fn supports_feature(self, feature: MatrixFeature) -> bool {
+        feature.stabilized_in().is_some_and(|version| self.is_superset_of(version))
+    }
+}
+
+/// Features of the Matrix specification that were introduced after its first version.
+///
+/// Use [`MatrixVersion::supports_feature()`] to check whether a feature is supported in a given
+/// version.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+pub enum MatrixFeature {
+    /// Knocking on rooms, introduced in Matrix 1.1.
+    Knock,
+
+    /// Threads, introduced in Matrix 1.4.
+    Threads,
+
+    /// The media size limit, introduced in Matrix 1.7.
+    MediaSizeLimit,
+
+    /// Sliding sync, not stable yet.
+    SlidingSync,
+}
+
+impl MatrixFeature {
+    /// The version of the Matrix specification that introduced this feature, if it is stable.
+    pub fn stabilized_in(self) -> Option<MatrixVersion> {
+        match self {
+            Self::Knock => Some(MatrixVersion::V1_1),
+            Self::Threads => Some(MatrixVersion::V1_4),
+            Self::MediaSizeLimit => Some(MatrixVersion::V1_7),
+            Self::SlidingSync => None,
+        }
+    }
 }
 
 impl Display for MatrixVersion {
@@ -752,7 +791,7 @@ mod tests {
     use http::Method;
 
     use super::{
-        AuthScheme,
+        AuthScheme, MatrixFeature,
         MatrixVersion::{self, V1_0, V1_1, V1_2, V1_3},
         Metadata, VersionHistory,
     };
@@ -872,4 +911,19 @@ mod tests {
 
         assert_eq!(LIT, V1_0);
     }
+
+    #[test]
+    fn supports_feature() {
+        assert!(!V1_0.supports_feature(MatrixFeature::Knock));
+        assert!(V1_1.supports_feature(MatrixFeature::Knock));
+        assert!(MatrixVersion::V1_11.supports_feature(MatrixFeature::Knock));
+
+        assert!(!V1_3.supports_feature(MatrixFeature::Threads));
+        assert!(MatrixVersion::V1_4.supports_feature(MatrixFeature::Threads));
+
+        assert!(!MatrixVersion::V1_6.supports_feature(MatrixFeature::MediaSizeLimit));
+        assert!(MatrixVersion::V1_7.supports_feature(MatrixFeature::MediaSizeLimit));
+
+        assert!(!MatrixVersion::V1_11.supports_feature(MatrixFeature::SlidingSync));
+    }
 }
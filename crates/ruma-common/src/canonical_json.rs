@@ -198,7 +198,7 @@ pub fn redact_in_place(
 
     let mut old_event = mem::take(event);
 
-    for &key in allowed_event_keys_for(version) {
+    for &key in preserved_event_keys(version) {
         if let Some(value) = old_event.remove(key) {
             event.insert(key.to_owned(), value);
         }
@@ -276,9 +276,11 @@ fn object_retain_some_keys(
     Ok(())
 }
 
-/// The fields that are allowed to remain in an event during redaction depending on the room
-/// version.
-fn allowed_event_keys_for(version: &RoomVersionId) -> &'static [&'static str] {
+/// The top-level keys of an event that are preserved by redaction in the given room version.
+///
+/// All other top-level keys are removed by [`redact()`] and [`redact_in_place()`]. Which keys of
+/// the `content` are preserved depends on the type of the event.
+pub fn preserved_event_keys(version: &RoomVersionId) -> &'static [&'static str] {
     match version {
         RoomVersionId::V1
         | RoomVersionId::V2
@@ -508,7 +510,8 @@ mod tests {
     };
 
     use super::{
-        redact_in_place, to_canonical_value, try_from_json_map, value::CanonicalJsonValue,
+        preserved_event_keys, redact, redact_in_place, to_canonical_value, try_from_json_map,
+        value::CanonicalJsonValue,
     };
     use crate::RoomVersionId;
 
@@ -674,6 +677,70 @@ mod tests {
         );
     }
 
+    #[test]
+    fn redact_top_level_keys_v10_v11() {
+        let original_event = json!({
+            "content": {
+                "membership": "join"
+            },
+            "event_id": "$143273582443PhrSn:example.org",
+            "membership": "join",
+            "origin": "example.org",
+            "origin_server_ts": 1_432_735_824_653_u64,
+            "prev_state": [],
+            "room_id": "!jEsUZKDJdhlrceRyVU:example.org",
+            "sender": "@example:example.org",
+            "state_key": "@example:example.org",
+            "type": "m.room.member",
+            "unsigned": {
+                "age": 1234
+            }
+        });
+
+        assert!(preserved_event_keys(&RoomVersionId::V10).contains(&"origin"));
+        assert!(!preserved_event_keys(&RoomVersionId::V11).contains(&"origin"));
+
+        assert_matches!(
+            CanonicalJsonValue::try_from(original_event),
+            Ok(CanonicalJsonValue::Object(object))
+        );
+
+        let redacted_v10 = redact(object.clone(), &RoomVersionId::V10, None).unwrap();
+        assert_eq!(
+            to_json_value(redacted_v10).unwrap(),
+            json!({
+                "content": {
+                    "membership": "join"
+                },
+                "event_id": "$143273582443PhrSn:example.org",
+                "membership": "join",
+                "origin": "example.org",
+                "origin_server_ts": 1_432_735_824_653_u64,
+                "prev_state": [],
+                "room_id": "!jEsUZKDJdhlrceRyVU:example.org",
+                "sender": "@example:example.org",
+                "state_key": "@example:example.org",
+                "type": "m.room.member",
+            })
+        );
+
+        let redacted_v11 = redact(object, &RoomVersionId::V11, None).unwrap();
+        assert_eq!(
+            to_json_value(redacted_v11).unwrap(),
+            json!({
+                "content": {
+                    "membership": "join"
+                },
+                "event_id": "$143273582443PhrSn:example.org",
+                "origin_server_ts": 1_432_735_824_653_u64,
+                "room_id": "!jEsUZKDJdhlrceRyVU:example.org",
+                "sender": "@example:example.org",
+                "state_key": "@example:example.org",
+                "type": "m.room.member",
+            })
+        );
+    }
+
     #[test]
     fn redact_allowed_keys_none() {
         let original_event = json!({
@@ -136,7 +136,7 @@ impl Filter {
 
     /// Returns `true` if the filter is empty.
     pub fn is_empty(&self) -> bool {
-        self.generic_search_term.is_none()
+        self.generic_search_term.is_none() && self.room_types.is_empty()
     }
 }
 
@@ -223,12 +223,22 @@ where
     }
 }
 
+impl From<RoomType> for RoomTypeFilter {
+    fn from(room_type: RoomType) -> Self {
+        match room_type {
+            RoomType::Space => Self::Space,
+            _ => Self::_Custom(PrivOwnedStr(room_type.as_str().into())),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use assert_matches2::assert_matches;
     use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
 
     use super::{Filter, RoomNetwork, RoomTypeFilter};
+    use crate::room::RoomType;
 
     #[test]
     fn serialize_matrix_network_only() {
@@ -329,4 +339,26 @@ mod tests {
         assert_matches!(&filter.room_types[2], RoomTypeFilter::_Custom(_));
         assert_eq!(filter.room_types[2].as_str(), Some("custom_type"));
     }
+
+    #[test]
+    fn filter_is_empty() {
+        assert!(Filter::new().is_empty());
+
+        let mut filter = Filter::new();
+        filter.generic_search_term = Some("ruma".to_owned());
+        assert!(!filter.is_empty());
+
+        let mut filter = Filter::new();
+        filter.room_types = vec![RoomTypeFilter::Space];
+        assert!(!filter.is_empty());
+    }
+
+    #[test]
+    fn room_type_filter_from_room_type() {
+        assert_eq!(RoomTypeFilter::from(RoomType::Space), RoomTypeFilter::Space);
+
+        let filter = RoomTypeFilter::from(RoomType::from("custom_type"));
+        assert_matches!(&filter, RoomTypeFilter::_Custom(_));
+        assert_eq!(filter.as_str(), Some("custom_type"));
+    }
 }
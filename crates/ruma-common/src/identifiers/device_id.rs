@@ -38,6 +38,30 @@ impl DeviceId {
     pub fn new() -> OwnedDeviceId {
         Self::from_borrowed(&generate_localpart(8)).to_owned()
     }
+
+    /// Generates a random `DeviceId` of 10 uppercase ASCII letters and digits.
+    ///
+    /// This is the format that homeservers commonly use for the device IDs that they generate.
+    #[cfg(feature = "rand")]
+    pub fn generate() -> OwnedDeviceId {
+        use rand::Rng as _;
+
+        const CHARSET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+        let mut rng = rand::thread_rng();
+        let id: String =
+            (0..10).map(|_| char::from(CHARSET[rng.gen_range(0..CHARSET.len())])).collect();
+        id.into()
+    }
+
+    /// Whether the given string can reasonably be used as a device ID.
+    ///
+    /// Device IDs are opaque to the Matrix specification, so this only checks that the string is
+    /// not empty, is at most 255 bytes long and consists of printable ASCII characters other than
+    /// space.
+    pub fn is_valid(s: &str) -> bool {
+        !s.is_empty() && s.len() <= 255 && s.bytes().all(|b| b.is_ascii_graphic())
+    }
 }
 
 #[cfg(test)]
@@ -50,6 +74,32 @@ mod tests {
         assert_eq!(DeviceId::new().as_str().len(), 8);
     }
 
+    #[cfg(feature = "rand")]
+    #[test]
+    fn generate_uppercase_device_id() {
+        use std::collections::BTreeSet;
+
+        let ids: BTreeSet<_> = (0..10_000).map(|_| DeviceId::generate()).collect();
+        assert_eq!(ids.len(), 10_000);
+
+        for id in &ids {
+            assert_eq!(id.as_str().len(), 10);
+            assert!(id.as_str().bytes().all(|b| b.is_ascii_uppercase() || b.is_ascii_digit()));
+            assert!(DeviceId::is_valid(id.as_str()));
+        }
+    }
+
+    #[test]
+    fn valid_device_id() {
+        assert!(DeviceId::is_valid("ABCDEFGHIJ"));
+        assert!(DeviceId::is_valid("abcdefgh"));
+        assert!(DeviceId::is_valid("device_1-2.3"));
+        assert!(!DeviceId::is_valid(""));
+        assert!(!DeviceId::is_valid("my device"));
+        assert!(!DeviceId::is_valid("appareil_é"));
+        assert!(!DeviceId::is_valid(&"A".repeat(256)));
+    }
+
     #[test]
     fn create_device_id_from_str() {
         let ref_id: &DeviceId = "abcdefgh".into();
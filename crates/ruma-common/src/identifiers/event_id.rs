@@ -52,6 +52,22 @@ impl EventId {
         Self::from_borrowed(&format!("${}:{server_name}", super::generate_localpart(18))).to_owned()
     }
 
+    /// Generates a random `EventId` in the format used by Matrix room versions 4 and later.
+    ///
+    /// The ID consists of 32 random bytes encoded with URL-safe unpadded base64, and has no server
+    /// name. Since these event IDs are normally a hash of the event, this is only useful for tests
+    /// and mocks.
+    #[cfg(feature = "rand")]
+    pub fn generate() -> OwnedEventId {
+        use rand::RngCore as _;
+
+        use crate::serde::{base64::UrlSafe, Base64};
+
+        let mut bytes = [0_u8; 32];
+        rand::thread_rng().fill_bytes(&mut bytes);
+        Self::from_borrowed(&format!("${}", Base64::<UrlSafe, _>::new(bytes))).to_owned()
+    }
+
     /// Returns the event's unique ID.
     ///
     /// For the original event format as used by Matrix room versions 1 and 2, this is the
@@ -69,6 +85,14 @@ impl EventId {
         self.colon_idx().map(|idx| ServerName::from_borrowed(&self.as_str()[idx + 1..]))
     }
 
+    /// Whether this event ID uses the original format as used by Matrix room versions 1 and 2.
+    ///
+    /// Event IDs in this format contain a server name after the localpart, unlike the hash-based
+    /// event IDs of later room versions.
+    pub fn is_historical(&self) -> bool {
+        self.colon_idx().is_some()
+    }
+
     fn colon_idx(&self) -> Option<usize> {
         self.as_str().find(':')
     }
@@ -105,6 +129,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn historical_event_id() {
+        let event_id = <&EventId>::try_from("$39hvsi03hlne:example.com").unwrap();
+        assert!(event_id.is_historical());
+        assert_eq!(event_id.localpart(), "39hvsi03hlne");
+        assert_eq!(event_id.server_name().map(|s| s.as_str()), Some("example.com"));
+
+        let event_id =
+            <&EventId>::try_from("$acR1l0raoZnm60CBwAVgqbZqoO/mYU81xysh1u7XcJk").unwrap();
+        assert!(!event_id.is_historical());
+        assert_eq!(event_id.server_name(), None);
+
+        let event_id =
+            <&EventId>::try_from("$Rqnc-F-dvnEYJTyHq_iKxU2bZ1CI92-kuZq3a5lr5Zg").unwrap();
+        assert!(!event_id.is_historical());
+        assert_eq!(event_id.localpart(), "Rqnc-F-dvnEYJTyHq_iKxU2bZ1CI92-kuZq3a5lr5Zg");
+        assert_eq!(event_id.server_name(), None);
+    }
+
     #[cfg(feature = "rand")]
     #[test]
     fn generate_random_valid_event_id() {
@@ -117,6 +160,19 @@ mod tests {
         assert_eq!(id_str.len(), 31);
     }
 
+    #[cfg(feature = "rand")]
+    #[test]
+    fn generate_random_valid_reference_hash_event_id() {
+        let event_id = EventId::generate();
+        let id_str = event_id.as_str();
+
+        assert!(id_str.starts_with('$'));
+        assert_eq!(id_str.len(), 44);
+        assert!(!event_id.is_historical());
+        assert_eq!(<&EventId>::try_from(id_str).unwrap(), event_id);
+        assert_ne!(EventId::generate(), event_id);
+    }
+
     #[test]
     fn serialize_valid_original_event_id() {
         assert_eq!(
@@ -19,6 +19,15 @@ type Result<T, E = MxcUriError> = std::result::Result<T, E>;
 pub struct MxcUri(str);
 
 impl MxcUri {
+    /// Creates an MXC URI from the given server name and media ID.
+    ///
+    /// Returns an error if the media ID contains characters that are not allowed.
+    pub fn from_parts(server_name: &ServerName, media_id: &str) -> Result<OwnedMxcUri> {
+        let uri = format!("mxc://{server_name}/{media_id}");
+        validate(&uri)?;
+        Ok(uri.into())
+    }
+
     /// If this is a valid MXC URI, returns the media ID.
     pub fn media_id(&self) -> Result<&str> {
         self.parts().map(|(_, s)| s)
@@ -63,6 +72,7 @@ mod tests {
     use ruma_identifiers_validation::error::MxcUriError;
 
     use super::{MxcUri, OwnedMxcUri};
+    use crate::server_name;
 
     #[test]
     fn parse_mxc_uri() {
@@ -109,4 +119,26 @@ mod tests {
             Ok(("server".try_into().expect("Failed to create ServerName"), "1234id"))
         );
     }
+
+    #[test]
+    fn mxc_uri_from_parts() {
+        let mxc = MxcUri::from_parts(server_name!("example.org"), "abc_DEF-123").unwrap();
+
+        assert_eq!(mxc.as_str(), "mxc://example.org/abc_DEF-123");
+        assert_eq!(mxc.server_name(), Ok(server_name!("example.org")));
+        assert_eq!(mxc.media_id(), Ok("abc_DEF-123"));
+
+        let mxc = MxcUri::from_parts(server_name!("127.0.0.1:8448"), "media").unwrap();
+        assert_eq!(mxc.parts(), Ok((server_name!("127.0.0.1:8448"), "media")));
+    }
+
+    #[test]
+    fn mxc_uri_from_parts_invalid_media_id() {
+        for media_id in ["with/slash", "with space", "emoji😀", "dot.dot", "../escape"] {
+            assert_eq!(
+                MxcUri::from_parts(server_name!("example.org"), media_id),
+                Err(MxcUriError::MediaIdMalformed)
+            );
+        }
+    }
 }
@@ -146,6 +146,16 @@ mod tests {
         assert_eq!(<&RoomAliasId>::try_from("#ruma").unwrap_err(), IdParseError::MissingColon);
     }
 
+    #[test]
+    #[cfg(not(feature = "compat-arbitrary-length-ids"))]
+    fn room_alias_id_too_long() {
+        let room_alias_id = format!("#{}:example.com", "a".repeat(255));
+        assert_eq!(
+            <&RoomAliasId>::try_from(room_alias_id.as_str()).unwrap_err(),
+            IdParseError::MaximumLengthExceeded
+        );
+    }
+
     #[test]
     fn invalid_leading_sigil() {
         assert_eq!(
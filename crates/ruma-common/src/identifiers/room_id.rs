@@ -249,6 +249,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn owned_room_id_serde_round_trip() {
+        let room_id = OwnedRoomId::try_from("!29fhd83h92h0:example.com").unwrap();
+        let json = serde_json::to_value(&room_id).unwrap();
+        assert_eq!(json, serde_json::json!("!29fhd83h92h0:example.com"));
+        assert_eq!(serde_json::from_value::<OwnedRoomId>(json).unwrap(), room_id);
+    }
+
+    #[test]
+    fn deserialize_invalid_room_id() {
+        let error = serde_json::from_str::<OwnedRoomId>(r#""29fhd83h92h0:example.com""#)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(error, IdParseError::MissingLeadingSigil.to_string());
+    }
+
     #[test]
     fn valid_room_id_with_explicit_standard_port() {
         let room_id =
@@ -266,6 +282,16 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(not(feature = "compat-arbitrary-length-ids"))]
+    fn room_id_too_long() {
+        let room_id = format!("!{}:example.com", "a".repeat(255));
+        assert_eq!(
+            <&RoomId>::try_from(room_id.as_str()).unwrap_err(),
+            IdParseError::MaximumLengthExceeded
+        );
+    }
+
     #[test]
     fn missing_room_id_sigil() {
         assert_eq!(
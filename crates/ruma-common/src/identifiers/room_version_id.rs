@@ -88,6 +88,34 @@ impl RoomVersionId {
     pub fn as_bytes(&self) -> &[u8] {
         self.as_str().as_bytes()
     }
+
+    /// Whether this is one of the stable room versions defined in the Matrix specification.
+    pub fn is_stable_version(&self) -> bool {
+        self.version_number().is_some()
+    }
+
+    /// Whether this is the latest stable room version known to Ruma.
+    pub fn is_latest_stable(&self) -> bool {
+        *self == Self::V11
+    }
+
+    /// The number of this room version, if it is a stable room version.
+    fn version_number(&self) -> Option<u8> {
+        match self {
+            Self::V1 => Some(1),
+            Self::V2 => Some(2),
+            Self::V3 => Some(3),
+            Self::V4 => Some(4),
+            Self::V5 => Some(5),
+            Self::V6 => Some(6),
+            Self::V7 => Some(7),
+            Self::V8 => Some(8),
+            Self::V9 => Some(9),
+            Self::V10 => Some(10),
+            Self::V11 => Some(11),
+            Self::_Custom(_) => None,
+        }
+    }
 }
 
 impl From<RoomVersionId> for String {
@@ -122,24 +150,29 @@ impl AsRef<[u8]> for RoomVersionId {
 }
 
 impl PartialOrd for RoomVersionId {
-    /// Compare the two given room version IDs by comparing their string representations.
+    /// Compare the two given room version IDs.
     ///
-    /// Please be aware that room version IDs don't have a defined ordering in the Matrix
-    /// specification. This implementation only exists to be able to use `RoomVersionId`s or
-    /// types containing `RoomVersionId`s as `BTreeMap` keys.
+    /// See the documentation of the `Ord` implementation for details.
     fn partial_cmp(&self, other: &RoomVersionId) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
 
 impl Ord for RoomVersionId {
-    /// Compare the two given room version IDs by comparing their string representations.
+    /// Compare the two given room version IDs.
+    ///
+    /// Room versions known to Ruma are ordered by their version number and are sorted before
+    /// custom room versions. Custom room versions are ordered by their string representation.
     ///
     /// Please be aware that room version IDs don't have a defined ordering in the Matrix
-    /// specification. This implementation only exists to be able to use `RoomVersionId`s or
-    /// types containing `RoomVersionId`s as `BTreeMap` keys.
+    /// specification, so a greater room version is not necessarily a newer or better one.
     fn cmp(&self, other: &Self) -> Ordering {
-        self.as_str().cmp(other.as_str())
+        match (self.version_number(), other.version_number()) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => self.as_str().cmp(other.as_str()),
+        }
     }
 }
 
@@ -394,4 +427,40 @@ mod tests {
             IdParseError::InvalidCharacters
         );
     }
+
+    #[test]
+    fn ordering() {
+        let versions = [
+            RoomVersionId::V1,
+            RoomVersionId::V2,
+            RoomVersionId::V3,
+            RoomVersionId::V4,
+            RoomVersionId::V5,
+            RoomVersionId::V6,
+            RoomVersionId::V7,
+            RoomVersionId::V8,
+            RoomVersionId::V9,
+            RoomVersionId::V10,
+            RoomVersionId::V11,
+            RoomVersionId::try_from("io.ruma.1").unwrap(),
+            RoomVersionId::try_from("io.ruma.2").unwrap(),
+        ];
+
+        for window in versions.windows(2) {
+            assert!(window[0] < window[1], "{} < {}", window[0], window[1]);
+        }
+        assert!(RoomVersionId::V9 < RoomVersionId::V10);
+        assert!(RoomVersionId::V10 < RoomVersionId::V11);
+    }
+
+    #[test]
+    fn stable_versions() {
+        assert!(RoomVersionId::V1.is_stable_version());
+        assert!(RoomVersionId::V11.is_stable_version());
+        assert!(!RoomVersionId::try_from("io.ruma.1").unwrap().is_stable_version());
+
+        assert!(RoomVersionId::V11.is_latest_stable());
+        assert!(!RoomVersionId::V10.is_latest_stable());
+        assert!(!RoomVersionId::try_from("12").unwrap().is_latest_stable());
+    }
 }
@@ -3,6 +3,7 @@
 use std::net::Ipv4Addr;
 
 use ruma_macros::IdZst;
+use wildmatch::WildMatch;
 
 /// A Matrix-spec compliant [server name].
 ///
@@ -49,6 +50,18 @@ impl ServerName {
     pub fn is_ip_literal(&self) -> bool {
         self.host().parse::<Ipv4Addr>().is_ok() || self.0.starts_with('[')
     }
+
+    /// Whether the host of this server name matches the given glob pattern of a server ACL.
+    ///
+    /// `*` matches zero or more characters and `?` matches exactly one character. The port, if
+    /// any, is ignored.
+    ///
+    /// See the [`m.room.server_acl`] event for details.
+    ///
+    /// [`m.room.server_acl`]: https://spec.matrix.org/latest/client-server-api/#mroomserver_acl
+    pub fn is_valid_acl_glob_match(&self, pattern: &str) -> bool {
+        WildMatch::new(pattern).matches(self.host())
+    }
 }
 
 #[cfg(test)]
@@ -152,4 +165,32 @@ mod tests {
         assert!(!server_name.is_ip_literal());
         assert_eq!(server_name.host(), "ruma.io");
     }
+
+    #[test]
+    fn port() {
+        assert_eq!(<&ServerName>::try_from("127.0.0.1").unwrap().port(), None);
+        assert_eq!(<&ServerName>::try_from("1.1.1.1:12000").unwrap().port(), Some(12000));
+        assert_eq!(<&ServerName>::try_from("[::1]").unwrap().port(), None);
+        assert_eq!(<&ServerName>::try_from("[1234:5678::abcd]:5678").unwrap().port(), Some(5678));
+        assert_eq!(<&ServerName>::try_from("example.com").unwrap().port(), None);
+        assert_eq!(<&ServerName>::try_from("ruma.io:8080").unwrap().port(), Some(8080));
+    }
+
+    #[test]
+    fn acl_glob_match() {
+        let server_name = <&ServerName>::try_from("matrix.example.com:8448").unwrap();
+        assert!(server_name.is_valid_acl_glob_match("*"));
+        assert!(server_name.is_valid_acl_glob_match("*.example.com"));
+        assert!(server_name.is_valid_acl_glob_match("matri?.example.com"));
+        assert!(!server_name.is_valid_acl_glob_match("example.com"));
+        assert!(!server_name.is_valid_acl_glob_match("matrix.example.com:8448"));
+
+        let server_name = <&ServerName>::try_from("10.0.0.1:8448").unwrap();
+        assert!(server_name.is_valid_acl_glob_match("10.0.0.*"));
+        assert!(!server_name.is_valid_acl_glob_match("10.0.1.*"));
+
+        let server_name = <&ServerName>::try_from("[::1]").unwrap();
+        assert!(server_name.is_valid_acl_glob_match("[::1]"));
+        assert!(server_name.is_valid_acl_glob_match("[*]"));
+    }
 }
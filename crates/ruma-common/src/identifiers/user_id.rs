@@ -311,6 +311,26 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(not(feature = "compat-user-id"))]
+    fn empty_user_id_localpart() {
+        assert_eq!(<&UserId>::try_from("@:example.com").unwrap_err(), IdParseError::Empty);
+        assert_eq!(
+            UserId::parse_with_server_name("", server_name!("example.com")).unwrap_err(),
+            IdParseError::Empty
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "compat-arbitrary-length-ids"))]
+    fn user_id_too_long() {
+        let user_id = format!("@{}:example.com", "a".repeat(255));
+        assert_eq!(
+            <&UserId>::try_from(user_id.as_str()).unwrap_err(),
+            IdParseError::MaximumLengthExceeded
+        );
+    }
+
     #[test]
     fn missing_user_id_sigil() {
         assert_eq!(
@@ -33,7 +33,7 @@ pub mod room;
 pub mod serde;
 pub mod space;
 pub mod thirdparty;
-mod time;
+pub mod time;
 pub mod to_device;
 
 use std::fmt;
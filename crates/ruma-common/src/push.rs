@@ -31,6 +31,7 @@ use crate::{
 mod action;
 mod condition;
 mod iter;
+mod notification_count;
 mod predefined;
 
 #[cfg(feature = "unstable-msc3932")]
@@ -43,6 +44,7 @@ pub use self::{
         _CustomPushCondition,
     },
     iter::{AnyPushRule, AnyPushRuleRef, RulesetIntoIter, RulesetIter},
+    notification_count::NotificationCount,
     predefined::{
         PredefinedContentRuleId, PredefinedOverrideRuleId, PredefinedRuleId,
         PredefinedUnderrideRuleId,
@@ -0,0 +1,157 @@
+//! Types to keep track of notification counts.
+
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+/// The number of unread notifications, as computed with the push rules.
+///
+/// The arithmetic operations on this type saturate instead of overflowing.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+pub struct NotificationCount {
+    /// The total number of unread notifications.
+    pub total: u64,
+
+    /// The number of unread notifications with the highlight flag set.
+    pub highlight: u64,
+}
+
+impl NotificationCount {
+    /// Creates a new `NotificationCount` with the given counts.
+    pub fn new(total: u64, highlight: u64) -> Self {
+        Self { total, highlight }
+    }
+
+    /// Whether there are no unread notifications.
+    pub fn is_zero(&self) -> bool {
+        self.total == 0 && self.highlight == 0
+    }
+
+    /// Count a new notification.
+    ///
+    /// If `is_highlighted` is `true`, it is also counted as a highlight.
+    pub fn add_event(&mut self, is_highlighted: bool) {
+        *self += Self::for_event(is_highlighted);
+    }
+
+    /// Remove a notification from the count, for example when it was read.
+    ///
+    /// If `is_highlighted` is `true`, it is also removed from the highlights.
+    pub fn remove_event(&mut self, is_highlighted: bool) {
+        *self -= Self::for_event(is_highlighted);
+    }
+
+    /// Add the given counts, saturating at the numeric bounds instead of overflowing.
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self {
+            total: self.total.saturating_add(rhs.total),
+            highlight: self.highlight.saturating_add(rhs.highlight),
+        }
+    }
+
+    /// Subtract the given counts, saturating at zero instead of overflowing.
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self {
+            total: self.total.saturating_sub(rhs.total),
+            highlight: self.highlight.saturating_sub(rhs.highlight),
+        }
+    }
+
+    fn for_event(is_highlighted: bool) -> Self {
+        Self::new(1, is_highlighted.into())
+    }
+}
+
+impl Add for NotificationCount {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.saturating_add(rhs)
+    }
+}
+
+impl AddAssign for NotificationCount {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.saturating_add(rhs);
+    }
+}
+
+impl Sub for NotificationCount {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.saturating_sub(rhs)
+    }
+}
+
+impl SubAssign for NotificationCount {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.saturating_sub(rhs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NotificationCount;
+
+    #[test]
+    fn is_zero() {
+        assert!(NotificationCount::default().is_zero());
+        assert!(NotificationCount::new(0, 0).is_zero());
+        assert!(!NotificationCount::new(1, 0).is_zero());
+        assert!(!NotificationCount::new(0, 1).is_zero());
+    }
+
+    #[test]
+    fn accumulate_events() {
+        let mut count = NotificationCount::default();
+
+        count.add_event(false);
+        count.add_event(true);
+        count.add_event(false);
+        assert_eq!(count, NotificationCount::new(3, 1));
+
+        count.remove_event(true);
+        assert_eq!(count, NotificationCount::new(2, 0));
+
+        count.remove_event(false);
+        count.remove_event(false);
+        assert!(count.is_zero());
+
+        // Removing more events than were added doesn't underflow.
+        count.remove_event(true);
+        assert!(count.is_zero());
+    }
+
+    #[test]
+    fn accumulate_rooms() {
+        let rooms = [
+            NotificationCount::new(3, 1),
+            NotificationCount::new(0, 0),
+            NotificationCount::new(5, 2),
+        ];
+
+        let total = rooms.into_iter().fold(NotificationCount::default(), |acc, c| acc + c);
+        assert_eq!(total, NotificationCount::new(8, 3));
+        assert_eq!(total - rooms[0], NotificationCount::new(5, 2));
+    }
+
+    #[test]
+    fn saturation() {
+        let max = NotificationCount::new(u64::MAX, u64::MAX);
+
+        assert_eq!(max + NotificationCount::new(1, 1), max);
+        assert_eq!(
+            NotificationCount::new(1, 0) - NotificationCount::new(2, 1),
+            NotificationCount::default()
+        );
+
+        let mut count = max;
+        count.add_event(true);
+        assert_eq!(count, max);
+
+        count -= max;
+        assert!(count.is_zero());
+        count -= NotificationCount::new(1, 1);
+        assert!(count.is_zero());
+    }
+}
@@ -13,7 +13,9 @@ mod buf;
 pub mod can_be_empty;
 mod cow;
 pub mod duration;
+pub mod json_bytes;
 pub mod json_string;
+pub mod lenient_bool;
 mod raw;
 pub mod single_element_seq;
 mod strings;
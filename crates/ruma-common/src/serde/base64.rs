@@ -150,7 +150,9 @@ impl std::error::Error for Base64DecodeError {}
 
 #[cfg(test)]
 mod tests {
-    use super::{Base64, Standard};
+    use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
+
+    use super::{Base64, Standard, UrlSafe};
 
     #[test]
     fn slightly_malformed_base64() {
@@ -162,4 +164,27 @@ mod tests {
         Base64::<Standard>::parse(INPUT_WITH_PADDING)
             .expect("We should be able to decode padded Base64");
     }
+
+    #[test]
+    fn serde_roundtrip() {
+        let bytes = vec![0, 1, 2, 250, 251, 252, 253, 254, 255];
+
+        let standard = Base64::<Standard>::new(bytes.clone());
+        let json = to_json_value(standard).unwrap();
+        assert_eq!(json, json!("AAEC+vv8/f7/"));
+        assert_eq!(from_json_value::<Base64<Standard>>(json).unwrap().as_bytes(), bytes);
+
+        let url_safe = Base64::<UrlSafe>::new(bytes.clone());
+        let json = to_json_value(url_safe).unwrap();
+        assert_eq!(json, json!("AAEC-vv8_f7_"));
+        assert_eq!(from_json_value::<Base64<UrlSafe>>(json).unwrap().as_bytes(), bytes);
+    }
+
+    #[test]
+    fn reject_invalid_base64() {
+        Base64::<Standard>::parse("not base64!").unwrap_err();
+        Base64::<Standard>::parse("AAEC-vv8_f7_").unwrap_err();
+        Base64::<UrlSafe>::parse("AAEC+vv8/f7/").unwrap_err();
+        from_json_value::<Base64<Standard>>(json!("€uro")).unwrap_err();
+    }
 }
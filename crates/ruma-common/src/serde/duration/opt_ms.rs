@@ -44,6 +44,7 @@ where
 mod tests {
     use std::time::Duration;
 
+    use js_int::UInt;
     use serde::{Deserialize, Serialize};
     use serde_json::json;
 
@@ -94,4 +95,29 @@ mod tests {
         let request = DurationTest { timeout: None };
         assert_eq!(serde_json::to_value(request).unwrap(), json!({}));
     }
+
+    #[test]
+    fn deserialize_max() {
+        let json = json!({ "timeout": UInt::MAX });
+
+        assert_eq!(
+            serde_json::from_value::<DurationTest>(json).unwrap(),
+            DurationTest { timeout: Some(Duration::from_millis(UInt::MAX.into())) },
+        );
+    }
+
+    #[test]
+    fn deserialize_overflow() {
+        let json = json!({ "timeout": u64::from(UInt::MAX) + 1 });
+        serde_json::from_value::<DurationTest>(json).unwrap_err();
+    }
+
+    #[test]
+    fn serialize_overflow() {
+        let request = DurationTest { timeout: Some(Duration::from_millis(UInt::MAX.into())) };
+        assert_eq!(serde_json::to_value(request).unwrap(), json!({ "timeout": UInt::MAX }));
+
+        let request = DurationTest { timeout: Some(Duration::MAX) };
+        serde_json::to_value(request).unwrap_err();
+    }
 }
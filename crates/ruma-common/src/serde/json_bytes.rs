@@ -0,0 +1,77 @@
+//! De-/serialization functions for `Vec<u8>` fields containing raw JSON.
+//!
+//! The bytes are (de)serialized as the JSON value they contain, instead of as a sequence of
+//! integers.
+
+use serde::{
+    de::{Deserialize, Deserializer},
+    ser::{Error as _, Serialize, Serializer},
+};
+use serde_json::value::RawValue as RawJsonValue;
+
+/// Serialize the given bytes as the JSON value they contain.
+///
+/// Will fail if the bytes are not valid UTF-8 or not valid JSON.
+pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let json = std::str::from_utf8(bytes).map_err(S::Error::custom)?;
+    let raw = RawJsonValue::from_string(json.to_owned()).map_err(S::Error::custom)?;
+    raw.serialize(serializer)
+}
+
+/// Deserialize any JSON value as its raw JSON bytes.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let raw = Box::<RawJsonValue>::deserialize(deserializer)?;
+    Ok(raw.get().as_bytes().to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use serde_json::{from_str as from_json_str, json, to_value as to_json_value};
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct JsonBytesTest {
+        #[serde(with = "super")]
+        payload: Vec<u8>,
+    }
+
+    #[test]
+    fn deserialize_valid_json() {
+        let test =
+            from_json_str::<JsonBytesTest>(r#"{ "payload": { "body": "hi", "n": [1, 2] } }"#)
+                .unwrap();
+        assert_eq!(test.payload, br#"{ "body": "hi", "n": [1, 2] }"#);
+    }
+
+    #[test]
+    fn deserialize_invalid_json() {
+        from_json_str::<JsonBytesTest>(r#"{ "payload": { "body": } }"#).unwrap_err();
+    }
+
+    #[test]
+    fn serialize_valid_json() {
+        let test = JsonBytesTest { payload: br#"{"body":"hi","n":[1,2]}"#.to_vec() };
+        assert_eq!(
+            to_json_value(test).unwrap(),
+            json!({ "payload": { "body": "hi", "n": [1, 2] } })
+        );
+    }
+
+    #[test]
+    fn serialize_invalid_json() {
+        let test = JsonBytesTest { payload: b"{ \"body\": }".to_vec() };
+        to_json_value(test).unwrap_err();
+    }
+
+    #[test]
+    fn serialize_invalid_utf8() {
+        let test = JsonBytesTest { payload: vec![b'"', 0xff, 0xfe, b'"'] };
+        to_json_value(test).unwrap_err();
+    }
+}
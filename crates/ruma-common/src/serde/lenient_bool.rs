@@ -0,0 +1,101 @@
+//! De-/serialization functions for `bool` fields that might be sent as strings.
+//!
+//! Some implementations send booleans as the strings `"true"` and `"false"`, or `"1"` and `"0"`,
+//! instead of JSON booleans. These are accepted during deserialization, and a JSON boolean is
+//! always emitted during serialization.
+
+use std::fmt;
+
+use serde::{
+    de::{self, Deserializer, Visitor},
+    ser::Serializer,
+};
+
+/// Serialize the given `bool` as a JSON boolean.
+#[allow(clippy::trivially_copy_pass_by_ref)]
+pub fn serialize<S>(value: &bool, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_bool(*value)
+}
+
+/// Deserialize a `bool` from a JSON boolean or one of the strings `"true"`, `"false"`, `"1"` and
+/// `"0"`.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct LenientBoolVisitor;
+
+    impl<'de> Visitor<'de> for LenientBoolVisitor {
+        type Value = bool;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a boolean or one of the strings \"true\", \"false\", \"1\", \"0\"")
+        }
+
+        fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(v)
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            match v {
+                "true" | "1" => Ok(true),
+                "false" | "0" => Ok(false),
+                _ => Err(E::invalid_value(de::Unexpected::Str(v), &self)),
+            }
+        }
+    }
+
+    deserializer.deserialize_any(LenientBoolVisitor)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
+
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct LenientBoolTest {
+        #[serde(with = "super")]
+        flag: bool,
+    }
+
+    #[test]
+    fn deserialize_accepted_values() {
+        for (value, expected) in [
+            (json!(true), true),
+            (json!(false), false),
+            (json!("true"), true),
+            (json!("false"), false),
+            (json!("1"), true),
+            (json!("0"), false),
+        ] {
+            let test = from_json_value::<LenientBoolTest>(json!({ "flag": value })).unwrap();
+            assert_eq!(test.flag, expected);
+        }
+    }
+
+    #[test]
+    fn deserialize_rejected_values() {
+        for value in [json!("yes"), json!("TRUE"), json!(""), json!(1), json!(null)] {
+            from_json_value::<LenientBoolTest>(json!({ "flag": value })).unwrap_err();
+        }
+    }
+
+    #[test]
+    fn serialize() {
+        assert_eq!(to_json_value(LenientBoolTest { flag: true }).unwrap(), json!({ "flag": true }));
+        assert_eq!(
+            to_json_value(LenientBoolTest { flag: false }).unwrap(),
+            json!({ "flag": false })
+        );
+    }
+}
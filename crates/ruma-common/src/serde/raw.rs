@@ -194,6 +194,18 @@ impl<T> Raw<T> {
         serde_json::from_str(self.json.get())
     }
 
+    /// Turns `&Raw<T>` into `Raw<U>`, after checking that the JSON can be deserialized as `U`.
+    ///
+    /// This is useful when the same JSON can be interpreted as different types, for example the
+    /// stable and unstable versions of an event content type.
+    pub fn try_convert<'a, U>(&'a self) -> serde_json::Result<Raw<U>>
+    where
+        U: Deserialize<'a>,
+    {
+        self.deserialize_as::<U>()?;
+        Ok(Raw::from_json(self.json.clone()))
+    }
+
     /// Turns `Raw<T>` into `Raw<U>` without changing the underlying JSON.
     ///
     /// This is useful for turning raw specific event types into raw event enum types.
@@ -267,4 +279,39 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn deserialize_as_and_try_convert() -> serde_json::Result<()> {
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Stable {
+            description: Option<String>,
+            timeout: u64,
+        }
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Unstable {
+            description: Option<String>,
+            timeout: u64,
+            #[serde(rename = "org.matrix.msc3488.ts")]
+            ts: u64,
+        }
+
+        const OBJ: &str =
+            r#"{ "description": "Alice", "timeout": 60, "org.matrix.msc3488.ts": 1 }"#;
+        let raw: Raw<Unstable> = from_json_str(OBJ)?;
+
+        assert_eq!(
+            raw.deserialize_as::<Stable>()?,
+            Stable { description: Some("Alice".to_owned()), timeout: 60 }
+        );
+
+        let stable = raw.try_convert::<Stable>()?;
+        assert_eq!(stable.json().get(), OBJ);
+        assert_eq!(stable.try_convert::<Unstable>()?.deserialize()?.ts, 1);
+
+        let raw: Raw<Stable> = from_json_str(r#"{ "timeout": 60 }"#)?;
+        raw.try_convert::<Unstable>().unwrap_err();
+
+        Ok(())
+    }
 }
@@ -1,3 +1,5 @@
+//! Timestamps and clocks.
+
 use std::fmt;
 
 use js_int::{uint, UInt};
@@ -132,6 +134,40 @@ impl fmt::Debug for SecondsSinceUnixEpoch {
     }
 }
 
+/// A source of the current time.
+///
+/// Methods that depend on the current time can take a `Clock` so that they can be tested with a
+/// fixed time, using a [`MockClock`]. Use [`SYSTEM_CLOCK`] for the actual current time.
+pub trait Clock {
+    /// The current time.
+    fn now(&self) -> MilliSecondsSinceUnixEpoch;
+}
+
+/// A [`Clock`] using the current system time.
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(clippy::exhaustive_structs)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> MilliSecondsSinceUnixEpoch {
+        MilliSecondsSinceUnixEpoch::now()
+    }
+}
+
+/// The [`SystemClock`].
+pub const SYSTEM_CLOCK: SystemClock = SystemClock;
+
+/// A [`Clock`] that always returns the given time.
+#[derive(Clone, Copy, Debug)]
+#[allow(clippy::exhaustive_structs)]
+pub struct MockClock(pub MilliSecondsSinceUnixEpoch);
+
+impl Clock for MockClock {
+    fn now(&self) -> MilliSecondsSinceUnixEpoch {
+        self.0
+    }
+}
+
 #[cfg(all(target_arch = "wasm32", target_os = "unknown", feature = "js"))]
 fn f64_to_uint(val: f64) -> UInt {
     // UInt::MAX milliseconds is ~285 616 years, we do not account for that
@@ -147,7 +183,9 @@ mod tests {
     use serde::{Deserialize, Serialize};
     use serde_json::json;
 
-    use super::{MilliSecondsSinceUnixEpoch, SecondsSinceUnixEpoch};
+    use super::{
+        Clock, MilliSecondsSinceUnixEpoch, MockClock, SecondsSinceUnixEpoch, SYSTEM_CLOCK,
+    };
 
     #[derive(Clone, Debug, Deserialize, Serialize)]
     struct SystemTimeTest {
@@ -186,4 +224,22 @@ mod tests {
         let seconds = MilliSecondsSinceUnixEpoch(uint!(0));
         assert_eq!(format!("{seconds:?}"), "1970-01-01T00:00:00.000");
     }
+
+    #[test]
+    fn mock_clock() {
+        let mut clock = MockClock(MilliSecondsSinceUnixEpoch(uint!(1_000)));
+        assert_eq!(clock.now(), MilliSecondsSinceUnixEpoch(uint!(1_000)));
+        assert_eq!(clock.now(), MilliSecondsSinceUnixEpoch(uint!(1_000)));
+
+        clock.0 = MilliSecondsSinceUnixEpoch(uint!(2_000));
+        assert_eq!(clock.now(), MilliSecondsSinceUnixEpoch(uint!(2_000)));
+    }
+
+    #[test]
+    fn system_clock() {
+        let before = MilliSecondsSinceUnixEpoch::now();
+        let now = SYSTEM_CLOCK.now();
+        assert!(now >= before);
+        assert!(now <= MilliSecondsSinceUnixEpoch::now());
+    }
 }
@@ -1,3 +1,5 @@
+use std::{convert::Infallible, fmt::Display, str::FromStr};
+
 use ruma_common::serde::StringEnum;
 use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
 
@@ -37,6 +39,18 @@ fn display() {
     assert_eq!(MyEnum::_Custom(PrivOwnedStr("HelloWorld".into())).to_string(), "HelloWorld");
 }
 
+#[test]
+fn from_str() {
+    fn assert_impls<T: Display + FromStr<Err = Infallible>>() {}
+    assert_impls::<MyEnum>();
+
+    assert_eq!("first".parse(), Ok(MyEnum::First));
+    assert_eq!("m.third".parse(), Ok(MyEnum::Third));
+    assert_eq!("m.stable".parse(), Ok(MyEnum::Stable));
+    assert_eq!("HelloWorld".parse(), Ok(MyEnum::_Custom(PrivOwnedStr("HelloWorld".into()))));
+    assert_eq!(format!("{}", "hello_world".parse::<MyEnum>().unwrap()), "hello_world");
+}
+
 #[test]
 fn debug() {
     assert_eq!(format!("{:?}", MyEnum::First), "\"first\"");
@@ -5,17 +5,21 @@
 
 use std::time::{Duration, SystemTime};
 
-use ruma_common::{MilliSecondsSinceUnixEpoch, OwnedUserId};
+use ruma_common::{time::Clock, MilliSecondsSinceUnixEpoch, OwnedUserId};
 use ruma_macros::EventContent;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
-use crate::location::AssetContent;
+use crate::{
+    location::AssetContent, EventContent, PossiblyRedactedStateEventContent, StateEventType,
+    StaticEventContent,
+};
 
 /// The content of a beacon_info state.
 #[derive(Clone, Debug, Deserialize, Serialize, EventContent)]
 #[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
 #[ruma_event(
-    type = "org.matrix.msc3672.beacon_info", alias = "m.beacon_info", kind = State, state_key_type = OwnedUserId
+    type = "org.matrix.msc3672.beacon_info", alias = "m.beacon_info", kind = State, state_key_type = OwnedUserId,
+    custom_possibly_redacted,
 )]
 pub struct BeaconInfoEventContent {
     /// The description of the location.
@@ -25,6 +29,10 @@ pub struct BeaconInfoEventContent {
     pub description: Option<String>,
 
     /// Whether the user starts sharing their location.
+    ///
+    /// The strings `"true"` and `"false"` are also accepted during deserialization, as they are
+    /// sent by some implementations.
+    #[serde(with = "ruma_common::serde::lenient_bool")]
     pub live: bool,
 
     /// The time when location sharing started.
@@ -70,14 +78,109 @@ impl BeaconInfoEventContent {
         self.live = false;
     }
 
-    /// Start time plus its timeout, it returns `false`, indicating that the beacon is not live.
+    /// Whether the beacon is live at the current time of the given clock.
+    ///
+    /// Returns `false` if `live` is `false` or if the start time plus its timeout has passed.
     /// Otherwise, it returns `true`.
-    pub fn is_live(&self) -> bool {
+    ///
+    /// Use [`SYSTEM_CLOCK`](ruma_common::time::SYSTEM_CLOCK) to check against the current system
+    /// time.
+    pub fn is_live(&self, clock: &impl Clock) -> bool {
+        self.live
+            && self
+                .expires_at()
+                .zip(clock.now().to_system_time())
+                .is_some_and(|(end, now)| end > now)
+    }
+
+    /// Whether the beacon was live and its timeout has passed at the current time of the given
+    /// clock.
+    ///
+    /// Returns `true` if `live` is `true` and the current time is after the start time plus its
+    /// timeout plus `grace`. The grace period allows to account for clock skew between servers.
+    ///
+    /// This is not simply the opposite of [`is_live()`](Self::is_live): a beacon that is not
+    /// `live` never expires, so this always returns `false` for it.
+    pub fn has_expired(&self, grace: Duration, clock: &impl Clock) -> bool {
         self.live
             && self
-                .ts
-                .to_system_time()
-                .and_then(|t| t.checked_add(self.timeout))
-                .is_some_and(|t| t > SystemTime::now())
+                .expires_at()
+                .and_then(|end| end.checked_add(grace))
+                .zip(clock.now().to_system_time())
+                .is_some_and(|(end, now)| now > end)
     }
+
+    /// The remaining time before the beacon expires at the current time of the given clock.
+    ///
+    /// Returns `None` if the beacon is not [live](Self::is_live).
+    pub fn time_until_expiry(&self, clock: &impl Clock) -> Option<Duration> {
+        if !self.live {
+            return None;
+        }
+
+        let remaining = self.expires_at()?.duration_since(clock.now().to_system_time()?).ok()?;
+        (!remaining.is_zero()).then_some(remaining)
+    }
+
+    /// The time at which the beacon stops being live.
+    fn expires_at(&self) -> Option<SystemTime> {
+        self.ts.to_system_time()?.checked_add(self.timeout)
+    }
+}
+
+/// The possibly redacted form of [`BeaconInfoEventContent`].
+///
+/// This type is used when it's not obvious whether the content is redacted or not.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+pub struct PossiblyRedactedBeaconInfoEventContent {
+    /// The description of the location.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Whether the user starts sharing their location.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_optional_lenient_bool",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub live: Option<bool>,
+
+    /// The time when location sharing started.
+    #[serde(rename = "org.matrix.msc3488.ts", skip_serializing_if = "Option::is_none")]
+    pub ts: Option<MilliSecondsSinceUnixEpoch>,
+
+    /// The duration that the location sharing will be live.
+    #[serde(default, with = "ruma_common::serde::duration::ms")]
+    pub timeout: Duration,
+
+    /// The asset that this message refers to.
+    #[serde(default, rename = "org.matrix.msc3488.asset")]
+    pub asset: AssetContent,
+}
+
+impl EventContent for PossiblyRedactedBeaconInfoEventContent {
+    type EventType = StateEventType;
+
+    fn event_type(&self) -> Self::EventType {
+        StateEventType::BeaconInfo
+    }
+}
+
+impl PossiblyRedactedStateEventContent for PossiblyRedactedBeaconInfoEventContent {
+    type StateKey = OwnedUserId;
+}
+
+impl StaticEventContent for PossiblyRedactedBeaconInfoEventContent {
+    const TYPE: &'static str = "org.matrix.msc3672.beacon_info";
+}
+
+fn deserialize_optional_lenient_bool<'de, D>(deserializer: D) -> Result<Option<bool>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct LenientBool(#[serde(with = "ruma_common::serde::lenient_bool")] bool);
+
+    Ok(Option::<LenientBool>::deserialize(deserializer)?.map(|LenientBool(value)| value))
 }
@@ -48,6 +48,17 @@ impl SessionDescription {
     }
 }
 
+/// The type of a VoIP call.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(clippy::exhaustive_enums)]
+pub enum CallType {
+    /// A call with only audio tracks.
+    Audio,
+
+    /// A call with at least one video track.
+    Video,
+}
+
 /// Metadata about a VoIP stream.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
@@ -104,3 +104,29 @@ pub enum Reason {
     #[doc(hidden)]
     _Custom(PrivOwnedStr),
 }
+
+impl Reason {
+    /// A human-readable description of this reason, in English.
+    ///
+    /// Returns the string representation of the reason for unknown reasons.
+    pub fn reason_display_string(&self) -> &str {
+        match self {
+            Self::IceFailed => "Connection failed",
+            Self::InviteTimeout => "Call was not answered",
+            Self::IceTimeout => "Connection lost",
+            Self::UserHangup => "Caller hung up",
+            Self::UserMediaFailed => "Could not access camera or microphone",
+            Self::UserBusy => "Recipient is busy",
+            Self::UnknownError => "Unknown error",
+            Self::_Custom(_) => self.as_str(),
+        }
+    }
+
+    /// Whether this reason denotes a failure of the connection, rather than an action of the user.
+    ///
+    /// Returns `true` for [`Reason::IceFailed`], [`Reason::InviteTimeout`] and
+    /// [`Reason::IceTimeout`].
+    pub fn is_error_condition(&self) -> bool {
+        matches!(self, Self::IceFailed | Self::InviteTimeout | Self::IceTimeout)
+    }
+}
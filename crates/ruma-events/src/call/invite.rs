@@ -2,16 +2,16 @@
 //!
 //! [`m.call.invite`]: https://spec.matrix.org/latest/client-server-api/#mcallinvite
 
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, time::Duration};
 
 use js_int::UInt;
-use ruma_common::{OwnedUserId, OwnedVoipId, VoipVersionId};
+use ruma_common::{MilliSecondsSinceUnixEpoch, OwnedUserId, OwnedVoipId, VoipVersionId};
 use ruma_macros::EventContent;
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "unstable-msc2747")]
 use super::CallCapabilities;
-use super::{SessionDescription, StreamMetadata};
+use super::{CallType, SessionDescription, StreamMetadata};
 
 /// The content of an `m.call.invite` event.
 ///
@@ -107,4 +107,48 @@ impl CallInviteEventContent {
             sdp_stream_metadata: Default::default(),
         }
     }
+
+    /// The type of the call, according to the media of the SDP offer.
+    ///
+    /// Returns [`CallType::Video`] if the offer contains a video media description,
+    /// [`CallType::Audio`] otherwise.
+    pub fn call_type(&self) -> CallType {
+        if self.offer.sdp.lines().any(|line| line.trim_start().starts_with("m=video")) {
+            CallType::Video
+        } else {
+            CallType::Audio
+        }
+    }
+}
+
+impl OriginalCallInviteEvent {
+    /// The remaining time during which the invite is valid, at the given time.
+    ///
+    /// The lifetime of the invite starts at the `origin_server_ts` of the event.
+    ///
+    /// Returns `None` if the invite has expired.
+    pub fn lifetime_remaining(&self, now: MilliSecondsSinceUnixEpoch) -> Option<Duration> {
+        lifetime_remaining(self.origin_server_ts, self.content.lifetime, now)
+    }
+}
+
+impl OriginalSyncCallInviteEvent {
+    /// The remaining time during which the invite is valid, at the given time.
+    ///
+    /// The lifetime of the invite starts at the `origin_server_ts` of the event.
+    ///
+    /// Returns `None` if the invite has expired.
+    pub fn lifetime_remaining(&self, now: MilliSecondsSinceUnixEpoch) -> Option<Duration> {
+        lifetime_remaining(self.origin_server_ts, self.content.lifetime, now)
+    }
+}
+
+fn lifetime_remaining(
+    sent_at: MilliSecondsSinceUnixEpoch,
+    lifetime: UInt,
+    now: MilliSecondsSinceUnixEpoch,
+) -> Option<Duration> {
+    let expires_at = u64::from(sent_at.get()).saturating_add(lifetime.into());
+    let remaining = expires_at.checked_sub(now.get().into()).filter(|ms| *ms > 0)?;
+    Some(Duration::from_millis(remaining))
 }
@@ -7,7 +7,7 @@ use std::{
     ops::{Deref, DerefMut},
 };
 
-use ruma_common::{OwnedRoomId, OwnedUserId};
+use ruma_common::{OwnedRoomId, OwnedUserId, RoomId, UserId};
 use ruma_macros::EventContent;
 use serde::{Deserialize, Serialize};
 
@@ -22,6 +22,52 @@ use serde::{Deserialize, Serialize};
 #[ruma_event(type = "m.direct", kind = GlobalAccountData)]
 pub struct DirectEventContent(pub BTreeMap<OwnedUserId, Vec<OwnedRoomId>>);
 
+impl DirectEventContent {
+    /// Get the rooms that are considered direct with the given user.
+    pub fn rooms_for_user(&self, user_id: &UserId) -> &[OwnedRoomId] {
+        self.get(user_id).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Add the given room to the direct rooms with the given user.
+    ///
+    /// Does nothing if the room is already considered direct with that user.
+    pub fn add_direct_room(&mut self, user_id: OwnedUserId, room_id: OwnedRoomId) {
+        let rooms = self.entry(user_id).or_default();
+        if !rooms.contains(&room_id) {
+            rooms.push(room_id);
+        }
+    }
+
+    /// Remove the given room from the direct rooms with the given user.
+    ///
+    /// If it was the last direct room with that user, the user is removed from the map.
+    ///
+    /// Returns `true` if the room was considered direct with that user.
+    pub fn remove_direct_room(&mut self, user_id: &UserId, room_id: &RoomId) -> bool {
+        let Some(rooms) = self.get_mut(user_id) else {
+            return false;
+        };
+
+        let len = rooms.len();
+        rooms.retain(|id| id != room_id);
+        let removed = rooms.len() != len;
+
+        if rooms.is_empty() {
+            self.remove(user_id);
+        }
+
+        removed
+    }
+
+    /// Get the users with which the given room is considered direct.
+    pub fn users_with_direct_room(&self, room_id: &RoomId) -> Vec<&UserId> {
+        self.iter()
+            .filter(|(_, rooms)| rooms.iter().any(|id| id == room_id))
+            .map(|(user_id, _)| &**user_id)
+            .collect()
+    }
+}
+
 impl Deref for DirectEventContent {
     type Target = BTreeMap<OwnedUserId, Vec<OwnedRoomId>>;
 
@@ -58,7 +104,7 @@ impl FromIterator<(OwnedUserId, Vec<OwnedRoomId>)> for DirectEventContent {
 mod tests {
     use std::collections::BTreeMap;
 
-    use ruma_common::{owned_room_id, owned_user_id};
+    use ruma_common::{owned_room_id, owned_user_id, room_id, user_id};
     use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
 
     use super::{DirectEvent, DirectEventContent};
@@ -96,4 +142,37 @@ mod tests {
         assert!(direct_rooms.contains(&rooms[0]));
         assert!(direct_rooms.contains(&rooms[1]));
     }
+
+    #[test]
+    fn add_and_remove_direct_rooms() {
+        let mut content = DirectEventContent::default();
+        let alice = owned_user_id!("@alice:ruma.io");
+        let room = owned_room_id!("!1:ruma.io");
+
+        content.add_direct_room(alice.clone(), room.clone());
+        content.add_direct_room(alice.clone(), room.clone());
+        assert_eq!(content.rooms_for_user(&alice), [room.clone()]);
+        assert!(content.rooms_for_user(user_id!("@bob:ruma.io")).is_empty());
+
+        assert!(!content.remove_direct_room(&alice, room_id!("!2:ruma.io")));
+        assert!(content.remove_direct_room(&alice, &room));
+        assert!(!content.remove_direct_room(&alice, &room));
+        assert!(!content.contains_key(&alice));
+    }
+
+    #[test]
+    fn users_with_direct_room() {
+        let mut content = DirectEventContent::default();
+        let room = owned_room_id!("!1:ruma.io");
+
+        content.add_direct_room(owned_user_id!("@bob:ruma.io"), room.clone());
+        content.add_direct_room(owned_user_id!("@alice:ruma.io"), room.clone());
+        content.add_direct_room(owned_user_id!("@carl:ruma.io"), owned_room_id!("!2:ruma.io"));
+
+        assert_eq!(
+            content.users_with_direct_room(&room),
+            [user_id!("@alice:ruma.io"), user_id!("@bob:ruma.io")]
+        );
+        assert!(content.users_with_direct_room(room_id!("!3:ruma.io")).is_empty());
+    }
 }
@@ -1,3 +1,4 @@
+use as_variant::as_variant;
 use ruma_common::{
     serde::from_raw_json_value, EventId, MilliSecondsSinceUnixEpoch, OwnedRoomId, RoomId,
     TransactionId, UserId,
@@ -6,7 +7,10 @@ use ruma_macros::{event_enum, EventEnumFromEvent};
 use serde::{de, Deserialize};
 use serde_json::value::RawValue as RawJsonValue;
 
-use super::room::encrypted;
+use super::{
+    fully_read::FullyReadEventContent, push_rules::PushRulesEventContent, room::encrypted,
+    UnsignedRoomRedactionEvent,
+};
 
 event_enum! {
     /// Any global account data event.
@@ -203,6 +207,25 @@ impl AnyTimelineEvent {
 
         /// Returns this event's `transaction_id` from inside `unsigned`, if there is one.
         pub fn transaction_id(&self) -> Option<&TransactionId>;
+
+        /// Returns the redaction event from inside `unsigned`, if this event was redacted.
+        pub fn redacted_because(&self) -> Option<&UnsignedRoomRedactionEvent>;
+    }
+
+    /// Whether this event was redacted.
+    pub fn is_redacted(&self) -> bool {
+        self.redacted_because().is_some()
+    }
+
+    /// Returns the reason given for the redaction of this event, if it was redacted and a reason
+    /// was given.
+    pub fn redaction_reason(&self) -> Option<&str> {
+        self.redacted_because()?.content.reason.as_deref()
+    }
+
+    /// Returns the ID of the redaction event from inside `unsigned`, if this event was redacted.
+    pub fn unsigned_redacted_by(&self) -> Option<&EventId> {
+        self.redacted_because().map(|ev| &*ev.event_id)
     }
 
     /// Returns this event's `type`.
@@ -240,6 +263,25 @@ impl AnySyncTimelineEvent {
 
         /// Returns this event's `transaction_id` from inside `unsigned`, if there is one.
         pub fn transaction_id(&self) -> Option<&TransactionId>;
+
+        /// Returns the redaction event from inside `unsigned`, if this event was redacted.
+        pub fn redacted_because(&self) -> Option<&UnsignedRoomRedactionEvent>;
+    }
+
+    /// Whether this event was redacted.
+    pub fn is_redacted(&self) -> bool {
+        self.redacted_because().is_some()
+    }
+
+    /// Returns the reason given for the redaction of this event, if it was redacted and a reason
+    /// was given.
+    pub fn redaction_reason(&self) -> Option<&str> {
+        self.redacted_because()?.content.reason.as_deref()
+    }
+
+    /// Returns the ID of the redaction event from inside `unsigned`, if this event was redacted.
+    pub fn unsigned_redacted_by(&self) -> Option<&EventId> {
+        self.redacted_because().map(|ev| &*ev.event_id)
     }
 
     /// Returns this event's `type`.
@@ -388,3 +430,17 @@ impl AnyMessageLikeEventContent {
         }
     }
 }
+
+impl AnyGlobalAccountDataEvent {
+    /// Get the content of this event if it is an `m.push_rules` event.
+    pub fn into_push_rules(self) -> Option<PushRulesEventContent> {
+        as_variant!(self, Self::PushRules(ev) => ev.content)
+    }
+}
+
+impl AnyRoomAccountDataEvent {
+    /// Get the content of this event if it is an `m.fully_read` event.
+    pub fn into_fully_read(self) -> Option<FullyReadEventContent> {
+        as_variant!(self, Self::FullyRead(ev) => ev.content)
+    }
+}
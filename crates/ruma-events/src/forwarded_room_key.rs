@@ -2,7 +2,9 @@
 //!
 //! [`m.forwarded_room_key`]: https://spec.matrix.org/latest/client-server-api/#mforwarded_room_key
 
-use ruma_common::{EventEncryptionAlgorithm, OwnedRoomId};
+use std::collections::BTreeSet;
+
+use ruma_common::{serde::Base64, EventEncryptionAlgorithm, OwnedRoomId};
 use ruma_macros::EventContent;
 use serde::{Deserialize, Serialize};
 
@@ -56,6 +58,58 @@ pub struct ToDeviceForwardedRoomKeyEventContent {
     pub shared_history: bool,
 }
 
+impl ToDeviceForwardedRoomKeyEventContent {
+    /// The maximum length of a valid forwarding chain.
+    pub const MAX_FORWARDING_CHAIN_LENGTH: usize = 10;
+
+    /// Check that the forwarding chain of this key is valid.
+    ///
+    /// The chain is valid if it contains at most [`Self::MAX_FORWARDING_CHAIN_LENGTH`] keys, if all
+    /// its keys are unpadded base64-encoded Curve25519 keys, and if no key appears twice, which
+    /// would mean that the chain is circular.
+    pub fn validate_chain(&self) -> Result<(), ForwardingChainError> {
+        let chain = &self.forwarding_curve25519_key_chain;
+
+        if chain.len() > Self::MAX_FORWARDING_CHAIN_LENGTH {
+            return Err(ForwardingChainError::TooLong);
+        }
+
+        let mut seen_keys = BTreeSet::new();
+        for key in chain {
+            let bytes: Base64 =
+                Base64::parse(key).map_err(|_| ForwardingChainError::InvalidKeyFormat)?;
+            if bytes.as_bytes().len() != 32 {
+                return Err(ForwardingChainError::InvalidKeyFormat);
+            }
+
+            if !seen_keys.insert(bytes.as_bytes().to_owned()) {
+                return Err(ForwardingChainError::DuplicateKey);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// An error encountered when validating the forwarding chain of a
+/// [`ToDeviceForwardedRoomKeyEventContent`].
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, thiserror::Error)]
+#[non_exhaustive]
+pub enum ForwardingChainError {
+    /// A key appears several times in the chain.
+    #[error("duplicate key in forwarding chain")]
+    DuplicateKey,
+
+    /// The chain has more than
+    /// [`ToDeviceForwardedRoomKeyEventContent::MAX_FORWARDING_CHAIN_LENGTH`] keys.
+    #[error("forwarding chain is too long")]
+    TooLong,
+
+    /// A key in the chain is not a base64-encoded Curve25519 key.
+    #[error("invalid key in forwarding chain")]
+    InvalidKeyFormat,
+}
+
 /// Initial set of fields of `ToDeviceForwardedRoomKeyEventContent`.
 ///
 /// This struct will not be updated even if additional fields are added to `ConditionalPushRule` in
@@ -109,3 +163,79 @@ impl From<ToDeviceForwardedRoomKeyEventContentInit> for ToDeviceForwardedRoomKey
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ruma_common::{
+        owned_room_id,
+        serde::{base64::Standard, Base64},
+        EventEncryptionAlgorithm,
+    };
+
+    use super::{
+        ForwardingChainError, ToDeviceForwardedRoomKeyEventContent,
+        ToDeviceForwardedRoomKeyEventContentInit,
+    };
+
+    fn content(chain: &[&str]) -> ToDeviceForwardedRoomKeyEventContent {
+        ToDeviceForwardedRoomKeyEventContentInit {
+            algorithm: EventEncryptionAlgorithm::MegolmV1AesSha2,
+            room_id: owned_room_id!("!testroomid:example.org"),
+            sender_key: "hPQNcabIABgGnx3/ACv/jmMmiQHoeFfuLB17tzWp6Hw".to_owned(),
+            session_id: "SessId".to_owned(),
+            session_key: "SessKey".to_owned(),
+            sender_claimed_ed25519_key: "aj40p+aw64yPIdsxoog8Jhlu9i1HSIVdH+1ZLQcNs2Q".to_owned(),
+            forwarding_curve25519_key_chain: chain.iter().map(|&key| key.to_owned()).collect(),
+        }
+        .into()
+    }
+
+    const KEY_A: &str = "hPQNcabIABgGnx3/ACv/jmMmiQHoeFfuLB17tzWp6Hw";
+    const KEY_B: &str = "3C5BFWi2Y8MaVvjM8M22DBmh24PmgR0nPvJOIArzgyI";
+    const KEY_C: &str = "rz7uQJk1mSeOjJ6lt3pxebmX4RNoT0XJ/NmKqz0YtDI";
+
+    #[test]
+    fn valid_chain() {
+        content(&[]).validate_chain().unwrap();
+        content(&[KEY_A]).validate_chain().unwrap();
+        content(&[KEY_A, KEY_B, KEY_C]).validate_chain().unwrap();
+    }
+
+    #[test]
+    fn duplicate_key() {
+        assert_eq!(
+            content(&[KEY_A, KEY_B, KEY_A]).validate_chain(),
+            Err(ForwardingChainError::DuplicateKey)
+        );
+        // The same key with padding.
+        let padded_key_a = format!("{KEY_A}=");
+        assert_eq!(
+            content(&[KEY_A, &padded_key_a]).validate_chain(),
+            Err(ForwardingChainError::DuplicateKey)
+        );
+    }
+
+    #[test]
+    fn too_long() {
+        let keys = (0..=ToDeviceForwardedRoomKeyEventContent::MAX_FORWARDING_CHAIN_LENGTH)
+            .map(|i| Base64::<Standard, _>::new([i as u8; 32]).encode())
+            .collect::<Vec<_>>();
+        let keys = keys.iter().map(String::as_str).collect::<Vec<_>>();
+
+        content(&keys[1..]).validate_chain().unwrap();
+        assert_eq!(content(&keys).validate_chain(), Err(ForwardingChainError::TooLong));
+    }
+
+    #[test]
+    fn invalid_key_format() {
+        assert_eq!(
+            content(&[KEY_A, "not base64!"]).validate_chain(),
+            Err(ForwardingChainError::InvalidKeyFormat)
+        );
+        // Valid base64, but only 16 bytes.
+        assert_eq!(
+            content(&["AAAAAAAAAAAAAAAAAAAAAA"]).validate_chain(),
+            Err(ForwardingChainError::InvalidKeyFormat)
+        );
+    }
+}
@@ -2,7 +2,7 @@
 //!
 //! [`m.fully_read`]: https://spec.matrix.org/latest/client-server-api/#mfully_read
 
-use ruma_common::OwnedEventId;
+use ruma_common::{EventId, OwnedEventId};
 use ruma_macros::EventContent;
 use serde::{Deserialize, Serialize};
 
@@ -24,4 +24,44 @@ impl FullyReadEventContent {
     pub fn new(event_id: OwnedEventId) -> Self {
         Self { event_id }
     }
+
+    /// Whether the read marker is at or after the given event in the given timeline.
+    ///
+    /// The timeline must be sorted in chronological order.
+    ///
+    /// Returns `None` if either the event of the read marker or the given event is not in the
+    /// timeline.
+    pub fn is_at_or_after_in_timeline(
+        &self,
+        event_id: &EventId,
+        timeline: &[&EventId],
+    ) -> Option<bool> {
+        let marker_pos = timeline.iter().position(|id| *id == self.event_id)?;
+        let event_pos = timeline.iter().position(|id| *id == event_id)?;
+        Some(marker_pos >= event_pos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ruma_common::{event_id, owned_event_id};
+
+    use super::FullyReadEventContent;
+
+    #[test]
+    fn is_at_or_after_in_timeline() {
+        let timeline = [event_id!("$a"), event_id!("$b"), event_id!("$c")];
+        let content = FullyReadEventContent::new(owned_event_id!("$b"));
+
+        assert_eq!(content.is_at_or_after_in_timeline(event_id!("$a"), &timeline), Some(true));
+        assert_eq!(content.is_at_or_after_in_timeline(event_id!("$b"), &timeline), Some(true));
+        assert_eq!(content.is_at_or_after_in_timeline(event_id!("$c"), &timeline), Some(false));
+
+        assert_eq!(content.is_at_or_after_in_timeline(event_id!("$d"), &timeline), None);
+        assert_eq!(
+            FullyReadEventContent::new(owned_event_id!("$d"))
+                .is_at_or_after_in_timeline(event_id!("$a"), &timeline),
+            None
+        );
+    }
 }
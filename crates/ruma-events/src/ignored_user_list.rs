@@ -4,7 +4,7 @@
 
 use std::collections::BTreeMap;
 
-use ruma_common::OwnedUserId;
+use ruma_common::{OwnedUserId, UserId};
 use ruma_macros::EventContent;
 use serde::{Deserialize, Serialize};
 
@@ -32,6 +32,30 @@ impl IgnoredUserListEventContent {
     pub fn users(ignored_users: impl IntoIterator<Item = OwnedUserId>) -> Self {
         Self::new(ignored_users.into_iter().map(|id| (id, IgnoredUser {})).collect())
     }
+
+    /// Add the given user to the list of ignored users.
+    ///
+    /// Does nothing if the user is already ignored.
+    pub fn ignore_user(&mut self, user_id: OwnedUserId) {
+        self.ignored_users.entry(user_id).or_default();
+    }
+
+    /// Remove the given user from the list of ignored users.
+    ///
+    /// Returns `true` if the user was ignored.
+    pub fn unignore_user(&mut self, user_id: &UserId) -> bool {
+        self.ignored_users.remove(user_id).is_some()
+    }
+
+    /// Whether the given user is ignored.
+    pub fn is_ignored(&self, user_id: &UserId) -> bool {
+        self.ignored_users.contains_key(user_id)
+    }
+
+    /// Iterate over the ignored users, in lexicographic order.
+    pub fn ignored_users(&self) -> impl Iterator<Item = &UserId> {
+        self.ignored_users.keys().map(|user_id| &**user_id)
+    }
 }
 
 /// Details about an ignored user.
@@ -91,4 +115,24 @@ mod tests {
             vec![user_id!("@carl:example.com")]
         );
     }
+
+    #[test]
+    fn ignore_and_unignore_users() {
+        let mut content = IgnoredUserListEventContent::default();
+        let carl = owned_user_id!("@carl:example.com");
+        let alice = owned_user_id!("@alice:example.com");
+
+        content.ignore_user(carl.clone());
+        content.ignore_user(alice.clone());
+        content.ignore_user(carl.clone());
+        assert_eq!(content.ignored_users.len(), 2);
+        assert!(content.is_ignored(&carl));
+        assert_eq!(content.ignored_users().collect::<Vec<_>>(), [&*alice, &*carl]);
+
+        assert!(content.unignore_user(&carl));
+        assert!(!content.is_ignored(&carl));
+        assert!(!content.unignore_user(&carl));
+        assert!(!content.unignore_user(user_id!("@bob:example.com")));
+        assert_eq!(content.ignored_users().collect::<Vec<_>>(), [&*alice]);
+    }
 }
@@ -7,6 +7,7 @@ use std::collections::BTreeMap;
 use ruma_common::{serde::Base64, OwnedTransactionId};
 use ruma_macros::EventContent;
 use serde::{Deserialize, Serialize};
+use subtle::ConstantTimeEq;
 
 use crate::relation::Reference;
 
@@ -42,6 +43,17 @@ impl ToDeviceKeyVerificationMacEventContent {
     ) -> Self {
         Self { transaction_id, mac, keys }
     }
+
+    /// Compare the MACs of this event with the given locally computed MACs.
+    ///
+    /// The comparison of the MACs is performed in constant time.
+    pub fn verify_codes(
+        &self,
+        expected_mac: &BTreeMap<String, Base64>,
+        expected_keys: &Base64,
+    ) -> Result<(), MacVerificationError> {
+        verify_codes(&self.mac, &self.keys, expected_mac, expected_keys)
+    }
 }
 
 /// The content of an in-room `m.key.verification.` event.
@@ -71,4 +83,145 @@ impl KeyVerificationMacEventContent {
     pub fn new(mac: BTreeMap<String, Base64>, keys: Base64, relates_to: Reference) -> Self {
         Self { mac, keys, relates_to }
     }
+
+    /// Compare the MACs of this event with the given locally computed MACs.
+    ///
+    /// The comparison of the MACs is performed in constant time.
+    pub fn verify_codes(
+        &self,
+        expected_mac: &BTreeMap<String, Base64>,
+        expected_keys: &Base64,
+    ) -> Result<(), MacVerificationError> {
+        verify_codes(&self.mac, &self.keys, expected_mac, expected_keys)
+    }
+}
+
+/// An error encountered when the MACs of a `m.key.verification.mac` event don't match the locally
+/// computed ones.
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+#[non_exhaustive]
+pub enum MacVerificationError {
+    /// The MAC of the given key doesn't match, or the key is missing in one of the lists.
+    #[error("MAC mismatch for key `{key}`")]
+    MacMismatch {
+        /// The ID of the key.
+        key: String,
+    },
+
+    /// The MAC of the list of keys doesn't match.
+    #[error("MAC mismatch for the list of keys")]
+    KeysMismatch,
+}
+
+fn verify_codes(
+    mac: &BTreeMap<String, Base64>,
+    keys: &Base64,
+    expected_mac: &BTreeMap<String, Base64>,
+    expected_keys: &Base64,
+) -> Result<(), MacVerificationError> {
+    if !constant_time_eq(keys.as_bytes(), expected_keys.as_bytes()) {
+        return Err(MacVerificationError::KeysMismatch);
+    }
+
+    if let Some(key) = mac.keys().find(|key| !expected_mac.contains_key(*key)) {
+        return Err(MacVerificationError::MacMismatch { key: key.clone() });
+    }
+
+    for (key, expected) in expected_mac {
+        let matches =
+            mac.get(key).is_some_and(|mac| constant_time_eq(mac.as_bytes(), expected.as_bytes()));
+        if !matches {
+            return Err(MacVerificationError::MacMismatch { key: key.clone() });
+        }
+    }
+
+    Ok(())
+}
+
+/// Compare the given byte slices in constant time.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.ct_eq(b).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use ruma_common::{owned_event_id, serde::Base64};
+
+    use super::{KeyVerificationMacEventContent, MacVerificationError};
+    use crate::relation::Reference;
+
+    fn base64(s: &str) -> Base64 {
+        Base64::parse(s).unwrap()
+    }
+
+    fn content() -> KeyVerificationMacEventContent {
+        KeyVerificationMacEventContent::new(
+            BTreeMap::from([
+                (
+                    "ed25519:ABCDEFGHIJ".to_owned(),
+                    base64("bxxXNm5DNWPEDb9zJJFkGK07uU6Ih4tHF0E9jWLhhys"),
+                ),
+                (
+                    "ed25519:NCbTn0fjk7ldWbE2Bpk8Ek0MeOfcaTUqdqgX/XXJnIs".to_owned(),
+                    base64("xbATk2M3yD9Em+JAVN/HlDnE9N4m/A4v4OiWfNlQe4Q"),
+                ),
+            ]),
+            base64("mp6m4YdT9oWlMYeRUtWz6CozfH94LCXQwL+zRHkbNxk"),
+            Reference::new(owned_event_id!("$1598361704261elfgc:localhost")),
+        )
+    }
+
+    #[test]
+    fn verify_codes_success() {
+        let content = content();
+        content.verify_codes(&content.mac.clone(), &content.keys.clone()).unwrap();
+    }
+
+    #[test]
+    fn verify_codes_keys_mismatch() {
+        let content = content();
+        assert_eq!(
+            content.verify_codes(
+                &content.mac.clone(),
+                &base64("nq6m4YdT9oWlMYeRUtWz6CozfH94LCXQwL+zRHkbNxk")
+            ),
+            Err(MacVerificationError::KeysMismatch)
+        );
+    }
+
+    #[test]
+    fn verify_codes_mac_mismatch() {
+        let content = content();
+
+        let mut expected_mac = content.mac.clone();
+        expected_mac.insert(
+            "ed25519:ABCDEFGHIJ".to_owned(),
+            base64("cxxXNm5DNWPEDb9zJJFkGK07uU6Ih4tHF0E9jWLhhys"),
+        );
+        assert_eq!(
+            content.verify_codes(&expected_mac, &content.keys),
+            Err(MacVerificationError::MacMismatch { key: "ed25519:ABCDEFGHIJ".to_owned() })
+        );
+
+        // Missing key in the event.
+        let mut expected_mac = content.mac.clone();
+        expected_mac.insert(
+            "ed25519:KLMNOPQRST".to_owned(),
+            base64("bxxXNm5DNWPEDb9zJJFkGK07uU6Ih4tHF0E9jWLhhys"),
+        );
+        assert_eq!(
+            content.verify_codes(&expected_mac, &content.keys),
+            Err(MacVerificationError::MacMismatch { key: "ed25519:KLMNOPQRST".to_owned() })
+        );
+
+        // Missing key in the expected MACs.
+        let mut expected_mac = content.mac.clone();
+        expected_mac.remove("ed25519:ABCDEFGHIJ");
+        assert_eq!(
+            content.verify_codes(&expected_mac, &content.keys),
+            Err(MacVerificationError::MacMismatch { key: "ed25519:ABCDEFGHIJ".to_owned() })
+        );
+    }
 }
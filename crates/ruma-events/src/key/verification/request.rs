@@ -42,4 +42,70 @@ impl ToDeviceKeyVerificationRequestEventContent {
     ) -> Self {
         Self { from_device, transaction_id, methods, timestamp }
     }
+
+    /// Whether the given verification method is supported by the sender.
+    pub fn methods_include(&self, method: &VerificationMethod) -> bool {
+        self.methods.contains(method)
+    }
+
+    /// The verification methods supported by both the sender of this request and the sender of
+    /// the other request.
+    pub fn common_methods<'a>(
+        &'a self,
+        other: &'a Self,
+    ) -> impl Iterator<Item = &'a VerificationMethod> + 'a {
+        self.methods.iter().filter(|method| other.methods_include(method))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ruma_common::{owned_device_id, MilliSecondsSinceUnixEpoch};
+
+    use super::ToDeviceKeyVerificationRequestEventContent;
+    use crate::key::verification::VerificationMethod;
+
+    fn request(methods: Vec<VerificationMethod>) -> ToDeviceKeyVerificationRequestEventContent {
+        ToDeviceKeyVerificationRequestEventContent::new(
+            owned_device_id!("123"),
+            "456".into(),
+            methods,
+            MilliSecondsSinceUnixEpoch::now(),
+        )
+    }
+
+    #[test]
+    fn methods_include() {
+        let empty = request(vec![]);
+        assert!(!empty.methods_include(&VerificationMethod::SasV1));
+
+        let sas = request(vec![VerificationMethod::SasV1]);
+        assert!(sas.methods_include(&VerificationMethod::SasV1));
+        assert!(!sas.methods_include(&VerificationMethod::QrCodeScanV1));
+    }
+
+    #[test]
+    fn common_methods() {
+        let empty = request(vec![]);
+        let sas = request(vec![VerificationMethod::SasV1]);
+        let qr = request(vec![VerificationMethod::QrCodeShowV1, VerificationMethod::ReciprocateV1]);
+        let all = request(vec![
+            VerificationMethod::SasV1,
+            VerificationMethod::QrCodeScanV1,
+            VerificationMethod::QrCodeShowV1,
+            VerificationMethod::ReciprocateV1,
+        ]);
+
+        assert_eq!(empty.common_methods(&all).count(), 0);
+        assert_eq!(all.common_methods(&empty).count(), 0);
+
+        assert_eq!(sas.common_methods(&all).collect::<Vec<_>>(), [&VerificationMethod::SasV1]);
+        assert_eq!(all.common_methods(&sas).collect::<Vec<_>>(), [&VerificationMethod::SasV1]);
+
+        assert_eq!(sas.common_methods(&qr).count(), 0);
+        assert_eq!(
+            all.common_methods(&qr).collect::<Vec<_>>(),
+            [&VerificationMethod::QrCodeShowV1, &VerificationMethod::ReciprocateV1]
+        );
+    }
 }
@@ -16,7 +16,7 @@ use super::{
     MessageLikeEventType, MessageLikeUnsigned, PossiblyRedactedStateEventContent, RedactContent,
     RedactedMessageLikeEventContent, RedactedStateEventContent, RedactedUnsigned,
     RedactionDeHelper, RoomAccountDataEventContent, StateEventType, StaticStateEventContent,
-    ToDeviceEventContent,
+    ToDeviceEventContent, UnsignedRoomRedactionEvent,
 };
 
 /// A global account data event.
@@ -623,6 +623,11 @@ macro_rules! impl_possibly_redacted_event {
                 }
             }
 
+            /// Returns the redaction event from inside `unsigned`, if this event was redacted.
+            pub fn redacted_because(&self) -> Option<&UnsignedRoomRedactionEvent> {
+                as_variant!(self, Self::Redacted).map(|ev| &ev.unsigned.redacted_because)
+            }
+
             // So the room_id method can be in the same impl block, in rustdoc
             $($extra)*
         }
@@ -666,6 +671,35 @@ impl_possibly_redacted_event!(
         pub fn as_original(&self) -> Option<&OriginalMessageLikeEvent<C>> {
             as_variant!(self, Self::Original)
         }
+
+        /// Transform `self` into its redacted form, removing the fields of the content that are
+        /// not preserved by redaction.
+        ///
+        /// If `self` is already redacted, it is returned unmodified.
+        ///
+        /// A small number of events have room-version specific redaction behavior, so a version
+        /// has to be specified.
+        pub fn into_redacted(
+            self,
+            redacted_because: UnsignedRoomRedactionEvent,
+            version: &RoomVersionId,
+        ) -> Self {
+            match self {
+                Self::Original(ev) => {
+                    let unsigned = RedactedUnsigned::new(redacted_because);
+                    let content = ev.content.redact(version);
+                    Self::Redacted(RedactedMessageLikeEvent {
+                        content,
+                        event_id: ev.event_id,
+                        sender: ev.sender,
+                        origin_server_ts: ev.origin_server_ts,
+                        room_id: ev.room_id,
+                        unsigned,
+                    })
+                }
+                Self::Redacted(_) => self,
+            }
+        }
     }
 );
 
@@ -678,6 +712,34 @@ impl_possibly_redacted_event!(
             as_variant!(self, Self::Original)
         }
 
+        /// Transform `self` into its redacted form, removing the fields of the content that are
+        /// not preserved by redaction.
+        ///
+        /// If `self` is already redacted, it is returned unmodified.
+        ///
+        /// A small number of events have room-version specific redaction behavior, so a version
+        /// has to be specified.
+        pub fn into_redacted(
+            self,
+            redacted_because: UnsignedRoomRedactionEvent,
+            version: &RoomVersionId,
+        ) -> Self {
+            match self {
+                Self::Original(ev) => {
+                    let unsigned = RedactedUnsigned::new(redacted_because);
+                    let content = ev.content.redact(version);
+                    Self::Redacted(RedactedSyncMessageLikeEvent {
+                        content,
+                        event_id: ev.event_id,
+                        sender: ev.sender,
+                        origin_server_ts: ev.origin_server_ts,
+                        unsigned,
+                    })
+                }
+                Self::Redacted(_) => self,
+            }
+        }
+
         /// Convert this sync event into a full event (one with a `room_id` field).
         pub fn into_full_event(self, room_id: OwnedRoomId) -> MessageLikeEvent<C> {
             match self {
@@ -713,6 +775,36 @@ impl_possibly_redacted_event!(
         pub fn as_original(&self) -> Option<&OriginalStateEvent<C>> {
             as_variant!(self, Self::Original)
         }
+
+        /// Transform `self` into its redacted form, removing the fields of the content that are
+        /// not preserved by redaction.
+        ///
+        /// If `self` is already redacted, it is returned unmodified.
+        ///
+        /// A small number of events have room-version specific redaction behavior, so a version
+        /// has to be specified.
+        pub fn into_redacted(
+            self,
+            redacted_because: UnsignedRoomRedactionEvent,
+            version: &RoomVersionId,
+        ) -> Self {
+            match self {
+                Self::Original(ev) => {
+                    let unsigned = RedactedUnsigned::new(redacted_because);
+                    let content = ev.content.redact(version);
+                    Self::Redacted(RedactedStateEvent {
+                        content,
+                        event_id: ev.event_id,
+                        sender: ev.sender,
+                        origin_server_ts: ev.origin_server_ts,
+                        room_id: ev.room_id,
+                        state_key: ev.state_key,
+                        unsigned,
+                    })
+                }
+                Self::Redacted(_) => self,
+            }
+        }
     }
 );
 
@@ -734,6 +826,35 @@ impl_possibly_redacted_event!(
             as_variant!(self, Self::Original)
         }
 
+        /// Transform `self` into its redacted form, removing the fields of the content that are
+        /// not preserved by redaction.
+        ///
+        /// If `self` is already redacted, it is returned unmodified.
+        ///
+        /// A small number of events have room-version specific redaction behavior, so a version
+        /// has to be specified.
+        pub fn into_redacted(
+            self,
+            redacted_because: UnsignedRoomRedactionEvent,
+            version: &RoomVersionId,
+        ) -> Self {
+            match self {
+                Self::Original(ev) => {
+                    let unsigned = RedactedUnsigned::new(redacted_because);
+                    let content = ev.content.redact(version);
+                    Self::Redacted(RedactedSyncStateEvent {
+                        content,
+                        event_id: ev.event_id,
+                        sender: ev.sender,
+                        origin_server_ts: ev.origin_server_ts,
+                        state_key: ev.state_key,
+                        unsigned,
+                    })
+                }
+                Self::Redacted(_) => self,
+            }
+        }
+
         /// Convert this sync event into a full event (one with a `room_id` field).
         pub fn into_full_event(self, room_id: OwnedRoomId) -> StateEvent<C> {
             match self {
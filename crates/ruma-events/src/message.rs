@@ -212,17 +212,28 @@ impl TextContentBlock {
 
     /// Get the plain text representation of this message.
     pub fn find_plain(&self) -> Option<&str> {
-        self.iter()
-            .find(|content| content.mimetype == "text/plain")
-            .map(|content| content.body.as_ref())
+        self.find_mimetype("text/plain")
     }
 
     /// Get the HTML representation of this message.
     pub fn find_html(&self) -> Option<&str> {
+        self.find_mimetype("text/html")
+    }
+
+    /// Get the first representation of this message with the given MIME type.
+    pub fn find_mimetype(&self, mimetype: &str) -> Option<&str> {
         self.iter()
-            .find(|content| content.mimetype == "text/html")
+            .find(|content| content.mimetype == mimetype)
             .map(|content| content.body.as_ref())
     }
+
+    /// Get the representation of this message with the given MIME type, falling back to the HTML
+    /// representation and then to the plain text representation.
+    pub fn preferred_representation(&self, preferred_mimetype: &str) -> Option<&str> {
+        self.find_mimetype(preferred_mimetype)
+            .or_else(|| self.find_html())
+            .or_else(|| self.find_plain())
+    }
 }
 
 impl From<Vec<TextRepresentation>> for TextContentBlock {
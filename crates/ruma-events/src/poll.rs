@@ -36,10 +36,123 @@ pub struct PollResponseData<'a> {
     pub selections: &'a [String],
 }
 
+impl PollResponseData<'_> {
+    /// Check whether this response is valid for the given poll.
+    ///
+    /// The response must have been sent after the poll started at `poll_start_ts` and not after
+    /// `end_time`, and its selections must be known answers of the poll.
+    ///
+    /// Note that [`compile_poll_results()`] doesn't discard responses with too many selections, it
+    /// only counts the first `max_selections` of them.
+    pub fn is_valid_for_poll(
+        &self,
+        poll: &PollContentBlock,
+        poll_start_ts: MilliSecondsSinceUnixEpoch,
+        end_time: PollEndTime,
+    ) -> Result<(), PollResponseValidationError> {
+        let answer_ids = poll.answers.iter().map(|a| a.id.as_str()).collect();
+        self.validate(&answer_ids, poll.max_selections, Some(poll_start_ts), end_time.timestamp())
+    }
+
+    /// Check whether this response is valid for the given unstable poll.
+    ///
+    /// The response must have been sent after the poll started at `poll_start_ts` and not after
+    /// `end_time`, and its selections must be known answers of the poll.
+    ///
+    /// Note that [`compile_unstable_poll_results()`] doesn't discard responses with too many
+    /// selections, it only counts the first `max_selections` of them.
+    pub fn is_valid_for_unstable_poll(
+        &self,
+        poll: &UnstablePollStartContentBlock,
+        poll_start_ts: MilliSecondsSinceUnixEpoch,
+        end_time: PollEndTime,
+    ) -> Result<(), PollResponseValidationError> {
+        let answer_ids = poll.answers.iter().map(|a| a.id.as_str()).collect();
+        self.validate(&answer_ids, poll.max_selections, Some(poll_start_ts), end_time.timestamp())
+    }
+
+    fn validate(
+        &self,
+        answer_ids: &BTreeSet<&str>,
+        max_selections: UInt,
+        start_timestamp: Option<MilliSecondsSinceUnixEpoch>,
+        end_timestamp: Option<MilliSecondsSinceUnixEpoch>,
+    ) -> Result<(), PollResponseValidationError> {
+        self.check_time(start_timestamp, end_timestamp)?;
+
+        if self.selections.is_empty() {
+            return Err(PollResponseValidationError::NoValidSelections);
+        }
+
+        check_selections(answer_ids, max_selections, self.selections)
+    }
+
+    fn check_time(
+        &self,
+        start_timestamp: Option<MilliSecondsSinceUnixEpoch>,
+        end_timestamp: Option<MilliSecondsSinceUnixEpoch>,
+    ) -> Result<(), PollResponseValidationError> {
+        if start_timestamp.is_some_and(|start_ts| self.origin_server_ts < start_ts) {
+            return Err(PollResponseValidationError::TooEarly);
+        }
+
+        if end_timestamp.is_some_and(|end_ts| self.origin_server_ts > end_ts) {
+            return Err(PollResponseValidationError::TooLate);
+        }
+
+        Ok(())
+    }
+}
+
+/// An error encountered when validating a poll response.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, thiserror::Error)]
+#[non_exhaustive]
+pub enum PollResponseValidationError {
+    /// The response was sent before the poll started.
+    #[error("response was sent before the poll started")]
+    TooEarly,
+
+    /// The response was sent after the poll ended.
+    #[error("response was sent after the poll ended")]
+    TooLate,
+
+    /// The response has no selections, or selections that are not answers of the poll.
+    #[error("response has no valid selections")]
+    NoValidSelections,
+
+    /// The response has more selections than allowed by the poll.
+    #[error("response has too many selections")]
+    TooManySelections,
+}
+
+/// The time after which responses to a poll are ignored when compiling its results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::exhaustive_enums)]
+pub enum PollEndTime {
+    /// Ignore responses sent after the current time.
+    Now,
+
+    /// Ignore responses sent after the given time.
+    At(MilliSecondsSinceUnixEpoch),
+
+    /// Include all responses, regardless of when they were sent.
+    Never,
+}
+
+impl PollEndTime {
+    /// The timestamp after which responses are ignored, if any.
+    fn timestamp(self) -> Option<MilliSecondsSinceUnixEpoch> {
+        match self {
+            Self::Now => Some(MilliSecondsSinceUnixEpoch::now()),
+            Self::At(ts) => Some(ts),
+            Self::Never => None,
+        }
+    }
+}
+
 /// Generate the current results with the given poll and responses.
 ///
-/// If the `end_timestamp` is provided, any response with an `origin_server_ts` after that timestamp
-/// is ignored. If it is not provided, `MilliSecondsSinceUnixEpoch::now()` will be used instead.
+/// Any response with an `origin_server_ts` after the given `end_time` is ignored.
 ///
 /// This method will handle invalid responses, or several response from the same user so all
 /// responses to the poll should be provided.
@@ -50,19 +163,18 @@ pub struct PollResponseData<'a> {
 pub fn compile_poll_results<'a>(
     poll: &'a PollContentBlock,
     responses: impl IntoIterator<Item = PollResponseData<'a>>,
-    end_timestamp: Option<MilliSecondsSinceUnixEpoch>,
+    end_time: PollEndTime,
 ) -> IndexMap<&'a str, BTreeSet<&'a UserId>> {
     let answer_ids = poll.answers.iter().map(|a| a.id.as_str()).collect();
     let users_selections =
-        filter_selections(answer_ids, poll.max_selections, responses, end_timestamp);
+        filter_selections(answer_ids, poll.max_selections, responses, end_time.timestamp());
 
     aggregate_results(poll.answers.iter().map(|a| a.id.as_str()), users_selections)
 }
 
 /// Generate the current results with the given unstable poll and responses.
 ///
-/// If the `end_timestamp` is provided, any response with an `origin_server_ts` after that timestamp
-/// is ignored. If it is not provided, `MilliSecondsSinceUnixEpoch::now()` will be used instead.
+/// Any response with an `origin_server_ts` after the given `end_time` is ignored.
 ///
 /// This method will handle invalid responses, or several response from the same user so all
 /// responses to the poll should be provided.
@@ -73,24 +185,44 @@ pub fn compile_poll_results<'a>(
 pub fn compile_unstable_poll_results<'a>(
     poll: &'a UnstablePollStartContentBlock,
     responses: impl IntoIterator<Item = PollResponseData<'a>>,
-    end_timestamp: Option<MilliSecondsSinceUnixEpoch>,
+    end_time: PollEndTime,
 ) -> IndexMap<&'a str, BTreeSet<&'a UserId>> {
     let answer_ids = poll.answers.iter().map(|a| a.id.as_str()).collect();
     let users_selections =
-        filter_selections(answer_ids, poll.max_selections, responses, end_timestamp);
+        filter_selections(answer_ids, poll.max_selections, responses, end_time.timestamp());
 
     aggregate_results(poll.answers.iter().map(|a| a.id.as_str()), users_selections)
 }
 
+/// Check that the given selections are known answers and that there are not too many of them.
+fn check_selections(
+    answer_ids: &BTreeSet<&str>,
+    max_selections: UInt,
+    selections: &[String],
+) -> Result<(), PollResponseValidationError> {
+    // Vote is spoiled if any answer is unknown.
+    if selections.iter().any(|s| !answer_ids.contains(s.as_str())) {
+        return Err(PollResponseValidationError::NoValidSelections);
+    }
+
+    if UInt::try_from(selections.len()).map_or(true, |len| len > max_selections) {
+        return Err(PollResponseValidationError::TooManySelections);
+    }
+
+    Ok(())
+}
+
 /// Validate the selections of a response.
+///
+/// Selections with unknown answers are spoiled, and only the first `max_selections` are kept.
 fn validate_selections<'a>(
     answer_ids: &BTreeSet<&str>,
     max_selections: UInt,
     selections: &'a [String],
 ) -> Option<impl Iterator<Item = &'a str>> {
-    // Vote is spoiled if any answer is unknown.
-    if selections.iter().any(|s| !answer_ids.contains(s.as_str())) {
-        return None;
+    match check_selections(answer_ids, max_selections, selections) {
+        Ok(()) | Err(PollResponseValidationError::TooManySelections) => {}
+        Err(_) => return None,
     }
 
     // Fallback to the maximum value for usize because we can't have more selections than that
@@ -108,9 +240,12 @@ fn filter_selections<'a>(
 ) -> BTreeMap<&'a UserId, (MilliSecondsSinceUnixEpoch, Option<impl Iterator<Item = &'a str>>)> {
     responses
         .into_iter()
-        .filter(|ev| {
+        .filter(|data| {
             // Filter out responses after the end_timestamp.
-            end_timestamp.map_or(true, |end_ts| ev.origin_server_ts <= end_ts)
+            !matches!(
+                data.check_time(None, end_timestamp),
+                Err(PollResponseValidationError::TooLate)
+            )
         })
         .fold(BTreeMap::new(), |mut acc, data| {
             let response =
@@ -154,50 +289,69 @@ fn aggregate_results<'a>(
     results
 }
 
-/// Generate the fallback text representation of a poll end event.
+/// A formatter for the fallback text representation of a poll end event.
 ///
-/// This is a sentence that lists the top answers for the given results, in english. It is used to
-/// generate a valid poll end event when using
-/// `OriginalSync(Unstable)PollStartEvent::compile_results()`.
+/// It is used to generate a valid poll end event when using
+/// `OriginalSync(Unstable)PollStartEvent::compile_results_with_formatter()`, for example to
+/// localize the text.
+pub trait PollFallbackFormatter {
+    /// Generate the fallback text representation of a poll end event.
+    ///
+    /// `answers` is a list of `(answer ID, answer plain text representation)` and `results` is an
+    /// iterator of `(answer ID, count)` ordered in descending order.
+    fn format_results<'a>(
+        &self,
+        answers: &[(&'a str, &'a str)],
+        results: impl Iterator<Item = (&'a str, usize)>,
+    ) -> String;
+}
+
+/// The default [`PollFallbackFormatter`], in English.
 ///
-/// `answers` is an iterator of `(answer ID, answer plain text representation)` and `results` is an
-/// iterator of `(answer ID, count)` ordered in descending order.
-fn generate_poll_end_fallback_text<'a>(
-    answers: &[(&'a str, &'a str)],
-    results: impl Iterator<Item = (&'a str, usize)>,
-) -> String {
-    let mut top_answers = Vec::new();
-    let mut top_count = 0;
-
-    for (id, count) in results {
-        if count >= top_count {
-            top_answers.push(id);
-            top_count = count;
-        } else {
-            break;
-        }
-    }
+/// This is a sentence that lists the top answers for the given results.
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(clippy::exhaustive_structs)]
+pub struct EnglishPollFallbackFormatter;
 
-    let top_answers_text = top_answers
-        .into_iter()
-        .map(|id| {
-            answers
-                .iter()
-                .find(|(a_id, _)| *a_id == id)
-                .expect("top answer ID should be a valid answer ID")
-                .1
-        })
-        .collect::<Vec<_>>();
+impl PollFallbackFormatter for EnglishPollFallbackFormatter {
+    fn format_results<'a>(
+        &self,
+        answers: &[(&'a str, &'a str)],
+        results: impl Iterator<Item = (&'a str, usize)>,
+    ) -> String {
+        let mut top_answers = Vec::new();
+        let mut top_count = 0;
 
-    // Construct the plain text representation.
-    match top_answers_text.len() {
-        0 => "The poll has closed with no top answer".to_owned(),
-        1 => {
-            format!("The poll has closed. Top answer: {}", top_answers_text[0])
+        for (id, count) in results {
+            if count >= top_count {
+                top_answers.push(id);
+                top_count = count;
+            } else {
+                break;
+            }
         }
-        _ => {
-            let answers = top_answers_text.join(", ");
-            format!("The poll has closed. Top answers: {answers}")
+
+        let top_answers_text = top_answers
+            .into_iter()
+            .map(|id| {
+                answers
+                    .iter()
+                    .find(|(a_id, _)| *a_id == id)
+                    .expect("top answer ID should be a valid answer ID")
+                    .1
+            })
+            .collect::<Vec<_>>();
+
+        // Construct the plain text representation.
+        match top_answers_text.len() {
+            0 => "The poll has closed with no top answer".to_owned(),
+            1 => {
+                format!("The poll has closed. Top answer: {}", top_answers_text[0])
+            }
+            _ => {
+                let answers = top_answers_text.join(", ");
+                format!("The poll has closed. Top answers: {answers}")
+            }
         }
     }
 }
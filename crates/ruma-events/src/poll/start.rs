@@ -2,8 +2,9 @@
 
 use std::ops::Deref;
 
+use as_variant::as_variant;
 use js_int::{uint, UInt};
-use ruma_common::{serde::StringEnum, MilliSecondsSinceUnixEpoch};
+use ruma_common::serde::StringEnum;
 use ruma_macros::EventContent;
 use serde::{Deserialize, Serialize};
 
@@ -16,9 +17,14 @@ use poll_answers_serde::PollAnswersDeHelper;
 use super::{
     compile_poll_results,
     end::{PollEndEventContent, PollResultsContentBlock},
-    generate_poll_end_fallback_text, PollResponseData,
+    unstable_start::{
+        NewUnstablePollStartEventContent, NewUnstablePollStartEventContentWithoutRelation,
+        ReplacementUnstablePollStartEventContent, UnstablePollAnswer, UnstablePollAnswers,
+        UnstablePollQuestion, UnstablePollStartContentBlock, UnstablePollStartEventContent,
+    },
+    EnglishPollFallbackFormatter, PollEndTime, PollFallbackFormatter, PollResponseData,
 };
-use crate::{message::TextContentBlock, room::message::Relation};
+use crate::{message::TextContentBlock, relation::Replacement, room::message::Relation};
 
 /// The payload for a poll start event.
 ///
@@ -77,6 +83,52 @@ impl PollStartEventContent {
     pub fn with_plain_text(plain_text: impl Into<String>, poll: PollContentBlock) -> Self {
         Self::new(TextContentBlock::plain(plain_text), poll)
     }
+
+    /// Whether this is a new poll start event, i.e. it doesn't replace another one.
+    pub fn is_new(&self) -> bool {
+        !self.is_replacement()
+    }
+
+    /// Whether this poll start event replaces another one.
+    pub fn is_replacement(&self) -> bool {
+        self.as_replacement().is_some()
+    }
+
+    /// Get the replacement relation of this poll start event, if any.
+    pub fn as_replacement(&self) -> Option<&Replacement<PollStartEventContentWithoutRelation>> {
+        as_variant!(&self.relates_to, Some(Relation::Replacement(replacement)) => replacement)
+    }
+
+    /// Convert this content to the equivalent [`UnstablePollStartEventContent`].
+    ///
+    /// The unstable format only supports plain text, so the plain text representations are used.
+    /// If the question or an answer doesn't have one, its first representation is used instead.
+    pub fn to_unstable(&self) -> UnstablePollStartEventContent {
+        let poll_start = self.poll.to_unstable();
+        let text = self.text.find_plain().map(ToOwned::to_owned);
+
+        match &self.relates_to {
+            Some(Relation::Replacement(replacement)) => {
+                let new_content = NewUnstablePollStartEventContentWithoutRelation {
+                    poll_start: replacement.new_content.poll.to_unstable(),
+                    text: replacement.new_content.text.find_plain().map(ToOwned::to_owned),
+                };
+
+                ReplacementUnstablePollStartEventContent {
+                    poll_start: Some(poll_start),
+                    text,
+                    relates_to: Replacement::new(replacement.event_id.clone(), new_content),
+                }
+                .into()
+            }
+            relates_to => NewUnstablePollStartEventContent {
+                poll_start,
+                text,
+                relates_to: relates_to.clone().and_then(|relation| relation.try_into().ok()),
+            }
+            .into(),
+        }
+    }
 }
 
 impl OriginalSyncPollStartEvent {
@@ -89,11 +141,20 @@ impl OriginalSyncPollStartEvent {
         &'a self,
         responses: impl IntoIterator<Item = PollResponseData<'a>>,
     ) -> PollEndEventContent {
-        let full_results = compile_poll_results(
-            &self.content.poll,
-            responses,
-            Some(MilliSecondsSinceUnixEpoch::now()),
-        );
+        self.compile_results_with_formatter(responses, &EnglishPollFallbackFormatter)
+    }
+
+    /// Compile the results for this poll with the given response into a `PollEndEventContent`.
+    ///
+    /// It generates the text representation of the results with the given formatter.
+    ///
+    /// This uses [`compile_poll_results()`] internally.
+    pub fn compile_results_with_formatter<'a>(
+        &'a self,
+        responses: impl IntoIterator<Item = PollResponseData<'a>>,
+        formatter: &impl PollFallbackFormatter,
+    ) -> PollEndEventContent {
+        let full_results = compile_poll_results(&self.content.poll, responses, PollEndTime::Now);
         let results =
             full_results.into_iter().map(|(id, users)| (id, users.len())).collect::<Vec<_>>();
 
@@ -115,7 +176,7 @@ impl OriginalSyncPollStartEvent {
                 (a.id.as_str(), text)
             })
             .collect::<Vec<_>>();
-        let plain_text = generate_poll_end_fallback_text(&answers, results.into_iter());
+        let plain_text = formatter.format_results(&answers, results.into_iter());
 
         let mut end = PollEndEventContent::with_plain_text(plain_text, self.event_id.clone());
         end.poll_results = Some(poll_results);
@@ -161,6 +222,24 @@ impl PollContentBlock {
         }
     }
 
+    /// Convert this block to the equivalent [`UnstablePollStartContentBlock`].
+    ///
+    /// The unstable format only supports plain text, so the plain text representations are used.
+    /// If the question or an answer doesn't have one, its first representation is used instead.
+    pub fn to_unstable(&self) -> UnstablePollStartContentBlock {
+        UnstablePollStartContentBlock {
+            question: UnstablePollQuestion::new(plain_text_or_first(&self.question.text)),
+            kind: self.kind.clone(),
+            max_selections: self.max_selections,
+            answers: UnstablePollAnswers(
+                self.answers
+                    .iter()
+                    .map(|a| UnstablePollAnswer::new(&a.id, plain_text_or_first(&a.text)))
+                    .collect(),
+            ),
+        }
+    }
+
     pub(super) fn default_max_selections() -> UInt {
         uint!(1)
     }
@@ -210,7 +289,7 @@ pub enum PollKind {
 /// To build this, use the `TryFrom` implementations.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(try_from = "PollAnswersDeHelper")]
-pub struct PollAnswers(Vec<PollAnswer>);
+pub struct PollAnswers(pub(super) Vec<PollAnswer>);
 
 impl PollAnswers {
     /// The smallest number of values contained in a `PollAnswers`.
@@ -283,3 +362,9 @@ impl PollAnswer {
         Self { id, text }
     }
 }
+
+/// Get the plain text representation of the given block, or its first representation if there is
+/// none.
+fn plain_text_or_first(text: &TextContentBlock) -> &str {
+    text.find_plain().or_else(|| text.first().map(|r| r.body.as_str())).unwrap_or_default()
+}
@@ -2,6 +2,7 @@
 
 use std::ops::Deref;
 
+use as_variant::as_variant;
 use js_int::UInt;
 use ruma_macros::EventContent;
 use serde::{Deserialize, Serialize};
@@ -10,19 +11,24 @@ mod content_serde;
 mod unstable_poll_answers_serde;
 mod unstable_poll_kind_serde;
 
-use ruma_common::{MilliSecondsSinceUnixEpoch, OwnedEventId};
+use ruma_common::OwnedEventId;
 
 use self::unstable_poll_answers_serde::UnstablePollAnswersDeHelper;
 use super::{
-    compile_unstable_poll_results, generate_poll_end_fallback_text,
-    start::{PollAnswers, PollAnswersError, PollContentBlock, PollKind},
+    compile_unstable_poll_results,
+    start::{
+        PollAnswer, PollAnswers, PollAnswersError, PollContentBlock, PollKind,
+        PollStartEventContent, PollStartEventContentWithoutRelation,
+    },
     unstable_end::UnstablePollEndEventContent,
-    PollResponseData,
+    EnglishPollFallbackFormatter, PollEndTime, PollFallbackFormatter, PollResponseData,
 };
 use crate::{
-    relation::Replacement, room::message::RelationWithoutReplacement, EventContent,
-    MessageLikeEventContent, MessageLikeEventType, RedactContent, RedactedMessageLikeEventContent,
-    StaticEventContent,
+    message::TextContentBlock,
+    relation::Replacement,
+    room::message::{Relation, RelationWithoutReplacement},
+    EventContent, MessageLikeEventContent, MessageLikeEventType, RedactContent,
+    RedactedMessageLikeEventContent, StaticEventContent,
 };
 
 /// The payload for an unstable poll start event.
@@ -56,6 +62,80 @@ impl UnstablePollStartEventContent {
             Self::Replacement(c) => &c.relates_to.new_content.poll_start,
         }
     }
+
+    /// Whether this is a new poll start event content.
+    pub fn is_new(&self) -> bool {
+        matches!(self, Self::New(_))
+    }
+
+    /// Whether this is a replacement poll start event content.
+    pub fn is_replacement(&self) -> bool {
+        matches!(self, Self::Replacement(_))
+    }
+
+    /// Get the inner content if this is a new poll start event content.
+    pub fn as_new(&self) -> Option<&NewUnstablePollStartEventContent> {
+        as_variant!(self, Self::New)
+    }
+
+    /// Get the inner content if this is a replacement poll start event content.
+    pub fn as_replacement(&self) -> Option<&ReplacementUnstablePollStartEventContent> {
+        as_variant!(self, Self::Replacement)
+    }
+
+    /// Convert this content to the equivalent [`PollStartEventContent`].
+    ///
+    /// The text representations are converted to plain text representations.
+    ///
+    /// Returns an error if the content doesn't have a text fallback, since it is required by
+    /// `PollStartEventContent`. The fallback of a replacement falls back to the one of the new
+    /// content.
+    pub fn to_stable(&self) -> Result<PollStartEventContent, PollConversionError> {
+        match self {
+            Self::New(c) => {
+                let text = c.text.as_deref().ok_or(PollConversionError::MissingTextFallback)?;
+
+                let mut content =
+                    PollStartEventContent::with_plain_text(text, c.poll_start.to_stable());
+                content.relates_to = c.relates_to.clone().map(Into::into);
+
+                Ok(content)
+            }
+            Self::Replacement(c) => {
+                let new_content = &c.relates_to.new_content;
+                let new_text =
+                    new_content.text.as_deref().ok_or(PollConversionError::MissingTextFallback)?;
+                let stable_new_content = PollStartEventContentWithoutRelation::from(
+                    PollStartEventContent::with_plain_text(
+                        new_text,
+                        new_content.poll_start.to_stable(),
+                    ),
+                );
+
+                let poll_start = c.poll_start.as_ref().unwrap_or(&new_content.poll_start);
+                let text = c.text.as_deref().unwrap_or(new_text);
+
+                let mut content =
+                    PollStartEventContent::with_plain_text(text, poll_start.to_stable());
+                content.relates_to = Some(Relation::Replacement(Replacement::new(
+                    c.relates_to.event_id.clone(),
+                    stable_new_content,
+                )));
+
+                Ok(content)
+            }
+        }
+    }
+}
+
+/// An error encountered when trying to convert an unstable poll start content to its stable
+/// equivalent.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, thiserror::Error)]
+#[non_exhaustive]
+pub enum PollConversionError {
+    /// The unstable content doesn't have a text fallback.
+    #[error("missing text fallback")]
+    MissingTextFallback,
 }
 
 impl RedactContent for UnstablePollStartEventContent {
@@ -88,21 +168,31 @@ impl OriginalSyncUnstablePollStartEvent {
     pub fn compile_results<'a>(
         &'a self,
         responses: impl IntoIterator<Item = PollResponseData<'a>>,
+    ) -> UnstablePollEndEventContent {
+        self.compile_results_with_formatter(responses, &EnglishPollFallbackFormatter)
+    }
+
+    /// Compile the results for this poll with the given response into an
+    /// `UnstablePollEndEventContent`.
+    ///
+    /// It generates the text representation of the results with the given formatter.
+    ///
+    /// This uses [`compile_unstable_poll_results()`] internally.
+    pub fn compile_results_with_formatter<'a>(
+        &'a self,
+        responses: impl IntoIterator<Item = PollResponseData<'a>>,
+        formatter: &impl PollFallbackFormatter,
     ) -> UnstablePollEndEventContent {
         let poll_start = self.content.poll_start();
 
-        let full_results = compile_unstable_poll_results(
-            poll_start,
-            responses,
-            Some(MilliSecondsSinceUnixEpoch::now()),
-        );
+        let full_results = compile_unstable_poll_results(poll_start, responses, PollEndTime::Now);
         let results =
             full_results.into_iter().map(|(id, users)| (id, users.len())).collect::<Vec<_>>();
 
         // Get the text representation of the best answers.
         let answers =
             poll_start.answers.iter().map(|a| (a.id.as_str(), a.text.as_str())).collect::<Vec<_>>();
-        let plain_text = generate_poll_end_fallback_text(&answers, results.into_iter());
+        let plain_text = formatter.format_results(&answers, results.into_iter());
 
         UnstablePollEndEventContent::new(plain_text, self.event_id.clone())
     }
@@ -117,7 +207,7 @@ pub struct NewUnstablePollStartEventContent {
     pub poll_start: UnstablePollStartContentBlock,
 
     /// Text representation of the message, for clients that don't support polls.
-    #[serde(rename = "org.matrix.msc1767.text")]
+    #[serde(rename = "org.matrix.msc1767.text", skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
 
     /// Information about related messages.
@@ -164,7 +254,7 @@ pub struct NewUnstablePollStartEventContentWithoutRelation {
     pub poll_start: UnstablePollStartContentBlock,
 
     /// Text representation of the message, for clients that don't support polls.
-    #[serde(rename = "org.matrix.msc1767.text")]
+    #[serde(rename = "org.matrix.msc1767.text", skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
 }
 
@@ -223,6 +313,13 @@ impl ReplacementUnstablePollStartEventContent {
             },
         }
     }
+
+    /// Set the given poll content as the fallback of this replacement, for clients that don't
+    /// support replacements.
+    pub fn with_outer_fallback(mut self, poll_start: UnstablePollStartContentBlock) -> Self {
+        self.poll_start = Some(poll_start);
+        self
+    }
 }
 
 impl EventContent for ReplacementUnstablePollStartEventContent {
@@ -298,6 +395,23 @@ impl UnstablePollStartContentBlock {
             answers,
         }
     }
+
+    /// Convert this block to the equivalent [`PollContentBlock`].
+    ///
+    /// The text representations are converted to plain text representations.
+    pub fn to_stable(&self) -> PollContentBlock {
+        PollContentBlock {
+            question: TextContentBlock::plain(&self.question.text).into(),
+            kind: self.kind.clone(),
+            max_selections: self.max_selections,
+            answers: PollAnswers(
+                self.answers
+                    .iter()
+                    .map(|a| PollAnswer::new(a.id.clone(), TextContentBlock::plain(&a.text)))
+                    .collect(),
+            ),
+        }
+    }
 }
 
 /// An unstable poll question.
@@ -323,7 +437,7 @@ impl UnstablePollQuestion {
 /// To build this, use one of the `TryFrom` implementations.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(try_from = "UnstablePollAnswersDeHelper")]
-pub struct UnstablePollAnswers(Vec<UnstablePollAnswer>);
+pub struct UnstablePollAnswers(pub(super) Vec<UnstablePollAnswer>);
 
 impl TryFrom<Vec<UnstablePollAnswer>> for UnstablePollAnswers {
     type Error = PollAnswersError;
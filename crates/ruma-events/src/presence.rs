@@ -2,6 +2,8 @@
 //!
 //! The only content valid for this event is `PresenceEventContent`.
 
+use std::time::Duration;
+
 use js_int::UInt;
 use ruma_common::{presence::PresenceState, OwnedMxcUri, OwnedUserId};
 use ruma_macros::{Event, EventContent};
@@ -83,10 +85,38 @@ impl PresenceEventContent {
             status_msg: None,
         }
     }
+
+    /// Whether the presence state of the user is [`PresenceState::Online`].
+    pub fn is_online(&self) -> bool {
+        self.presence == PresenceState::Online
+    }
+
+    /// Whether the presence state of the user is [`PresenceState::Offline`].
+    pub fn is_offline(&self) -> bool {
+        self.presence == PresenceState::Offline
+    }
+
+    /// Whether the presence state of the user is [`PresenceState::Unavailable`].
+    pub fn is_unavailable(&self) -> bool {
+        self.presence == PresenceState::Unavailable
+    }
+
+    /// Whether the user is online and performed some action recently.
+    ///
+    /// Returns `false` if the user is not online or if `last_active_ago` is greater than the given
+    /// threshold. If `last_active_ago` is not set, only the presence state is considered.
+    pub fn currently_active_considering_idle(&self, idle_threshold: Duration) -> bool {
+        self.is_online()
+            && self
+                .last_active_ago
+                .map_or(true, |ago| Duration::from_millis(ago.into()) <= idle_threshold)
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
     use js_int::uint;
     use ruma_common::{mxc_uri, presence::PresenceState};
     use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
@@ -165,4 +195,40 @@ mod tests {
             assert_eq!(ev.sender, "@example:localhost");
         }
     }
+
+    #[test]
+    fn presence_state_helpers() {
+        let content = PresenceEventContent::new(PresenceState::Online);
+        assert!(content.is_online());
+        assert!(!content.is_offline());
+        assert!(!content.is_unavailable());
+
+        let content = PresenceEventContent::new(PresenceState::Offline);
+        assert!(!content.is_online());
+        assert!(content.is_offline());
+        assert!(!content.is_unavailable());
+
+        let content = PresenceEventContent::new(PresenceState::Unavailable);
+        assert!(!content.is_online());
+        assert!(!content.is_offline());
+        assert!(content.is_unavailable());
+    }
+
+    #[test]
+    fn currently_active_considering_idle() {
+        let threshold = Duration::from_secs(60);
+
+        let mut content = PresenceEventContent::new(PresenceState::Online);
+        assert!(content.currently_active_considering_idle(threshold));
+
+        content.last_active_ago = Some(uint!(60_000));
+        assert!(content.currently_active_considering_idle(threshold));
+
+        content.last_active_ago = Some(uint!(60_001));
+        assert!(!content.currently_active_considering_idle(threshold));
+
+        let mut content = PresenceEventContent::new(PresenceState::Unavailable);
+        content.last_active_ago = Some(uint!(0));
+        assert!(!content.currently_active_considering_idle(threshold));
+    }
 }
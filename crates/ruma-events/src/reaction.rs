@@ -2,10 +2,18 @@
 //!
 //! [`m.reaction`]: https://spec.matrix.org/latest/client-server-api/#mreaction
 
+use std::collections::BTreeMap;
+
+use ruma_common::{EventId, UserId};
 use ruma_macros::EventContent;
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "unicode")]
+use unicode_normalization::UnicodeNormalization;
+#[cfg(feature = "unicode")]
+use unicode_segmentation::UnicodeSegmentation;
 
 use super::relation::Annotation;
+use crate::{AnyMessageLikeEvent, MessageLikeEvent};
 
 /// The payload for a `m.reaction` event.
 ///
@@ -26,6 +34,25 @@ impl ReactionEventContent {
     pub fn new(relates_to: Annotation) -> Self {
         Self { relates_to }
     }
+
+    /// Whether the key of this reaction is a single Unicode grapheme cluster, like an emoji.
+    ///
+    /// Emoji made of several code points, like flags or emoji with a skin tone modifier, are a
+    /// single grapheme cluster.
+    #[cfg(feature = "unicode")]
+    pub fn key_is_valid_emoji(&self) -> bool {
+        let mut graphemes = self.relates_to.key.graphemes(true);
+        graphemes.next().is_some() && graphemes.next().is_none()
+    }
+
+    /// Normalizes the given reaction key with the Unicode Normalization Form C.
+    ///
+    /// This allows to group reactions whose keys are visually identical but use different code
+    /// points.
+    #[cfg(feature = "unicode")]
+    pub fn normalize_key(key: &str) -> String {
+        key.nfc().collect()
+    }
 }
 
 impl From<Annotation> for ReactionEventContent {
@@ -34,14 +61,43 @@ impl From<Annotation> for ReactionEventContent {
     }
 }
 
+/// Collect the reactions to the event with the given ID in the given timeline.
+///
+/// Returns a map of reaction key to the list of users that reacted with that key, in the order of
+/// the timeline. Each user appears at most once per key, and redacted reactions are ignored.
+pub fn collect_reactions<'a>(
+    timeline: impl IntoIterator<Item = &'a AnyMessageLikeEvent>,
+    event_id: &EventId,
+) -> BTreeMap<String, Vec<&'a UserId>> {
+    let mut reactions: BTreeMap<String, Vec<&'a UserId>> = BTreeMap::new();
+
+    for event in timeline {
+        let AnyMessageLikeEvent::Reaction(MessageLikeEvent::Original(reaction)) = event else {
+            continue;
+        };
+
+        let annotation = &reaction.content.relates_to;
+        if annotation.event_id != event_id {
+            continue;
+        }
+
+        let senders = reactions.entry(annotation.key.clone()).or_default();
+        if !senders.contains(&&*reaction.sender) {
+            senders.push(&reaction.sender);
+        }
+    }
+
+    reactions
+}
+
 #[cfg(test)]
 mod tests {
     use assert_matches2::assert_matches;
-    use ruma_common::owned_event_id;
+    use ruma_common::{event_id, owned_event_id, user_id};
     use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
 
-    use super::ReactionEventContent;
-    use crate::relation::Annotation;
+    use super::{collect_reactions, ReactionEventContent};
+    use crate::{relation::Annotation, AnyMessageLikeEvent};
 
     #[test]
     fn deserialize() {
@@ -79,4 +135,120 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn key_is_valid_emoji() {
+        fn content(key: &str) -> ReactionEventContent {
+            ReactionEventContent::new(Annotation::new(
+                owned_event_id!("$my_reaction"),
+                key.to_owned(),
+            ))
+        }
+
+        assert!(content("🦛").key_is_valid_emoji());
+        // Flag sequence.
+        assert!(content("🇫🇷").key_is_valid_emoji());
+        // Skin tone modifier.
+        assert!(content("👍🏽").key_is_valid_emoji());
+        // ZWJ sequence.
+        assert!(content("👩‍👩‍👧").key_is_valid_emoji());
+        // Emoji with variation selector.
+        assert!(content("❤️").key_is_valid_emoji());
+        assert!(content("a").key_is_valid_emoji());
+
+        assert!(!content("").key_is_valid_emoji());
+        assert!(!content("+1").key_is_valid_emoji());
+        assert!(!content("🦛🦛").key_is_valid_emoji());
+        assert!(!content("🇫🇷🇧🇪").key_is_valid_emoji());
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn normalize_key() {
+        // `e` followed by a combining acute accent.
+        let decomposed = "e\u{301}";
+        assert_ne!(decomposed, "é");
+        assert_eq!(ReactionEventContent::normalize_key(decomposed), "é");
+        assert_eq!(ReactionEventContent::normalize_key("👍🏽"), "👍🏽");
+    }
+
+    #[test]
+    fn collect_reactions_in_timeline() {
+        fn reaction(
+            event_id: &str,
+            sender: &str,
+            relates_to: &str,
+            key: &str,
+        ) -> AnyMessageLikeEvent {
+            from_json_value(json!({
+                "type": "m.reaction",
+                "event_id": event_id,
+                "sender": sender,
+                "origin_server_ts": 1,
+                "room_id": "!room:localhost",
+                "content": {
+                    "m.relates_to": {
+                        "rel_type": "m.annotation",
+                        "event_id": relates_to,
+                        "key": key,
+                    },
+                },
+            }))
+            .unwrap()
+        }
+
+        let timeline = vec![
+            from_json_value(json!({
+                "type": "m.room.message",
+                "event_id": "$message",
+                "sender": "@alice:localhost",
+                "origin_server_ts": 1,
+                "room_id": "!room:localhost",
+                "content": {
+                    "msgtype": "m.text",
+                    "body": "Hello",
+                },
+            }))
+            .unwrap(),
+            reaction("$r1", "@alice:localhost", "$message", "👍"),
+            reaction("$r2", "@bob:localhost", "$message", "👍"),
+            // Competing reaction from the same user.
+            reaction("$r3", "@bob:localhost", "$message", "🎉"),
+            // Duplicate reaction.
+            reaction("$r4", "@alice:localhost", "$message", "👍"),
+            // Reaction to another event.
+            reaction("$r5", "@carol:localhost", "$other", "👍"),
+            // Redacted reaction.
+            from_json_value(json!({
+                "type": "m.reaction",
+                "event_id": "$r6",
+                "sender": "@carol:localhost",
+                "origin_server_ts": 1,
+                "room_id": "!room:localhost",
+                "content": {},
+                "unsigned": {
+                    "redacted_because": {
+                        "type": "m.room.redaction",
+                        "event_id": "$redaction",
+                        "sender": "@carol:localhost",
+                        "origin_server_ts": 2,
+                        "redacts": "$r6",
+                        "content": {},
+                    },
+                },
+            }))
+            .unwrap(),
+        ];
+
+        let reactions = collect_reactions(&timeline, event_id!("$message"));
+        assert_eq!(reactions.len(), 2);
+        assert_eq!(
+            reactions.get("👍").unwrap(),
+            &[user_id!("@alice:localhost"), user_id!("@bob:localhost")]
+        );
+        assert_eq!(reactions.get("🎉").unwrap(), &[user_id!("@bob:localhost")]);
+
+        assert!(collect_reactions(&timeline, event_id!("$r1")).is_empty());
+    }
 }
@@ -38,6 +38,52 @@ impl ReceiptEventContent {
             Some((event_id.as_ref(), receipt))
         })
     }
+
+    /// Get the most recent receipt for the given user ID with the given receipt type, if any.
+    ///
+    /// Receipts are compared by their timestamp, receipts without a timestamp being considered
+    /// older than those with one. If several receipts have the same timestamp, the one with the
+    /// greatest event ID is returned.
+    pub fn latest_for_user(
+        &self,
+        user_id: &UserId,
+        receipt_type: &ReceiptType,
+    ) -> Option<(&EventId, &Receipt)> {
+        self.latest_user_receipt_matching(user_id, |ty, _| ty == receipt_type)
+    }
+
+    /// Get the most recent receipt of the given user ID for the thread with the given root event
+    /// ID, if any.
+    ///
+    /// Receipts of all types are considered. The same ordering as in
+    /// [`latest_for_user()`](Self::latest_for_user) is used.
+    pub fn latest_thread_receipt_for_user(
+        &self,
+        user_id: &UserId,
+        thread_id: &EventId,
+    ) -> Option<(&EventId, &Receipt)> {
+        self.latest_user_receipt_matching(
+            user_id,
+            |_, receipt| matches!(&receipt.thread, ReceiptThread::Thread(id) if id == thread_id),
+        )
+    }
+
+    fn latest_user_receipt_matching(
+        &self,
+        user_id: &UserId,
+        filter: impl Fn(&ReceiptType, &Receipt) -> bool,
+    ) -> Option<(&EventId, &Receipt)> {
+        self.iter()
+            .flat_map(|(event_id, receipts)| {
+                receipts.iter().filter_map(move |(receipt_type, user_receipts)| {
+                    let receipt = user_receipts.get(user_id)?;
+                    Some((receipt_type, &**event_id, receipt))
+                })
+            })
+            .filter(|(receipt_type, _, receipt)| filter(receipt_type, receipt))
+            .max_by(|(_, a_id, a), (_, b_id, b)| a.ts.cmp(&b.ts).then_with(|| a_id.cmp(b_id)))
+            .map(|(_, event_id, receipt)| (event_id, receipt))
+    }
 }
 
 impl Deref for ReceiptEventContent {
@@ -212,10 +258,10 @@ where
 #[cfg(test)]
 mod tests {
     use assert_matches2::assert_matches;
-    use ruma_common::{owned_event_id, MilliSecondsSinceUnixEpoch};
+    use ruma_common::{event_id, owned_event_id, user_id, MilliSecondsSinceUnixEpoch};
     use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
 
-    use super::{Receipt, ReceiptThread};
+    use super::{Receipt, ReceiptEventContent, ReceiptThread, ReceiptType};
 
     #[test]
     fn serialize_receipt() {
@@ -272,4 +318,54 @@ mod tests {
         assert_matches!(&receipt.thread, ReceiptThread::_Custom(_));
         assert_eq!(receipt.thread.as_str().unwrap(), "io.ruma.unknown");
     }
+
+    #[test]
+    fn latest_receipts_for_user() {
+        let content = from_json_value::<ReceiptEventContent>(json!({
+            "$older": {
+                "m.read": {
+                    "@alice:localhost": { "ts": 1_000 },
+                },
+            },
+            "$aaa": {
+                "m.read": {
+                    "@alice:localhost": { "ts": 2_000 },
+                },
+                "m.read.private": {
+                    "@alice:localhost": { "ts": 3_000, "thread_id": "$thread" },
+                },
+            },
+            "$bbb": {
+                "m.read": {
+                    "@alice:localhost": { "ts": 2_000 },
+                    "@bob:localhost": { "ts": 5_000, "thread_id": "$thread" },
+                },
+            },
+            "$ccc": {
+                "m.read": {
+                    "@alice:localhost": { "ts": 1_500, "thread_id": "$thread" },
+                },
+            },
+        }))
+        .unwrap();
+        let alice = user_id!("@alice:localhost");
+
+        let (event_id, receipt) = content.latest_for_user(alice, &ReceiptType::Read).unwrap();
+        assert_eq!(event_id, "$bbb");
+        assert_eq!(receipt.ts, Some(MilliSecondsSinceUnixEpoch(2_000_u32.into())));
+
+        let (event_id, _) = content.latest_for_user(alice, &ReceiptType::ReadPrivate).unwrap();
+        assert_eq!(event_id, "$aaa");
+
+        let (event_id, receipt) =
+            content.latest_thread_receipt_for_user(alice, event_id!("$thread")).unwrap();
+        assert_eq!(event_id, "$aaa");
+        assert_eq!(receipt.ts, Some(MilliSecondsSinceUnixEpoch(3_000_u32.into())));
+
+        assert!(content.latest_thread_receipt_for_user(alice, event_id!("$other")).is_none());
+
+        let carl = user_id!("@carl:localhost");
+        assert!(content.latest_for_user(carl, &ReceiptType::Read).is_none());
+        assert!(content.latest_thread_receipt_for_user(carl, event_id!("$thread")).is_none());
+    }
 }
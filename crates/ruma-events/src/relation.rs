@@ -7,12 +7,12 @@ use std::fmt::Debug;
 use js_int::UInt;
 use ruma_common::{
     serde::{JsonObject, Raw, StringEnum},
-    OwnedEventId,
+    EventId, OwnedEventId,
 };
 use serde::{Deserialize, Serialize};
 
 use super::AnyMessageLikeEvent;
-use crate::PrivOwnedStr;
+use crate::{room::encrypted, PrivOwnedStr};
 
 mod rel_serde;
 
@@ -130,6 +130,40 @@ impl Thread {
     }
 }
 
+/// Count the replies to the thread with the given root event ID in the given timeline.
+///
+/// Only events with a [`Thread`] relation to the root event are counted, the root event itself is
+/// excluded.
+pub fn thread_reply_count<'a>(
+    root_event_id: &EventId,
+    timeline: impl IntoIterator<Item = &'a AnyMessageLikeEvent>,
+) -> usize {
+    timeline.into_iter().filter(|event| is_thread_reply(event, root_event_id)).count()
+}
+
+/// Get the reply to the thread with the given root event ID with the latest timestamp in the
+/// given timeline.
+///
+/// If several replies have the same timestamp, the last one in the timeline is returned.
+pub fn latest_thread_reply<'a>(
+    root_event_id: &EventId,
+    timeline: impl IntoIterator<Item = &'a AnyMessageLikeEvent>,
+) -> Option<&'a AnyMessageLikeEvent> {
+    timeline
+        .into_iter()
+        .filter(|event| is_thread_reply(event, root_event_id))
+        .max_by_key(|event| event.origin_server_ts())
+}
+
+/// Whether the given event has a [`Thread`] relation to the given root event.
+fn is_thread_reply(event: &AnyMessageLikeEvent, root_event_id: &EventId) -> bool {
+    event.event_id() != root_event_id
+        && matches!(
+            event.original_content().and_then(|content| content.relation()),
+            Some(encrypted::Relation::Thread(thread)) if thread.event_id == root_event_id
+        )
+}
+
 /// A bundled thread.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
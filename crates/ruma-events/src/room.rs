@@ -280,7 +280,7 @@ mod tests {
     use assert_matches2::assert_matches;
     use ruma_common::{mxc_uri, serde::Base64};
     use serde::Deserialize;
-    use serde_json::{from_value as from_json_value, json};
+    use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
 
     use super::{EncryptedFile, JsonWebKey, MediaSource};
 
@@ -333,4 +333,27 @@ mod tests {
 
         assert_matches!(msg.source, MediaSource::Encrypted(_));
     }
+
+    #[test]
+    fn encrypted_file_base64_encoding() {
+        let mut file = encrypted_file();
+        file.iv = Base64::new(vec![0xff; 16]);
+        file.key.k = Base64::new(vec![0xff; 32]);
+
+        // The IV uses the standard alphabet and the key uses the URL-safe alphabet, both without
+        // padding.
+        let json = to_json_value(&file).unwrap();
+        assert_eq!(json["iv"], "/////////////////////w");
+        assert_eq!(json["key"]["k"], "__________________________________________8");
+
+        let file = from_json_value::<EncryptedFile>(json).unwrap();
+        assert_eq!(file.iv.as_bytes(), [0xff; 16]);
+        assert_eq!(file.key.k.as_bytes(), [0xff; 32]);
+
+        // Padding is accepted when deserializing.
+        let mut json = to_json_value(encrypted_file()).unwrap();
+        json["iv"] = "/////////////////////w==".into();
+        let file = from_json_value::<EncryptedFile>(json).unwrap();
+        assert_eq!(file.iv.as_bytes(), [0xff; 16]);
+    }
 }
@@ -2,7 +2,7 @@
 //!
 //! [`m.room.create`]: https://spec.matrix.org/latest/client-server-api/#mroomcreate
 
-use ruma_common::{room::RoomType, OwnedEventId, OwnedRoomId, OwnedUserId, RoomVersionId};
+use ruma_common::{room::RoomType, OwnedEventId, OwnedRoomId, OwnedUserId, RoomVersionId, UserId};
 use ruma_macros::EventContent;
 use serde::{Deserialize, Serialize};
 
@@ -80,6 +80,15 @@ impl RoomCreateEventContent {
             room_type: None,
         }
     }
+
+    /// The `user_id` of the room creator, if it is set in the content.
+    ///
+    /// This is only set in room versions 1 through 10. Starting from room version 11, the creator
+    /// is the sender of the `m.room.create` event.
+    pub fn room_creator(&self) -> Option<&UserId> {
+        #[allow(deprecated)]
+        self.creator.as_deref()
+    }
 }
 
 impl RedactContent for RoomCreateEventContent {
@@ -227,4 +236,13 @@ mod tests {
         assert_matches!(content.predecessor, None);
         assert_eq!(content.room_type, Some(RoomType::Space));
     }
+
+    #[test]
+    fn room_creator() {
+        let content = RoomCreateEventContent::new_v1(owned_user_id!("@carl:example.com"));
+        assert_eq!(content.room_creator().unwrap(), "@carl:example.com");
+
+        let content = RoomCreateEventContent::new_v11();
+        assert_eq!(content.room_creator(), None);
+    }
 }
@@ -2,6 +2,8 @@
 //!
 //! [`m.room.encryption`]: https://spec.matrix.org/latest/client-server-api/#mroomencryption
 
+use std::time::Duration;
+
 use js_int::{uint, UInt};
 use ruma_macros::EventContent;
 use serde::{Deserialize, Serialize};
@@ -52,4 +54,56 @@ impl RoomEncryptionEventContent {
             rotation_period_msgs: Some(uint!(100)),
         }
     }
+
+    /// Whether a session with the given age, that was used to send the given number of messages,
+    /// should be rotated according to the settings of this event.
+    ///
+    /// Returns `true` if either the rotation period or the number of messages has been reached.
+    /// The recommended defaults of a week and 100 messages are used for the fields that are
+    /// missing.
+    pub fn is_rotation_due(&self, session_age: Duration, messages_sent: u64) -> bool {
+        let rotation_period_ms = self.rotation_period_ms.unwrap_or(uint!(604_800_000));
+        let rotation_period_msgs = self.rotation_period_msgs.unwrap_or(uint!(100));
+
+        session_age >= Duration::from_millis(rotation_period_ms.into())
+            || messages_sent >= u64::from(rotation_period_msgs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use js_int::uint;
+
+    use super::RoomEncryptionEventContent;
+    use crate::EventEncryptionAlgorithm;
+
+    #[test]
+    fn is_rotation_due() {
+        let mut content =
+            RoomEncryptionEventContent::new(EventEncryptionAlgorithm::MegolmV1AesSha2);
+        content.rotation_period_ms = Some(uint!(3_600_000));
+        content.rotation_period_msgs = Some(uint!(10));
+        let hour = Duration::from_secs(3_600);
+
+        // Neither exceeded.
+        assert!(!content.is_rotation_due(Duration::from_secs(60), 5));
+        // Only age exceeded.
+        assert!(content.is_rotation_due(hour, 5));
+        // Only count exceeded.
+        assert!(content.is_rotation_due(Duration::from_secs(60), 10));
+        // Both exceeded.
+        assert!(content.is_rotation_due(hour * 2, 20));
+    }
+
+    #[test]
+    fn is_rotation_due_defaults() {
+        let content = RoomEncryptionEventContent::new(EventEncryptionAlgorithm::MegolmV1AesSha2);
+        let week = Duration::from_secs(7 * 24 * 3_600);
+
+        assert!(!content.is_rotation_due(week - Duration::from_millis(1), 99));
+        assert!(content.is_rotation_due(week, 0));
+        assert!(content.is_rotation_due(Duration::ZERO, 100));
+    }
 }
@@ -27,6 +27,29 @@ impl RoomGuestAccessEventContent {
     pub fn new(guest_access: GuestAccess) -> Self {
         Self { guest_access }
     }
+
+    /// Creates a new `RoomGuestAccessEventContent` allowing guests to join the room.
+    pub fn allowing() -> Self {
+        Self::new(GuestAccess::CanJoin)
+    }
+
+    /// Creates a new `RoomGuestAccessEventContent` forbidding guests to join the room.
+    pub fn forbidding() -> Self {
+        Self::new(GuestAccess::Forbidden)
+    }
+
+    /// Whether guests are allowed to join the room.
+    pub fn allows_guests(&self) -> bool {
+        self.guest_access == GuestAccess::CanJoin
+    }
+
+    /// Whether guests are forbidden to join the room.
+    ///
+    /// This is the negation of [`allows_guests()`](Self::allows_guests), so unknown policies are
+    /// considered to forbid guests.
+    pub fn is_forbidden(&self) -> bool {
+        !self.allows_guests()
+    }
 }
 
 impl RoomGuestAccessEvent {
@@ -64,3 +87,31 @@ pub enum GuestAccess {
     #[doc(hidden)]
     _Custom(PrivOwnedStr),
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
+
+    use super::{GuestAccess, RoomGuestAccessEventContent};
+
+    #[test]
+    fn allowing_and_forbidding() {
+        let content = RoomGuestAccessEventContent::allowing();
+        assert!(content.allows_guests());
+        assert!(!content.is_forbidden());
+        let json = to_json_value(&content).unwrap();
+        assert_eq!(json, json!({ "guest_access": "can_join" }));
+        assert!(from_json_value::<RoomGuestAccessEventContent>(json).unwrap().allows_guests());
+
+        let content = RoomGuestAccessEventContent::forbidding();
+        assert!(!content.allows_guests());
+        assert!(content.is_forbidden());
+        let json = to_json_value(&content).unwrap();
+        assert_eq!(json, json!({ "guest_access": "forbidden" }));
+        assert!(from_json_value::<RoomGuestAccessEventContent>(json).unwrap().is_forbidden());
+
+        let content = RoomGuestAccessEventContent::new(GuestAccess::from("io.ruma.custom"));
+        assert!(!content.allows_guests());
+        assert!(content.is_forbidden());
+    }
+}
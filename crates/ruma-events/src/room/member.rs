@@ -13,6 +13,7 @@ use ruma_common::{
 use ruma_macros::EventContent;
 use serde::{Deserialize, Serialize};
 
+use super::create::RoomCreateEventContent;
 use crate::{
     AnyStrippedStateEvent, BundledStateRelations, EventContent, PossiblyRedactedStateEventContent,
     PrivOwnedStr, RedactContent, RedactedStateEventContent, StateEventType,
@@ -575,19 +576,39 @@ impl CanBeEmpty for RoomMemberUnsigned {
     }
 }
 
+/// Whether the room is a direct chat, according to its initial `m.room.member` events.
+///
+/// Returns `true` if any of the given events is an invite with `is_direct` set to `true`. If the
+/// creator of the room is set in the given `m.room.create` content, only invites sent by the
+/// creator are taken into account.
+pub fn is_direct_room(
+    create: &RoomCreateEventContent,
+    initial_member_events: &[OriginalSyncRoomMemberEvent],
+) -> bool {
+    let creator = create.room_creator();
+
+    initial_member_events.iter().any(|ev| {
+        ev.content.membership == MembershipState::Invite
+            && ev.content.is_direct == Some(true)
+            && creator.map_or(true, |creator| ev.sender == creator)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use assert_matches2::assert_matches;
     use js_int::uint;
     use maplit::btreemap;
     use ruma_common::{
-        mxc_uri, owned_server_signing_key_id, serde::CanBeEmpty, server_name, user_id,
-        MilliSecondsSinceUnixEpoch,
+        mxc_uri, owned_server_signing_key_id, owned_user_id, serde::CanBeEmpty, server_name,
+        user_id, MilliSecondsSinceUnixEpoch,
     };
     use serde_json::{from_value as from_json_value, json};
 
-    use super::{MembershipState, RoomMemberEventContent};
-    use crate::OriginalStateEvent;
+    use super::{
+        is_direct_room, MembershipState, OriginalSyncRoomMemberEvent, RoomMemberEventContent,
+    };
+    use crate::{room::create::RoomCreateEventContent, OriginalStateEvent};
 
     #[test]
     fn serde_with_no_prev_content() {
@@ -820,4 +841,67 @@ mod tests {
             Some(user_id!("@notcarl:example.com"))
         );
     }
+
+    fn member_event(
+        sender: &str,
+        state_key: &str,
+        membership: &str,
+        is_direct: Option<bool>,
+    ) -> OriginalSyncRoomMemberEvent {
+        let mut content = json!({ "membership": membership });
+        if let Some(is_direct) = is_direct {
+            content["is_direct"] = is_direct.into();
+        }
+
+        from_json_value(json!({
+            "type": "m.room.member",
+            "content": content,
+            "event_id": "$h29iv0s8:example.com",
+            "origin_server_ts": 1,
+            "sender": sender,
+            "state_key": state_key,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn direct_room() {
+        let create = RoomCreateEventContent::new_v1(owned_user_id!("@alice:example.org"));
+        let events = [
+            member_event("@alice:example.org", "@alice:example.org", "join", None),
+            member_event("@alice:example.org", "@bob:example.org", "invite", Some(true)),
+        ];
+        assert!(is_direct_room(&create, &events));
+
+        let create = RoomCreateEventContent::new_v11();
+        assert!(is_direct_room(&create, &events));
+    }
+
+    #[test]
+    fn not_direct_room() {
+        let create = RoomCreateEventContent::new_v1(owned_user_id!("@alice:example.org"));
+
+        // No invite.
+        let events = [member_event("@alice:example.org", "@alice:example.org", "join", None)];
+        assert!(!is_direct_room(&create, &events));
+
+        // Invite without `is_direct`.
+        let events = [
+            member_event("@alice:example.org", "@alice:example.org", "join", None),
+            member_event("@alice:example.org", "@bob:example.org", "invite", None),
+            member_event("@alice:example.org", "@carl:example.org", "invite", Some(false)),
+        ];
+        assert!(!is_direct_room(&create, &events));
+
+        // Direct invite not sent by the creator.
+        let events = [
+            member_event("@alice:example.org", "@alice:example.org", "join", None),
+            member_event("@bob:example.org", "@carl:example.org", "invite", Some(true)),
+        ];
+        assert!(!is_direct_room(&create, &events));
+
+        // `is_direct` on a join.
+        let events = [member_event("@alice:example.org", "@alice:example.org", "join", Some(true))];
+        assert!(!is_direct_room(&create, &events));
+    }
 }
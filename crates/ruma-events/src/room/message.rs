@@ -6,7 +6,7 @@ use std::borrow::Cow;
 
 use ruma_common::{
     serde::{JsonObject, Raw, StringEnum},
-    OwnedEventId, RoomId,
+    EventId, OwnedEventId, RoomId,
 };
 #[cfg(feature = "html")]
 use ruma_html::{sanitize_html, HtmlSanitizerMode, RemoveReplyFallback};
@@ -15,9 +15,10 @@ use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use tracing::warn;
 
-use self::reply::OriginalEventData;
-#[cfg(feature = "html")]
-use self::sanitize::remove_plain_reply_fallback;
+use self::{
+    reply::OriginalEventData,
+    sanitize::{remove_plain_reply_fallback, strip_html_reply_fallback},
+};
 use crate::{
     relation::{InReplyTo, Replacement, Thread},
     AnySyncTimelineEvent, Mentions, PrivOwnedStr,
@@ -329,6 +330,40 @@ impl RoomMessageEventContent {
         self.msgtype.body()
     }
 
+    /// Return a reference to the message body, without the [rich reply fallback].
+    ///
+    /// The fallback is only removed if this message is a reply, as there is no way to
+    /// differentiate plain text reply fallbacks and markdown quotes.
+    ///
+    /// [rich reply fallback]: https://spec.matrix.org/latest/client-server-api/#fallbacks-for-rich-replies
+    pub fn body_for_display(&self) -> &str {
+        if matches!(self.relates_to, Some(Relation::Reply { .. })) {
+            remove_plain_reply_fallback(self.body())
+        } else {
+            self.body()
+        }
+    }
+
+    /// Return a reference to the HTML formatted body of the message, if any, without the
+    /// [rich reply fallback].
+    ///
+    /// This method is only effective on text, notice and emote messages.
+    ///
+    /// [rich reply fallback]: https://spec.matrix.org/latest/client-server-api/#fallbacks-for-rich-replies
+    pub fn formatted_body_for_display(&self) -> Option<&str> {
+        let (MessageType::Emote(EmoteMessageEventContent { formatted, .. })
+        | MessageType::Notice(NoticeMessageEventContent { formatted, .. })
+        | MessageType::Text(TextMessageEventContent { formatted, .. })) = &self.msgtype
+        else {
+            return None;
+        };
+
+        formatted
+            .as_ref()
+            .filter(|formatted| formatted.format == MessageFormat::Html)
+            .map(|formatted| strip_html_reply_fallback(&formatted.body))
+    }
+
     /// Apply the given new content from a [`Replacement`] to this message.
     pub fn apply_replacement(&mut self, new_content: RoomMessageEventContentWithoutRelation) {
         let RoomMessageEventContentWithoutRelation { msgtype, mentions } = new_content;
@@ -589,6 +624,62 @@ impl MessageType {
         }
     }
 
+    /// Returns the inner content if this is a text message, or `self` otherwise.
+    pub fn try_into_text(self) -> Result<TextMessageEventContent, Self> {
+        match self {
+            Self::Text(c) => Ok(c),
+            _ => Err(self),
+        }
+    }
+
+    /// Returns the inner content if this is an image message, or `self` otherwise.
+    pub fn try_into_image(self) -> Result<ImageMessageEventContent, Self> {
+        match self {
+            Self::Image(c) => Ok(c),
+            _ => Err(self),
+        }
+    }
+
+    /// Returns the inner content if this is an audio message, or `self` otherwise.
+    pub fn try_into_audio(self) -> Result<AudioMessageEventContent, Self> {
+        match self {
+            Self::Audio(c) => Ok(c),
+            _ => Err(self),
+        }
+    }
+
+    /// Returns the inner content if this is a video message, or `self` otherwise.
+    pub fn try_into_video(self) -> Result<VideoMessageEventContent, Self> {
+        match self {
+            Self::Video(c) => Ok(c),
+            _ => Err(self),
+        }
+    }
+
+    /// Returns the inner content if this is a file message, or `self` otherwise.
+    pub fn try_into_file(self) -> Result<FileMessageEventContent, Self> {
+        match self {
+            Self::File(c) => Ok(c),
+            _ => Err(self),
+        }
+    }
+
+    /// Returns the inner content if this is an emote message, or `self` otherwise.
+    pub fn try_into_emote(self) -> Result<EmoteMessageEventContent, Self> {
+        match self {
+            Self::Emote(c) => Ok(c),
+            _ => Err(self),
+        }
+    }
+
+    /// Returns the inner content if this is a notice message, or `self` otherwise.
+    pub fn try_into_notice(self) -> Result<NoticeMessageEventContent, Self> {
+        match self {
+            Self::Notice(c) => Ok(c),
+            _ => Err(self),
+        }
+    }
+
     /// Returns the associated data.
     ///
     /// The returned JSON object won't contain the `msgtype` and `body` fields, use
@@ -779,6 +870,65 @@ impl From<&OriginalSyncRoomMessageEvent> for ReplacementMetadata {
     }
 }
 
+impl OriginalRoomMessageEvent {
+    /// Returns the ID of the event that this event replies to, if any.
+    ///
+    /// Returns `None` for a message in a thread whose `m.in_reply_to` is only a fallback for
+    /// clients that do not support threads. Use [`Self::in_reply_to_event_id()`] to get the ID in
+    /// all cases.
+    pub fn reply_to_event_id(&self) -> Option<&EventId> {
+        reply_to_event_id(self.content.relates_to.as_ref())
+    }
+
+    /// Returns the ID of the event in this event's `m.in_reply_to` field, if any.
+    ///
+    /// Unlike [`Self::reply_to_event_id()`], this returns the ID even if it is only a thread
+    /// fallback.
+    pub fn in_reply_to_event_id(&self) -> Option<&EventId> {
+        in_reply_to_event_id(self.content.relates_to.as_ref())
+    }
+}
+
+impl OriginalSyncRoomMessageEvent {
+    /// Returns the ID of the event that this event replies to, if any.
+    ///
+    /// Returns `None` for a message in a thread whose `m.in_reply_to` is only a fallback for
+    /// clients that do not support threads. Use [`Self::in_reply_to_event_id()`] to get the ID in
+    /// all cases.
+    pub fn reply_to_event_id(&self) -> Option<&EventId> {
+        reply_to_event_id(self.content.relates_to.as_ref())
+    }
+
+    /// Returns the ID of the event in this event's `m.in_reply_to` field, if any.
+    ///
+    /// Unlike [`Self::reply_to_event_id()`], this returns the ID even if it is only a thread
+    /// fallback.
+    pub fn in_reply_to_event_id(&self) -> Option<&EventId> {
+        in_reply_to_event_id(self.content.relates_to.as_ref())
+    }
+}
+
+fn reply_to_event_id(
+    relates_to: Option<&Relation<RoomMessageEventContentWithoutRelation>>,
+) -> Option<&EventId> {
+    match relates_to? {
+        Relation::Thread(Thread { is_falling_back: true, .. }) => None,
+        relation => in_reply_to_event_id(Some(relation)),
+    }
+}
+
+fn in_reply_to_event_id(
+    relates_to: Option<&Relation<RoomMessageEventContentWithoutRelation>>,
+) -> Option<&EventId> {
+    match relates_to? {
+        Relation::Reply { in_reply_to } => Some(&in_reply_to.event_id),
+        Relation::Thread(Thread { in_reply_to, .. }) => {
+            in_reply_to.as_ref().map(|in_reply_to| &*in_reply_to.event_id)
+        }
+        _ => None,
+    }
+}
+
 /// The format for the formatted representation of a message body.
 #[doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/doc/string_enum.md"))]
 #[derive(Clone, PartialEq, Eq, StringEnum)]
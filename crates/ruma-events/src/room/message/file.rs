@@ -60,6 +60,20 @@ impl FileMessageEventContent {
     pub fn info(self, info: impl Into<Option<Box<FileInfo>>>) -> Self {
         Self { info: info.into(), ..self }
     }
+
+    /// Computes the filename of the uploaded file.
+    ///
+    /// This is the `filename` field if it is set, otherwise the `body`.
+    pub fn effective_filename(&self) -> &str {
+        self.filename.as_deref().unwrap_or(&self.body)
+    }
+
+    /// Sets the original filename of the uploaded file.
+    ///
+    /// The `body` can then be used as a caption.
+    pub fn set_filename(&mut self, filename: impl Into<String>) {
+        self.filename = Some(filename.into());
+    }
 }
 
 /// Metadata about a file.
@@ -49,4 +49,18 @@ impl KeyVerificationRequestEventContent {
     ) -> Self {
         Self { body, formatted: None, methods, from_device, to }
     }
+
+    /// Whether the given verification method is supported by the sender.
+    pub fn methods_include(&self, method: &VerificationMethod) -> bool {
+        self.methods.contains(method)
+    }
+
+    /// The verification methods supported by both the sender of this request and the sender of
+    /// the other request.
+    pub fn common_methods<'a>(
+        &'a self,
+        other: &'a Self,
+    ) -> impl Iterator<Item = &'a VerificationMethod> + 'a {
+        self.methods.iter().filter(|method| other.methods_include(method))
+    }
 }
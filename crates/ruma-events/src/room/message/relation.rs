@@ -119,3 +119,13 @@ impl<C> TryFrom<Relation<C>> for RelationWithoutReplacement {
         Ok(rel)
     }
 }
+
+impl<C> From<RelationWithoutReplacement> for Relation<C> {
+    fn from(value: RelationWithoutReplacement) -> Self {
+        match value {
+            RelationWithoutReplacement::Reply { in_reply_to } => Self::Reply { in_reply_to },
+            RelationWithoutReplacement::Thread(t) => Self::Thread(t),
+            RelationWithoutReplacement::_Custom(c) => Self::_Custom(c),
+        }
+    }
+}
@@ -24,9 +24,21 @@ pub fn remove_plain_reply_fallback(mut s: &str) -> &str {
     }
 }
 
+/// Remove the [rich reply fallback] at the start of the given HTML string.
+///
+/// Contrary to `ruma_html::remove_html_reply_fallback()`, this doesn't parse the HTML, it only
+/// strips a leading `<mx-reply>` element.
+///
+/// [rich reply fallback]: https://spec.matrix.org/latest/client-server-api/#fallbacks-for-rich-replies
+pub(crate) fn strip_html_reply_fallback(s: &str) -> &str {
+    s.strip_prefix("<mx-reply>")
+        .and_then(|rest| rest.split_once("</mx-reply>"))
+        .map_or(s, |(_reply, rest)| rest)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::remove_plain_reply_fallback;
+    use super::{remove_plain_reply_fallback, strip_html_reply_fallback};
 
     #[test]
     fn remove_plain_reply() {
@@ -53,4 +65,24 @@ mod tests {
             "> New quote"
         );
     }
+
+    #[test]
+    fn strip_html_reply() {
+        assert_eq!(strip_html_reply_fallback("<p>No reply here</p>"), "<p>No reply here</p>");
+
+        assert_eq!(
+            strip_html_reply_fallback(
+                "<mx-reply><blockquote><a href=\"https://matrix.to/#/!room:notareal.hs/$event\">In reply to</a> \
+                 <a href=\"https://matrix.to/#/@user:notareal.hs\">@user:notareal.hs</a>\
+                 <br>Replied to</blockquote></mx-reply>This is my <em>reply</em>"
+            ),
+            "This is my <em>reply</em>"
+        );
+
+        assert_eq!(strip_html_reply_fallback("<mx-reply>Unclosed"), "<mx-reply>Unclosed");
+        assert_eq!(
+            strip_html_reply_fallback("Not <mx-reply>at start</mx-reply>"),
+            "Not <mx-reply>at start</mx-reply>"
+        );
+    }
 }
@@ -2,7 +2,7 @@
 //!
 //! [`m.room.pinned_events`]: https://spec.matrix.org/latest/client-server-api/#mroompinned_events
 
-use ruma_common::OwnedEventId;
+use ruma_common::{EventId, OwnedEventId};
 use ruma_macros::EventContent;
 use serde::{Deserialize, Serialize};
 
@@ -24,13 +24,60 @@ impl RoomPinnedEventsEventContent {
     pub fn new(pinned: Vec<OwnedEventId>) -> Self {
         Self { pinned }
     }
+
+    /// The largest number of events that can be pinned with [`pin()`](Self::pin).
+    pub const MAX_PINNED: usize = 100;
+
+    /// Pin the given event.
+    ///
+    /// Does nothing if the event is already pinned.
+    ///
+    /// Returns an error if [`Self::MAX_PINNED`] events are already pinned.
+    pub fn pin(&mut self, event_id: OwnedEventId) -> Result<(), TooManyPinnedEvents> {
+        if self.is_pinned(&event_id) {
+            return Ok(());
+        }
+
+        if self.pinned.len() >= Self::MAX_PINNED {
+            return Err(TooManyPinnedEvents);
+        }
+
+        self.pinned.push(event_id);
+        Ok(())
+    }
+
+    /// Unpin the given event.
+    ///
+    /// Returns `true` if the event was pinned.
+    pub fn unpin(&mut self, event_id: &EventId) -> bool {
+        let len = self.pinned.len();
+        self.pinned.retain(|id| id != event_id);
+        self.pinned.len() != len
+    }
+
+    /// Whether the given event is pinned.
+    pub fn is_pinned(&self, event_id: &EventId) -> bool {
+        self.pinned.iter().any(|id| id == event_id)
+    }
+
+    /// Iterate over the pinned events, in order.
+    pub fn pins(&self) -> impl Iterator<Item = &EventId> {
+        self.pinned.iter().map(|id| &**id)
+    }
 }
 
+/// An error encountered when trying to pin more than
+/// [`RoomPinnedEventsEventContent::MAX_PINNED`] events.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, thiserror::Error)]
+#[allow(clippy::exhaustive_structs)]
+#[error("too many pinned events")]
+pub struct TooManyPinnedEvents;
+
 #[cfg(test)]
 mod tests {
-    use ruma_common::owned_event_id;
+    use ruma_common::{event_id, owned_event_id, OwnedEventId};
 
-    use super::RoomPinnedEventsEventContent;
+    use super::{RoomPinnedEventsEventContent, TooManyPinnedEvents};
 
     #[test]
     fn serialization_deserialization() {
@@ -46,4 +93,40 @@ mod tests {
 
         assert_eq!(parsed_content.pinned, content.pinned);
     }
+
+    #[test]
+    fn pin_and_unpin() {
+        let mut content = RoomPinnedEventsEventContent::new(Vec::new());
+
+        content.pin(owned_event_id!("$a:example.com")).unwrap();
+        content.pin(owned_event_id!("$b:example.com")).unwrap();
+        content.pin(owned_event_id!("$a:example.com")).unwrap();
+        assert_eq!(
+            content.pins().collect::<Vec<_>>(),
+            [event_id!("$a:example.com"), event_id!("$b:example.com")]
+        );
+        assert!(content.is_pinned(event_id!("$b:example.com")));
+
+        assert!(content.unpin(event_id!("$a:example.com")));
+        assert!(!content.unpin(event_id!("$a:example.com")));
+        assert!(!content.unpin(event_id!("$c:example.com")));
+        assert!(!content.is_pinned(event_id!("$a:example.com")));
+        assert_eq!(content.pins().collect::<Vec<_>>(), [event_id!("$b:example.com")]);
+    }
+
+    #[test]
+    fn pin_limit() {
+        let pinned = (0..RoomPinnedEventsEventContent::MAX_PINNED)
+            .map(|i| OwnedEventId::try_from(format!("${i}:example.com")).unwrap())
+            .collect();
+        let mut content = RoomPinnedEventsEventContent::new(pinned);
+
+        assert_eq!(content.pin(owned_event_id!("$new:example.com")), Err(TooManyPinnedEvents));
+        // Pinning an already pinned event is still fine.
+        content.pin(owned_event_id!("$0:example.com")).unwrap();
+
+        assert!(content.unpin(event_id!("$0:example.com")));
+        content.pin(owned_event_id!("$new:example.com")).unwrap();
+        assert_eq!(content.pinned.len(), RoomPinnedEventsEventContent::MAX_PINNED);
+    }
 }
@@ -480,6 +480,20 @@ impl RoomPowerLevels {
         self.user_can_redact_own_event(user_id) && self.for_user(user_id) >= self.redact
     }
 
+    /// Whether the given user can redact an event sent by the given sender based on the power
+    /// levels.
+    ///
+    /// Uses [`user_can_redact_own_event()`](Self::user_can_redact_own_event) if the user is the
+    /// sender of the event, and
+    /// [`user_can_redact_event_of_other()`](Self::user_can_redact_event_of_other) otherwise.
+    pub fn user_can_redact_event(&self, user_id: &UserId, event_sender: &UserId) -> bool {
+        if user_id == event_sender {
+            self.user_can_redact_own_event(user_id)
+        } else {
+            self.user_can_redact_event_of_other(user_id)
+        }
+    }
+
     /// Whether the given user can send message events based on the power levels.
     ///
     /// Shorthand for `power_levels.user_can_do(user_id, PowerLevelAction::SendMessage(msg_type))`.
@@ -703,7 +717,11 @@ mod tests {
     use ruma_common::user_id;
     use serde_json::{json, to_value as to_json_value};
 
-    use super::{default_power_level, NotificationPowerLevels, RoomPowerLevelsEventContent};
+    use super::{
+        default_power_level, NotificationPowerLevels, PowerLevelAction, RoomPowerLevels,
+        RoomPowerLevelsEventContent,
+    };
+    use crate::MessageLikeEventType;
 
     #[test]
     fn serialization_with_optional_fields_as_none() {
@@ -770,4 +788,53 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn redaction_power_levels() {
+        let admin = user_id!("@admin:example.org");
+        let moderator = user_id!("@moderator:example.org");
+        let user = user_id!("@user:example.org");
+        let muted = user_id!("@muted:example.org");
+
+        let mut content = RoomPowerLevelsEventContent::new();
+        content.users.insert(admin.to_owned(), int!(100));
+        content.users.insert(moderator.to_owned(), int!(50));
+        content.users.insert(muted.to_owned(), int!(-1));
+        let power_levels = RoomPowerLevels::from(content);
+
+        // Everyone who can send redactions can redact their own events.
+        assert!(power_levels.user_can_redact_own_event(admin));
+        assert!(power_levels.user_can_redact_own_event(moderator));
+        assert!(power_levels.user_can_redact_own_event(user));
+        assert!(!power_levels.user_can_redact_own_event(muted));
+
+        // Only users with the `redact` power level can redact events of others.
+        assert!(power_levels.user_can_redact_event_of_other(admin));
+        assert!(power_levels.user_can_redact_event_of_other(moderator));
+        assert!(!power_levels.user_can_redact_event_of_other(user));
+        assert!(!power_levels.user_can_redact_event_of_other(muted));
+        assert!(!power_levels.user_can_do(user, PowerLevelAction::RedactOther));
+
+        assert!(power_levels.user_can_redact_event(user, user));
+        assert!(!power_levels.user_can_redact_event(user, moderator));
+        assert!(power_levels.user_can_redact_event(moderator, user));
+        assert!(!power_levels.user_can_redact_event(muted, muted));
+    }
+
+    #[test]
+    fn redaction_power_levels_with_event_level() {
+        let moderator = user_id!("@moderator:example.org");
+        let user = user_id!("@user:example.org");
+
+        let mut content = RoomPowerLevelsEventContent::new();
+        content.users.insert(moderator.to_owned(), int!(50));
+        content.events.insert(MessageLikeEventType::RoomRedaction.into(), int!(50));
+        let power_levels = RoomPowerLevels::from(content);
+
+        // The level to send `m.room.redaction` events also applies to self-redaction.
+        assert!(power_levels.user_can_redact_event(moderator, moderator));
+        assert!(power_levels.user_can_redact_event(moderator, user));
+        assert!(!power_levels.user_can_redact_event(user, user));
+        assert!(!power_levels.user_can_redact_event(user, moderator));
+    }
 }
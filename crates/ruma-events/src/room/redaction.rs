@@ -17,6 +17,7 @@ use tracing::error;
 use crate::{
     BundledMessageLikeRelations, EventContent, MessageLikeEventType, RedactContent,
     RedactedMessageLikeEventContent, RedactedUnsigned, StaticEventContent,
+    UnsignedRoomRedactionEvent,
 };
 
 mod event_serde;
@@ -316,6 +317,35 @@ impl RoomRedactionEvent {
     pub fn as_original(&self) -> Option<&OriginalRoomRedactionEvent> {
         as_variant!(self, Self::Original)
     }
+
+    /// Returns the redaction event from inside `unsigned`, if this event was redacted.
+    pub fn redacted_because(&self) -> Option<&UnsignedRoomRedactionEvent> {
+        as_variant!(self, Self::Redacted).map(|ev| &ev.unsigned.redacted_because)
+    }
+
+    /// Transform `self` into its redacted form, removing the fields of the content that are not
+    /// preserved by redaction.
+    ///
+    /// If `self` is already redacted, it is returned unmodified.
+    ///
+    /// The fields that are preserved depend on the room version, so a version has to be specified.
+    pub fn into_redacted(
+        self,
+        redacted_because: UnsignedRoomRedactionEvent,
+        version: &RoomVersionId,
+    ) -> Self {
+        match self {
+            Self::Original(ev) => Self::Redacted(RedactedRoomRedactionEvent {
+                content: ev.content.redact(version),
+                event_id: ev.event_id,
+                sender: ev.sender,
+                origin_server_ts: ev.origin_server_ts,
+                room_id: ev.room_id,
+                unsigned: RedactedUnsigned::new(redacted_because),
+            }),
+            Self::Redacted(_) => self,
+        }
+    }
 }
 
 impl SyncRoomRedactionEvent {
@@ -369,6 +399,34 @@ impl SyncRoomRedactionEvent {
         as_variant!(self, Self::Original)
     }
 
+    /// Returns the redaction event from inside `unsigned`, if this event was redacted.
+    pub fn redacted_because(&self) -> Option<&UnsignedRoomRedactionEvent> {
+        as_variant!(self, Self::Redacted).map(|ev| &ev.unsigned.redacted_because)
+    }
+
+    /// Transform `self` into its redacted form, removing the fields of the content that are not
+    /// preserved by redaction.
+    ///
+    /// If `self` is already redacted, it is returned unmodified.
+    ///
+    /// The fields that are preserved depend on the room version, so a version has to be specified.
+    pub fn into_redacted(
+        self,
+        redacted_because: UnsignedRoomRedactionEvent,
+        version: &RoomVersionId,
+    ) -> Self {
+        match self {
+            Self::Original(ev) => Self::Redacted(RedactedSyncRoomRedactionEvent {
+                content: ev.content.redact(version),
+                event_id: ev.event_id,
+                sender: ev.sender,
+                origin_server_ts: ev.origin_server_ts,
+                unsigned: RedactedUnsigned::new(redacted_because),
+            }),
+            Self::Redacted(_) => self,
+        }
+    }
+
     /// Convert this sync event into a full event (one with a `room_id` field).
     pub fn into_full_event(self, room_id: OwnedRoomId) -> RoomRedactionEvent {
         match self {
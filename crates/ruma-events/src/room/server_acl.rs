@@ -5,7 +5,6 @@
 use ruma_common::ServerName;
 use ruma_macros::EventContent;
 use serde::{Deserialize, Serialize};
-use wildmatch::WildMatch;
 
 use crate::EmptyStateKey;
 
@@ -59,10 +58,15 @@ impl RoomServerAclEventContent {
             return false;
         }
 
-        let host = server_name.host();
+        self.deny.iter().all(|d| !server_name.is_valid_acl_glob_match(d))
+            && self.allow.iter().any(|a| server_name.is_valid_acl_glob_match(a))
+    }
 
-        self.deny.iter().all(|d| !WildMatch::new(d).matches(host))
-            && self.allow.iter().any(|a| WildMatch::new(a).matches(host))
+    /// Returns true if and only if the server is denied by the ACL rules.
+    ///
+    /// This is the negation of [`is_allowed()`](Self::is_allowed).
+    pub fn is_denied(&self, server_name: &ServerName) -> bool {
+        !self.is_allowed(server_name)
     }
 }
 
@@ -178,4 +182,42 @@ mod tests {
         assert!(!acl_event.is_allowed(server_name!("[2001:db8:1234::2]")));
         assert!(acl_event.is_allowed(server_name!("[2001:db8:1234::1]")));
     }
+
+    #[test]
+    fn acl_spec_example() {
+        let acl_event = from_json_value::<RoomServerAclEventContent>(json!({
+            "allow": ["*"],
+            "allow_ip_literals": false,
+            "deny": ["*.evil.com", "evil.com"],
+        }))
+        .unwrap();
+
+        assert!(acl_event.is_allowed(server_name!("matrix.org")));
+        assert!(!acl_event.is_denied(server_name!("matrix.org")));
+        assert!(acl_event.is_denied(server_name!("evil.com")));
+        assert!(acl_event.is_denied(server_name!("server.evil.com:8448")));
+        assert!(acl_event.is_allowed(server_name!("notevil.com")));
+        assert!(acl_event.is_denied(server_name!("1.1.1.1")));
+    }
+
+    #[test]
+    fn acl_empty_allow() {
+        let acl_event = RoomServerAclEventContent::new(true, Vec::new(), Vec::new());
+        assert!(acl_event.is_denied(server_name!("matrix.org")));
+        assert!(acl_event.is_denied(server_name!("1.1.1.1")));
+    }
+
+    #[test]
+    fn acl_multiple_wildcards() {
+        let acl_event = RoomServerAclEventContent::new(
+            false,
+            vec!["*.matrix.*".to_owned(), "ruma?.*.io".to_owned()],
+            vec!["*bad*".to_owned()],
+        );
+        assert!(acl_event.is_allowed(server_name!("server.matrix.org")));
+        assert!(acl_event.is_allowed(server_name!("ruma1.server.io")));
+        assert!(acl_event.is_denied(server_name!("ruma.server.io")));
+        assert!(acl_event.is_denied(server_name!("bad.matrix.org")));
+        assert!(acl_event.is_denied(server_name!("server.matrix.badhost")));
+    }
 }
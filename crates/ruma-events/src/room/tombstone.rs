@@ -2,7 +2,7 @@
 //!
 //! [`m.room.tombstone`]: https://spec.matrix.org/latest/client-server-api/#mroomtombstone
 
-use ruma_common::OwnedRoomId;
+use ruma_common::{OwnedRoomId, RoomId};
 use ruma_macros::EventContent;
 use serde::{Deserialize, Serialize};
 
@@ -40,6 +40,44 @@ impl RoomTombstoneEventContent {
     pub fn new(body: String, replacement_room: OwnedRoomId) -> Self {
         Self { body, replacement_room }
     }
+
+    /// The maximum number of characters allowed in the `body`, as checked by
+    /// [`validate_body_length()`](Self::validate_body_length).
+    pub const MAX_BODY_LENGTH: usize = 1000;
+
+    /// Check that this tombstone doesn't point to the room it is sent in.
+    ///
+    /// Returns an error if the replacement room is the given current room.
+    pub fn validate(&self, current_room_id: &RoomId) -> Result<(), TombstoneValidationError> {
+        if self.replacement_room == current_room_id {
+            return Err(TombstoneValidationError::SelfReferential);
+        }
+
+        Ok(())
+    }
+
+    /// Check that the `body` of this tombstone is not longer than [`Self::MAX_BODY_LENGTH`]
+    /// characters.
+    pub fn validate_body_length(&self) -> Result<(), TombstoneValidationError> {
+        if self.body.chars().count() > Self::MAX_BODY_LENGTH {
+            return Err(TombstoneValidationError::BodyTooLong);
+        }
+
+        Ok(())
+    }
+}
+
+/// An error encountered when validating a [`RoomTombstoneEventContent`].
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, thiserror::Error)]
+#[non_exhaustive]
+pub enum TombstoneValidationError {
+    /// The replacement room is the room the tombstone is sent in.
+    #[error("the replacement room is the current room")]
+    SelfReferential,
+
+    /// The body is longer than [`RoomTombstoneEventContent::MAX_BODY_LENGTH`] characters.
+    #[error("the body is too long")]
+    BodyTooLong,
 }
 
 /// The possibly redacted form of [`RoomTombstoneEventContent`].
@@ -70,3 +108,38 @@ impl PossiblyRedactedStateEventContent for PossiblyRedactedRoomTombstoneEventCon
 impl StaticEventContent for PossiblyRedactedRoomTombstoneEventContent {
     const TYPE: &'static str = "m.room.tombstone";
 }
+
+#[cfg(test)]
+mod tests {
+    use ruma_common::{owned_room_id, room_id};
+
+    use super::{RoomTombstoneEventContent, TombstoneValidationError};
+
+    #[test]
+    fn validate_replacement_room() {
+        let content = RoomTombstoneEventContent::new(
+            "This room has been replaced".to_owned(),
+            owned_room_id!("!newroom:example.org"),
+        );
+
+        content.validate(room_id!("!oldroom:example.org")).unwrap();
+        assert_eq!(
+            content.validate(room_id!("!newroom:example.org")),
+            Err(TombstoneValidationError::SelfReferential)
+        );
+    }
+
+    #[test]
+    fn validate_body_length() {
+        let mut content =
+            RoomTombstoneEventContent::new(String::new(), owned_room_id!("!newroom:example.org"));
+        content.validate_body_length().unwrap();
+
+        // Characters are counted, not bytes.
+        content.body = "é".repeat(RoomTombstoneEventContent::MAX_BODY_LENGTH);
+        content.validate_body_length().unwrap();
+
+        content.body.push('é');
+        assert_eq!(content.validate_body_length(), Err(TombstoneValidationError::BodyTooLong));
+    }
+}
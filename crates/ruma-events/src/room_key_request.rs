@@ -14,6 +14,7 @@ use crate::PrivOwnedStr;
 #[derive(Clone, Debug, Deserialize, Serialize, EventContent)]
 #[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
 #[ruma_event(type = "m.room_key_request", kind = ToDevice)]
+#[serde(try_from = "ToDeviceRoomKeyRequestEventContentDeHelper")]
 pub struct ToDeviceRoomKeyRequestEventContent {
     /// Whether this is a new key request or a cancellation of a previous request.
     pub action: Action,
@@ -44,6 +45,52 @@ impl ToDeviceRoomKeyRequestEventContent {
     ) -> Self {
         Self { action, body, requesting_device_id, request_id }
     }
+
+    /// Check that this key request is valid.
+    ///
+    /// A key request is invalid if its action is `request` and it doesn't have a `body`.
+    ///
+    /// This is checked during deserialization.
+    pub fn validate(&self) -> Result<(), KeyRequestValidationError> {
+        if self.action == Action::Request && self.body.is_none() {
+            return Err(KeyRequestValidationError::MissingBody);
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Deserialize)]
+struct ToDeviceRoomKeyRequestEventContentDeHelper {
+    action: Action,
+    body: Option<RequestedKeyInfo>,
+    requesting_device_id: OwnedDeviceId,
+    request_id: OwnedTransactionId,
+}
+
+impl TryFrom<ToDeviceRoomKeyRequestEventContentDeHelper> for ToDeviceRoomKeyRequestEventContent {
+    type Error = KeyRequestValidationError;
+
+    fn try_from(value: ToDeviceRoomKeyRequestEventContentDeHelper) -> Result<Self, Self::Error> {
+        let ToDeviceRoomKeyRequestEventContentDeHelper {
+            action,
+            body,
+            requesting_device_id,
+            request_id,
+        } = value;
+        let content = Self::new(action, body, requesting_device_id, request_id);
+        content.validate()?;
+        Ok(content)
+    }
+}
+
+/// An error encountered when validating a [`ToDeviceRoomKeyRequestEventContent`].
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, thiserror::Error)]
+#[non_exhaustive]
+pub enum KeyRequestValidationError {
+    /// The action is `request` but the information about the requested key is missing.
+    #[error("missing body for key request")]
+    MissingBody,
 }
 
 /// A new key request or a cancellation of a previous request.
@@ -39,3 +39,20 @@ impl fmt::Debug for ToDeviceSecretSendEventContent {
             .finish_non_exhaustive()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ToDeviceSecretSendEventContent;
+
+    #[test]
+    fn debug_omits_secret() {
+        let content = ToDeviceSecretSendEventContent::new(
+            "randomly_generated_id_9573".into(),
+            "ThisIsASecretDon'tTellAnyone".to_owned(),
+        );
+
+        let debug = format!("{content:?}");
+        assert!(debug.contains("randomly_generated_id_9573"));
+        assert!(!debug.contains("ThisIsASecretDon'tTellAnyone"));
+    }
+}
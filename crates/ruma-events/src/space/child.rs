@@ -2,6 +2,8 @@
 //!
 //! [`m.space.child`]: https://spec.matrix.org/latest/client-server-api/#mspacechild
 
+use std::cmp::Ordering;
+
 use ruma_common::{MilliSecondsSinceUnixEpoch, OwnedRoomId, OwnedServerName, OwnedUserId};
 use ruma_macros::{Event, EventContent};
 use serde::{Deserialize, Serialize};
@@ -49,6 +51,43 @@ impl SpaceChildEventContent {
     pub fn new(via: Vec<OwnedServerName>) -> Self {
         Self { via, order: None, suggested: false }
     }
+
+    /// Creates a new `SpaceChildEventContent` that removes a child from the space.
+    ///
+    /// Children are removed by sending an `m.space.child` event with an empty `via`.
+    pub fn deletion() -> Self {
+        Self::new(Vec::new())
+    }
+
+    /// Whether this event is a valid space child, i.e. it was not deleted.
+    ///
+    /// Children that have an empty `via` are not part of the space.
+    pub fn is_canonical(&self) -> bool {
+        !self.via.is_empty()
+    }
+
+    /// The `order` of this child, if it is valid.
+    ///
+    /// Returns `None` if `order` is not set, contains characters outside of the range `\x20`
+    /// (space) to `\x7E` (`~`), or is longer than 50 characters.
+    pub fn valid_order(&self) -> Option<&str> {
+        self.order
+            .as_deref()
+            .filter(|order| order.len() <= 50 && order.bytes().all(|b| (0x20..=0x7E).contains(&b)))
+    }
+
+    /// Compares the `order` of this child with the one of another child.
+    ///
+    /// Children with a valid `order` are sorted lexicographically, and before children without a
+    /// valid `order`.
+    pub fn cmp_order(&self, other: &Self) -> Ordering {
+        match (self.valid_order(), other.valid_order()) {
+            (Some(order), Some(other_order)) => order.cmp(other_order),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+    }
 }
 
 /// An `m.space.child` event represented as a Stripped State Event with an added `origin_server_ts`
@@ -69,6 +108,19 @@ pub struct HierarchySpaceChildEvent {
     pub origin_server_ts: MilliSecondsSinceUnixEpoch,
 }
 
+impl HierarchySpaceChildEvent {
+    /// Compares the position of this child in the space with the one of another child.
+    ///
+    /// Children are sorted according to [`SpaceChildEventContent::cmp_order()`], and ties are
+    /// broken by their `origin_server_ts`, then by the lexicographic order of their room IDs.
+    pub fn cmp_order(&self, other: &Self) -> Ordering {
+        self.content
+            .cmp_order(&other.content)
+            .then_with(|| self.origin_server_ts.cmp(&other.origin_server_ts))
+            .then_with(|| self.state_key.cmp(&other.state_key))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use js_int::uint;
@@ -124,4 +176,82 @@ mod tests {
         assert_eq!(ev.content.order, None);
         assert!(!ev.content.suggested);
     }
+
+    #[test]
+    fn space_child_deletion() {
+        let content = SpaceChildEventContent::deletion();
+        assert!(!content.is_canonical());
+        assert_eq!(to_json_value(&content).unwrap(), json!({ "via": [] }));
+
+        let content = SpaceChildEventContent::new(vec![server_name!("example.com").to_owned()]);
+        assert!(content.is_canonical());
+    }
+
+    #[test]
+    fn space_child_valid_order() {
+        let mut content = SpaceChildEventContent::new(vec![server_name!("example.com").to_owned()]);
+        assert_eq!(content.valid_order(), None);
+
+        content.order = Some("a valid order~".to_owned());
+        assert_eq!(content.valid_order(), Some("a valid order~"));
+
+        content.order = Some("ünicode".to_owned());
+        assert_eq!(content.valid_order(), None);
+
+        content.order = Some("a".repeat(51));
+        assert_eq!(content.valid_order(), None);
+    }
+
+    #[test]
+    fn hierarchy_space_child_ordering() {
+        fn child(room_id: &str, order: Option<&str>, ts: u64) -> HierarchySpaceChildEvent {
+            from_json_value(json!({
+                "content": {
+                    "via": ["example.org"],
+                    "order": order,
+                },
+                "origin_server_ts": ts,
+                "sender": "@alice:example.org",
+                "state_key": room_id,
+                "type": "m.space.child"
+            }))
+            .unwrap()
+        }
+
+        let mut children = [
+            child("!d:example.org", None, 1_629_413_349),
+            child("!c:example.org", Some("b"), 1_629_413_349),
+            child("!b:example.org", Some("a"), 1_629_413_349),
+            child("!a:example.org", Some("b"), 1_629_413_349),
+            child("!e:example.org", Some("\u{7F}"), 1_629_413_349),
+        ];
+        children.sort_by(HierarchySpaceChildEvent::cmp_order);
+
+        let room_ids: Vec<_> = children.iter().map(|child| child.state_key.as_str()).collect();
+        assert_eq!(
+            room_ids,
+            [
+                "!b:example.org",
+                "!a:example.org",
+                "!c:example.org",
+                "!d:example.org",
+                "!e:example.org"
+            ]
+        );
+
+        // Ties are broken by the timestamp before the room ID.
+        let mut children = [
+            child("!a:example.org", Some("a"), 1_629_413_350),
+            child("!b:example.org", Some("a"), 1_629_413_349),
+            child("!c:example.org", None, 1_629_413_350),
+            child("!d:example.org", None, 1_629_413_348),
+        ];
+        children.sort_by(HierarchySpaceChildEvent::cmp_order);
+
+        let room_ids: Vec<_> = children.iter().map(|child| child.state_key.as_str()).collect();
+        assert_eq!(
+            room_ids,
+            ["!b:example.org", "!a:example.org", "!d:example.org", "!c:example.org"]
+        );
+    }
 }
@@ -38,6 +38,25 @@ impl SpaceParentEventContent {
     pub fn new(via: Vec<OwnedServerName>) -> Self {
         Self { via, canonical: false }
     }
+
+    /// Creates a new `SpaceParentEventContent` that removes a parent from the room.
+    ///
+    /// Parents are removed by sending an `m.space.parent` event with an empty `via`.
+    pub fn deletion() -> Self {
+        Self::new(Vec::new())
+    }
+
+    /// Whether this event removes the parent from the room, i.e. its `via` is empty.
+    pub fn is_deleted(&self) -> bool {
+        self.via.is_empty()
+    }
+
+    /// Whether this event designates the main parent of the room.
+    ///
+    /// This is the case if `canonical` is `true` and the parent was not deleted.
+    pub fn is_canonical_parent(&self) -> bool {
+        self.canonical && !self.is_deleted()
+    }
 }
 
 #[cfg(test)]
@@ -70,4 +89,28 @@ mod tests {
 
         assert_eq!(to_json_value(&content).unwrap(), json);
     }
+
+    #[test]
+    fn space_parent_canonical() {
+        let mut content =
+            SpaceParentEventContent::new(vec![server_name!("example.com").to_owned()]);
+        assert!(!content.is_canonical_parent());
+
+        content.canonical = true;
+        assert!(content.is_canonical_parent());
+
+        content.via.clear();
+        assert!(!content.is_canonical_parent());
+    }
+
+    #[test]
+    fn space_parent_deletion() {
+        let content = SpaceParentEventContent::deletion();
+        assert!(content.is_deleted());
+        assert!(!content.is_canonical_parent());
+        assert_eq!(to_json_value(&content).unwrap(), json!({ "via": [] }));
+
+        let content = SpaceParentEventContent::new(vec![server_name!("example.com").to_owned()]);
+        assert!(!content.is_deleted());
+    }
 }
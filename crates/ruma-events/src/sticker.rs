@@ -2,6 +2,7 @@
 //!
 //! [`m.sticker`]: https://spec.matrix.org/latest/client-server-api/#msticker
 
+use js_int::uint;
 use ruma_common::OwnedMxcUri;
 use ruma_macros::EventContent;
 use serde::{de, Deserialize, Serialize};
@@ -107,4 +108,61 @@ impl StickerEventContent {
     pub fn with_source(body: String, info: ImageInfo, source: StickerMediaSource) -> Self {
         Self { body, info, source }
     }
+
+    /// Creates a new `StickerEventContent` with the given body, image info and URL, after checking
+    /// that they are valid.
+    ///
+    /// See [`StickerEventContent::validate()`] for the checks that are performed.
+    pub fn try_new(
+        body: String,
+        info: ImageInfo,
+        url: OwnedMxcUri,
+    ) -> Result<Self, StickerValidationError> {
+        let content = Self::new(body, info, url);
+        content.validate()?;
+        Ok(content)
+    }
+
+    /// Check that this sticker is valid.
+    ///
+    /// A sticker is valid if its image info has a non-zero width and height, and if the MXC URI of
+    /// its media source is valid.
+    pub fn validate(&self) -> Result<(), StickerValidationError> {
+        if self.info.width.map_or(true, |width| width == uint!(0)) {
+            return Err(StickerValidationError::MissingWidth);
+        }
+        if self.info.height.map_or(true, |height| height == uint!(0)) {
+            return Err(StickerValidationError::MissingHeight);
+        }
+
+        // The match is infallible without the `compat-encrypted-stickers` feature.
+        #[allow(clippy::infallible_destructuring_match)]
+        let url = match &self.source {
+            StickerMediaSource::Plain(url) => url,
+            #[cfg(feature = "compat-encrypted-stickers")]
+            StickerMediaSource::Encrypted(file) => &file.url,
+        };
+        if !url.is_valid() {
+            return Err(StickerValidationError::InvalidUrl);
+        }
+
+        Ok(())
+    }
+}
+
+/// An error encountered when validating a [`StickerEventContent`].
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, thiserror::Error)]
+#[non_exhaustive]
+pub enum StickerValidationError {
+    /// The width of the image is missing or zero.
+    #[error("missing image width")]
+    MissingWidth,
+
+    /// The height of the image is missing or zero.
+    #[error("missing image height")]
+    MissingHeight,
+
+    /// The MXC URI of the media source is invalid.
+    #[error("invalid MXC URI")]
+    InvalidUrl,
 }
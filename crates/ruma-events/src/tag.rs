@@ -2,7 +2,7 @@
 //!
 //! [`m.tag`]: https://spec.matrix.org/latest/client-server-api/#mtag
 
-use std::{collections::BTreeMap, error::Error, fmt, str::FromStr};
+use std::{cmp::Ordering, collections::BTreeMap, error::Error, fmt, str::FromStr};
 
 #[cfg(feature = "compat-tag-info")]
 use ruma_common::serde::deserialize_as_optional_number_or_string;
@@ -31,6 +31,39 @@ impl TagEventContent {
     pub fn new(tags: Tags) -> Self {
         Self { tags }
     }
+
+    /// Adds the given tag, replacing the info of the tag if it was already present.
+    pub fn add_tag(&mut self, tag: TagName, info: TagInfo) {
+        self.tags.insert(tag, info);
+    }
+
+    /// Removes the given tag.
+    ///
+    /// Returns `true` if the tag was present.
+    pub fn remove_tag(&mut self, tag: &TagName) -> bool {
+        self.tags.remove(tag).is_some()
+    }
+
+    /// Whether the given tag is present.
+    pub fn has_tag(&self, tag: &TagName) -> bool {
+        self.tags.contains_key(tag)
+    }
+
+    /// Returns the tags sorted by their `order`.
+    ///
+    /// Tags without an `order` are sorted last. Tags with the same `order` keep the ordering of
+    /// [`TagName`].
+    pub fn tags_sorted_by_order(&self) -> Vec<(&TagName, &TagInfo)> {
+        let mut tags: Vec<_> = self.tags.iter().collect();
+        // The sort is stable so the ordering of the map is kept for equal orders.
+        tags.sort_by(|(_, a), (_, b)| match (a.order, b.order) {
+            (Some(a), Some(b)) => a.total_cmp(&b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        });
+        tags
+    }
 }
 
 impl From<Tags> for TagEventContent {
@@ -264,4 +297,43 @@ mod tests {
         assert_eq!(TagName::from("rs.conduit.rules").display_name(), "rules");
         assert_eq!(TagName::from("Play").display_name(), "Play");
     }
+
+    #[test]
+    fn mutation_helpers() {
+        let mut content = TagEventContent::new(Default::default());
+        assert!(!content.has_tag(&TagName::Favorite));
+
+        content.add_tag(TagName::Favorite, TagInfo::new());
+        content.add_tag(TagName::Favorite, TagInfo { order: Some(0.5) });
+        content.add_tag("u.work".to_owned().into(), TagInfo::new());
+        assert!(content.has_tag(&TagName::Favorite));
+        assert_eq!(content.tags[&TagName::Favorite].order, Some(0.5));
+
+        assert!(content.remove_tag(&"u.work".to_owned().into()));
+        assert!(!content.remove_tag(&TagName::LowPriority));
+
+        let json = to_json_value(&content).unwrap();
+        assert_eq!(json, json!({ "tags": { "m.favourite": { "order": 0.5 } } }));
+        let content = from_json_value::<TagEventContent>(json).unwrap();
+        assert!(content.has_tag(&TagName::Favorite));
+        assert!(!content.has_tag(&"u.work".to_owned().into()));
+    }
+
+    #[test]
+    fn tags_sorted_by_order() {
+        let content = TagEventContent::new(btreemap! {
+            TagName::Favorite => TagInfo::new(),
+            TagName::LowPriority => TagInfo { order: Some(0.8) },
+            TagName::ServerNotice => TagInfo::new(),
+            "u.first".to_owned().into() => TagInfo { order: Some(0.1) },
+            "u.second".to_owned().into() => TagInfo { order: Some(0.8) },
+        });
+
+        let names: Vec<_> =
+            content.tags_sorted_by_order().into_iter().map(|(name, _)| name.as_ref()).collect();
+        assert_eq!(
+            names,
+            ["u.first", "m.lowpriority", "u.second", "m.favourite", "m.server_notice"]
+        );
+    }
 }
@@ -2,7 +2,7 @@
 //!
 //! [`m.typing`]: https://spec.matrix.org/latest/client-server-api/#mtyping
 
-use ruma_common::OwnedUserId;
+use ruma_common::{MilliSecondsSinceUnixEpoch, OwnedUserId, UserId};
 use ruma_macros::EventContent;
 use serde::{Deserialize, Serialize};
 
@@ -22,4 +22,93 @@ impl TypingEventContent {
     pub fn new(user_ids: Vec<OwnedUserId>) -> Self {
         Self { user_ids }
     }
+
+    /// Whether the given user is typing.
+    pub fn contains(&self, user_id: &UserId) -> bool {
+        self.user_ids.iter().any(|id| id == user_id)
+    }
+
+    /// Add the given user to the list of users typing.
+    ///
+    /// Does nothing if the user is already typing.
+    pub fn add_typing(&mut self, user_id: OwnedUserId) {
+        if !self.contains(&user_id) {
+            self.user_ids.push(user_id);
+        }
+    }
+
+    /// Remove the given user from the list of users typing.
+    ///
+    /// Returns `true` if the user was typing.
+    pub fn stop_typing(&mut self, user_id: &UserId) -> bool {
+        let len = self.user_ids.len();
+        self.user_ids.retain(|id| id != user_id);
+        self.user_ids.len() != len
+    }
+}
+
+/// A [`TypingEventContent`] that is only valid until a given time.
+///
+/// This can be used to stop considering that users are typing if no new `m.typing` event was
+/// received in time.
+#[derive(Clone, Debug)]
+#[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+pub struct TypingWithExpiry {
+    /// The list of users typing.
+    pub content: TypingEventContent,
+
+    /// The time after which the list of users typing should be considered stale.
+    pub expires_at: MilliSecondsSinceUnixEpoch,
+}
+
+impl TypingWithExpiry {
+    /// Creates a new `TypingWithExpiry` with the given content and expiry time.
+    pub fn new(content: TypingEventContent, expires_at: MilliSecondsSinceUnixEpoch) -> Self {
+        Self { content, expires_at }
+    }
+
+    /// Whether the given user is typing at the given time.
+    ///
+    /// Returns `false` if the list has expired.
+    pub fn is_still_typing(&self, user_id: &UserId, now: MilliSecondsSinceUnixEpoch) -> bool {
+        now < self.expires_at && self.content.contains(user_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use js_int::uint;
+    use ruma_common::{owned_user_id, user_id, MilliSecondsSinceUnixEpoch};
+
+    use super::{TypingEventContent, TypingWithExpiry};
+
+    #[test]
+    fn add_and_stop_typing() {
+        let mut content = TypingEventContent::default();
+        let alice = owned_user_id!("@alice:localhost");
+
+        content.add_typing(alice.clone());
+        content.add_typing(alice.clone());
+        assert_eq!(content.user_ids, [alice.clone()]);
+        assert!(content.contains(&alice));
+        assert!(!content.contains(user_id!("@bob:localhost")));
+
+        assert!(content.stop_typing(&alice));
+        assert!(!content.stop_typing(&alice));
+        assert!(!content.contains(&alice));
+    }
+
+    #[test]
+    fn is_still_typing() {
+        let alice = user_id!("@alice:localhost");
+        let typing = TypingWithExpiry::new(
+            TypingEventContent::new(vec![alice.to_owned()]),
+            MilliSecondsSinceUnixEpoch(uint!(30_000)),
+        );
+
+        assert!(typing.is_still_typing(alice, MilliSecondsSinceUnixEpoch(uint!(29_999))));
+        assert!(!typing.is_still_typing(alice, MilliSecondsSinceUnixEpoch(uint!(30_000))));
+        assert!(!typing
+            .is_still_typing(user_id!("@bob:localhost"), MilliSecondsSinceUnixEpoch(uint!(0))));
+    }
 }
@@ -4,9 +4,16 @@ use std::time::Duration;
 
 use assert_matches2::assert_matches;
 use js_int::uint;
-use ruma_common::{event_id, room_id, serde::CanBeEmpty, user_id, MilliSecondsSinceUnixEpoch};
+use ruma_common::{
+    event_id, room_id,
+    serde::CanBeEmpty,
+    time::{MockClock, SYSTEM_CLOCK},
+    user_id, MilliSecondsSinceUnixEpoch,
+};
 use ruma_events::{
-    beacon_info::BeaconInfoEventContent, location::AssetType, AnyStateEvent, StateEvent,
+    beacon_info::{BeaconInfoEventContent, PossiblyRedactedBeaconInfoEventContent},
+    location::AssetType,
+    AnyStateEvent, StateEvent, StateEventType,
 };
 use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
 
@@ -37,15 +44,64 @@ fn get_beacon_info_json() -> serde_json::Value {
 fn beacon_info_is_live() {
     let event_content = get_beacon_info_event_content(None, None);
 
-    assert!(event_content.is_live());
+    assert!(event_content.is_live(&SYSTEM_CLOCK));
 }
 
 #[test]
 fn beacon_info_is_not_live() {
-    let duration = Some(Duration::from_nanos(1));
-    let event_content = get_beacon_info_event_content(duration, None);
+    let ts = Some(MilliSecondsSinceUnixEpoch(uint!(1_636_829_458)));
+    let event_content = get_beacon_info_event_content(None, ts);
+
+    assert!(!event_content.is_live(&SYSTEM_CLOCK));
+}
+
+#[test]
+fn beacon_info_is_live_with_mock_clock() {
+    let ts = MilliSecondsSinceUnixEpoch(uint!(1_636_829_458));
+    let mut event_content = get_beacon_info_event_content(Some(Duration::from_secs(60)), Some(ts));
+
+    assert!(event_content.is_live(&MockClock(ts)));
+    assert!(event_content.is_live(&MockClock(MilliSecondsSinceUnixEpoch(uint!(1_636_889_457)))));
+    assert!(!event_content.is_live(&MockClock(MilliSecondsSinceUnixEpoch(uint!(1_636_889_458)))));
+
+    event_content.stop();
+    assert!(!event_content.is_live(&MockClock(ts)));
+}
+
+#[test]
+fn beacon_info_expiry() {
+    let ts = MilliSecondsSinceUnixEpoch(uint!(1_636_829_458));
+    let mut event_content = get_beacon_info_event_content(Some(Duration::from_secs(60)), Some(ts));
+    let grace = Duration::from_secs(5);
 
-    assert!(!event_content.is_live());
+    // Before the timeout.
+    let clock = MockClock(ts);
+    assert!(!event_content.has_expired(grace, &clock));
+    assert_eq!(event_content.time_until_expiry(&clock), Some(Duration::from_secs(60)));
+
+    let clock = MockClock(MilliSecondsSinceUnixEpoch(uint!(1_636_889_457)));
+    assert!(!event_content.has_expired(grace, &clock));
+    assert_eq!(event_content.time_until_expiry(&clock), Some(Duration::from_millis(1)));
+
+    // During the grace period.
+    let clock = MockClock(MilliSecondsSinceUnixEpoch(uint!(1_636_889_458)));
+    assert!(!event_content.has_expired(grace, &clock));
+    assert!(event_content
+        .has_expired(Duration::ZERO, &MockClock(MilliSecondsSinceUnixEpoch(uint!(1_636_889_459)))));
+    assert_eq!(event_content.time_until_expiry(&clock), None);
+
+    let clock = MockClock(MilliSecondsSinceUnixEpoch(uint!(1_636_894_458)));
+    assert!(!event_content.has_expired(grace, &clock));
+
+    // After the grace period.
+    let clock = MockClock(MilliSecondsSinceUnixEpoch(uint!(1_636_894_459)));
+    assert!(event_content.has_expired(grace, &clock));
+    assert_eq!(event_content.time_until_expiry(&clock), None);
+
+    // Stopped beacons never expire.
+    event_content.stop();
+    assert!(!event_content.has_expired(grace, &clock));
+    assert_eq!(event_content.time_until_expiry(&MockClock(ts)), None);
 }
 
 #[test]
@@ -159,3 +215,54 @@ fn state_event_deserialization() {
     assert_eq!(ev.state_key, "@example:example.com");
     assert!(ev.unsigned.is_empty());
 }
+
+#[test]
+fn state_event_alias_deserialization() {
+    let json_data = json!({
+        "content": get_beacon_info_json(),
+        "event_id": "$beacon_event_id:example.com",
+        "origin_server_ts": 1_636_829_458,
+        "room_id": "!roomid:example.com",
+        "type": "m.beacon_info",
+        "sender": "@example:example.com",
+        "state_key": "@example:example.com"
+    });
+
+    assert_eq!(StateEventType::from("m.beacon_info"), StateEventType::BeaconInfo);
+
+    let event = from_json_value::<AnyStateEvent>(json_data).unwrap();
+    assert_matches!(event, AnyStateEvent::BeaconInfo(StateEvent::Original(ev)));
+    assert_eq!(ev.content.description, Some("Kylie's live location".to_owned()));
+    assert_eq!(ev.state_key, "@example:example.com");
+}
+
+#[test]
+fn beacon_info_content_live_as_string_deserialization() {
+    let mut json_data = get_beacon_info_json();
+    json_data["live"] = json!("false");
+
+    let content = from_json_value::<BeaconInfoEventContent>(json_data).unwrap();
+    assert!(!content.live);
+    assert_eq!(to_json_value(content).unwrap()["live"], json!(false));
+}
+
+#[test]
+fn beacon_info_content_missing_live_deserialization() {
+    let mut json_data = get_beacon_info_json();
+    json_data.as_object_mut().unwrap().remove("live");
+
+    from_json_value::<BeaconInfoEventContent>(json_data).unwrap_err();
+}
+
+#[test]
+fn possibly_redacted_beacon_info_content_deserialization() {
+    let mut json_data = get_beacon_info_json();
+    json_data["live"] = json!("true");
+
+    let content = from_json_value::<PossiblyRedactedBeaconInfoEventContent>(json_data).unwrap();
+    assert_eq!(content.live, Some(true));
+
+    let content = from_json_value::<PossiblyRedactedBeaconInfoEventContent>(json!({})).unwrap();
+    assert_eq!(content.live, None);
+    assert_eq!(content.ts, None);
+}
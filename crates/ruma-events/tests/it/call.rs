@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use assert_matches2::assert_matches;
 #[cfg(feature = "unstable-msc2747")]
 use assign::assign;
@@ -10,11 +12,11 @@ use ruma_events::{
         answer::CallAnswerEventContent,
         candidates::{CallCandidatesEventContent, Candidate},
         hangup::{CallHangupEventContent, Reason},
-        invite::CallInviteEventContent,
+        invite::{CallInviteEventContent, OriginalSyncCallInviteEvent},
         negotiate::CallNegotiateEventContent,
         reject::CallRejectEventContent,
         select_answer::CallSelectAnswerEventContent,
-        SessionDescription,
+        CallType, SessionDescription,
     },
     AnyMessageLikeEvent, AnySyncMessageLikeEvent, MessageLikeEvent,
 };
@@ -608,3 +610,77 @@ fn select_v1_answer_event_deserialization() {
     assert_eq!(content.selected_party_id, "6336");
     assert_eq!(content.version, VoipVersionId::V1);
 }
+
+#[test]
+fn invite_call_type() {
+    let audio_sdp = "v=0\r\no=- 0 0 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\n\
+                     m=audio 9 UDP/TLS/RTP/SAVPF 111\r\na=rtpmap:111 opus/48000/2\r\n";
+    let content = CallInviteEventContent::version_1(
+        "abcdef".into(),
+        "9876".into(),
+        uint!(60000),
+        SessionDescription::new("offer".to_owned(), audio_sdp.to_owned()),
+    );
+    assert_eq!(content.call_type(), CallType::Audio);
+
+    let video_sdp =
+        format!("{audio_sdp}m=video 9 UDP/TLS/RTP/SAVPF 96\r\na=rtpmap:96 VP8/90000\r\n");
+    let content = CallInviteEventContent::version_1(
+        "abcdef".into(),
+        "9876".into(),
+        uint!(60000),
+        SessionDescription::new("offer".to_owned(), video_sdp),
+    );
+    assert_eq!(content.call_type(), CallType::Video);
+}
+
+#[test]
+fn invite_lifetime_remaining() {
+    let json_data = json!({
+        "content": {
+            "call_id": "abcdef",
+            "party_id": "9876",
+            "lifetime": 60000,
+            "version": "1",
+            "offer": {
+                "type": "offer",
+                "sdp": "not a real sdp",
+            },
+        },
+        "event_id": "$event:notareal.hs",
+        "origin_server_ts": 100_000,
+        "sender": "@user:notareal.hs",
+        "type": "m.call.invite",
+    });
+    let event = from_json_value::<OriginalSyncCallInviteEvent>(json_data).unwrap();
+
+    assert_eq!(
+        event.lifetime_remaining(MilliSecondsSinceUnixEpoch(uint!(100_000))),
+        Some(Duration::from_secs(60))
+    );
+    assert_eq!(
+        event.lifetime_remaining(MilliSecondsSinceUnixEpoch(uint!(150_000))),
+        Some(Duration::from_secs(10))
+    );
+    assert_eq!(event.lifetime_remaining(MilliSecondsSinceUnixEpoch(uint!(160_000))), None);
+    assert_eq!(event.lifetime_remaining(MilliSecondsSinceUnixEpoch(uint!(200_000))), None);
+}
+
+#[test]
+fn hangup_reason_display_string() {
+    let reasons = [
+        (Reason::IceFailed, "Connection failed", true),
+        (Reason::InviteTimeout, "Call was not answered", true),
+        (Reason::IceTimeout, "Connection lost", true),
+        (Reason::UserHangup, "Caller hung up", false),
+        (Reason::UserMediaFailed, "Could not access camera or microphone", false),
+        (Reason::UserBusy, "Recipient is busy", false),
+        (Reason::UnknownError, "Unknown error", false),
+        (Reason::from("io.ruma.custom_reason"), "io.ruma.custom_reason", false),
+    ];
+
+    for (reason, display, is_error) in reasons {
+        assert_eq!(reason.reason_display_string(), display);
+        assert_eq!(reason.is_error_condition(), is_error, "{reason:?}");
+    }
+}
@@ -7,12 +7,12 @@ use ruma_events::{
         message::{MessageType, RoomMessageEventContent},
         power_levels::RoomPowerLevelsEventContent,
     },
-    AnyEphemeralRoomEvent, AnyMessageLikeEvent, AnyStateEvent, AnySyncMessageLikeEvent,
-    AnySyncStateEvent, AnySyncTimelineEvent, AnyTimelineEvent, EphemeralRoomEventType,
-    GlobalAccountDataEventType, MessageLikeEvent, MessageLikeEventType, OriginalMessageLikeEvent,
-    OriginalStateEvent, OriginalSyncMessageLikeEvent, OriginalSyncStateEvent,
-    RoomAccountDataEventType, StateEvent, StateEventType, SyncMessageLikeEvent, SyncStateEvent,
-    ToDeviceEventType,
+    AnyEphemeralRoomEvent, AnyGlobalAccountDataEvent, AnyMessageLikeEvent, AnyRoomAccountDataEvent,
+    AnyStateEvent, AnySyncMessageLikeEvent, AnySyncStateEvent, AnySyncTimelineEvent,
+    AnyTimelineEvent, EphemeralRoomEventType, GlobalAccountDataEventType, MessageLikeEvent,
+    MessageLikeEventType, OriginalMessageLikeEvent, OriginalStateEvent,
+    OriginalSyncMessageLikeEvent, OriginalSyncStateEvent, RoomAccountDataEventType, StateEvent,
+    StateEventType, SyncMessageLikeEvent, SyncStateEvent, ToDeviceEventType,
 };
 use serde_json::{from_value as from_json_value, json, Value as JsonValue};
 
@@ -253,6 +253,18 @@ fn alias_event_field_access() {
     assert_eq!(deser.event_type().to_string(), "m.room.aliases");
 }
 
+#[test]
+fn state_event_accessors_match_variant() {
+    let deser = from_json_value::<AnyStateEvent>(aliases_event()).unwrap();
+    assert_matches!(&deser, AnyStateEvent::RoomAliases(StateEvent::Original(ev)));
+
+    assert_eq!(deser.state_key(), ev.state_key.as_str());
+    assert_eq!(deser.event_id(), ev.event_id);
+    assert_eq!(deser.sender(), ev.sender);
+    assert_eq!(deser.origin_server_ts(), ev.origin_server_ts);
+    assert_eq!(deser.room_id(), ev.room_id);
+}
+
 #[test]
 fn ephemeral_event_deserialization() {
     let json_data = json!({
@@ -282,3 +294,62 @@ fn serialize_and_deserialize_from_display_form() {
     serde_json_eq(StateEventType::RoomCreate, json!("m.room.create"));
     serde_json_eq(EphemeralRoomEventType::Typing, json!("m.typing"));
 }
+
+#[test]
+fn global_account_data_into_push_rules() {
+    let json_data = json!({
+        "content": {
+            "global": {
+                "content": [],
+                "override": [],
+                "room": [],
+                "sender": [],
+                "underride": [],
+            },
+        },
+        "type": "m.push_rules",
+    });
+
+    let event = from_json_value::<AnyGlobalAccountDataEvent>(json_data).unwrap();
+    assert_eq!(event.event_type(), GlobalAccountDataEventType::PushRules);
+    let content = event.into_push_rules().unwrap();
+    assert!(content.global.content.is_empty());
+    assert!(content.global.override_.is_empty());
+
+    let json_data = json!({
+        "content": {
+            "ignored_users": {},
+        },
+        "type": "m.ignored_user_list",
+    });
+
+    let event = from_json_value::<AnyGlobalAccountDataEvent>(json_data).unwrap();
+    assert_eq!(event.event_type(), GlobalAccountDataEventType::IgnoredUserList);
+    assert!(event.into_push_rules().is_none());
+}
+
+#[test]
+fn room_account_data_into_fully_read() {
+    let json_data = json!({
+        "content": {
+            "event_id": "$someplace:example.org",
+        },
+        "type": "m.fully_read",
+    });
+
+    let event = from_json_value::<AnyRoomAccountDataEvent>(json_data).unwrap();
+    assert_eq!(event.event_type(), RoomAccountDataEventType::FullyRead);
+    let content = event.into_fully_read().unwrap();
+    assert_eq!(content.event_id, "$someplace:example.org");
+
+    let json_data = json!({
+        "content": {
+            "tags": {},
+        },
+        "type": "m.tag",
+    });
+
+    let event = from_json_value::<AnyRoomAccountDataEvent>(json_data).unwrap();
+    assert_eq!(event.event_type(), RoomAccountDataEventType::Tag);
+    assert!(event.into_fully_read().is_none());
+}
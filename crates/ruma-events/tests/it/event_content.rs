@@ -1,3 +1,8 @@
+use ruma_common::{event_id, OwnedEventId};
+use ruma_events::{macros::EventContent, OriginalSyncMessageLikeEvent};
+use serde::{Deserialize, Serialize};
+use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
+
 #[test]
 fn ui() {
     let t = trybuild::TestCases::new();
@@ -8,4 +13,59 @@ fn ui() {
     t.pass("tests/it/ui/11-content-without-relation-sanity-check.rs");
     t.compile_fail("tests/it/ui/12-no-relates_to.rs");
     t.pass("tests/it/ui/13-private-event-content-type.rs");
+    t.compile_fail("tests/it/ui/14-empty-event-type.rs");
+}
+
+/// A relation that is serialized as a flat `m.relates_to` object with a custom `rel_type`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct FlatRelation {
+    rel_type: String,
+    event_id: OwnedEventId,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, EventContent)]
+#[ruma_event(type = "io.ruma.custom_relation", kind = MessageLike, without_relation)]
+struct CustomRelationEventContent {
+    body: String,
+
+    #[serde(rename = "m.relates_to", skip_serializing_if = "Option::is_none")]
+    relates_to: Option<FlatRelation>,
+}
+
+#[test]
+fn content_with_custom_relation() {
+    let json = json!({
+        "content": {
+            "body": "hello",
+            "m.relates_to": {
+                "rel_type": "io.ruma.custom",
+                "event_id": "$original",
+            },
+        },
+        "event_id": "$event",
+        "origin_server_ts": 1,
+        "sender": "@alice:localhost",
+        "type": "io.ruma.custom_relation",
+    });
+
+    let event =
+        from_json_value::<OriginalSyncMessageLikeEvent<CustomRelationEventContent>>(json).unwrap();
+    let relation = event.content.relates_to.clone().unwrap();
+    assert_eq!(relation.rel_type, "io.ruma.custom");
+    assert_eq!(relation.event_id, event_id!("$original"));
+
+    let without_relation = CustomRelationEventContentWithoutRelation::from(event.content);
+    assert_eq!(to_json_value(&without_relation).unwrap(), json!({ "body": "hello" }));
+
+    let content = without_relation.with_relation(Some(relation));
+    assert_eq!(
+        to_json_value(content).unwrap(),
+        json!({
+            "body": "hello",
+            "m.relates_to": {
+                "rel_type": "io.ruma.custom",
+                "event_id": "$original",
+            },
+        })
+    );
 }
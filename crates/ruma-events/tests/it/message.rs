@@ -121,6 +121,62 @@ fn markdown_content_serialization() {
     );
 }
 
+#[test]
+fn text_content_block_find_representations() {
+    let block = TextContentBlock::from(vec![
+        TextRepresentation::new("text/markdown", "Hello, _World_!"),
+        TextRepresentation::html("Hello, <em>World</em>!"),
+        TextRepresentation::plain("Hello, World!"),
+        TextRepresentation::html("Hello, <strong>World</strong>!"),
+    ]);
+
+    assert_eq!(block.find_plain(), Some("Hello, World!"));
+    // The first representation is returned.
+    assert_eq!(block.find_html(), Some("Hello, <em>World</em>!"));
+    assert_eq!(block.find_mimetype("text/markdown"), Some("Hello, _World_!"));
+    assert_eq!(block.find_mimetype("text/x-unknown"), None);
+
+    assert_eq!(block.preferred_representation("text/markdown"), Some("Hello, _World_!"));
+    assert_eq!(block.preferred_representation("text/x-unknown"), Some("Hello, <em>World</em>!"));
+
+    let block = TextContentBlock::plain("Hello, World!");
+    assert_eq!(block.find_html(), None);
+    assert_eq!(block.preferred_representation("text/x-unknown"), Some("Hello, World!"));
+
+    let block = TextContentBlock::from(vec![TextRepresentation::new("text/markdown", "_Hi_")]);
+    assert_eq!(block.find_plain(), None);
+    assert_eq!(block.preferred_representation("text/x-unknown"), None);
+
+    let block = TextContentBlock::from(vec![]);
+    assert_eq!(block.preferred_representation("text/plain"), None);
+}
+
+#[test]
+#[cfg(feature = "markdown")]
+fn markdown_text_content_block() {
+    let block = TextContentBlock::markdown(
+        "# Title\n\nSome **bold** text and a [link](https://ruma.dev).\n\n```rust\nlet x = 1;\n```",
+    );
+
+    assert_eq!(
+        block.find_plain(),
+        Some("# Title\n\nSome **bold** text and a [link](https://ruma.dev).\n\n```rust\nlet x = 1;\n```")
+    );
+    assert_eq!(
+        block.find_html(),
+        Some(
+            "<h1>Title</h1>\n\
+             <p>Some <strong>bold</strong> text and a <a href=\"https://ruma.dev\">link</a>.</p>\n\
+             <pre><code class=\"language-rust\">let x = 1;\n</code></pre>\n"
+        )
+    );
+
+    let block = TextContentBlock::markdown("No formatting here");
+    assert_eq!(block.len(), 1);
+    assert_eq!(block.find_plain(), Some("No formatting here"));
+    assert_eq!(block.find_html(), None);
+}
+
 #[test]
 fn reply_content_serialization() {
     #[rustfmt::skip] // rustfmt wants to merge the next two lines
@@ -3,8 +3,9 @@
 use std::{collections::BTreeMap, ops::Range};
 
 use assert_matches2::assert_matches;
+use assign::assign;
 use js_int::{uint, UInt};
-use ruma_common::{owned_event_id, MilliSecondsSinceUnixEpoch};
+use ruma_common::{owned_event_id, user_id, MilliSecondsSinceUnixEpoch};
 use ruma_events::{
     message::TextContentBlock,
     poll::{
@@ -14,6 +15,7 @@ use ruma_events::{
         start::{
             OriginalSyncPollStartEvent, PollAnswer, PollAnswers, PollAnswersError,
             PollContentBlock, PollKind, PollStartEventContent,
+            PollStartEventContentWithoutRelation,
         },
         unstable_end::UnstablePollEndEventContent,
         unstable_response::{
@@ -21,11 +23,12 @@ use ruma_events::{
         },
         unstable_start::{
             NewUnstablePollStartEventContent, OriginalSyncUnstablePollStartEvent,
-            ReplacementUnstablePollStartEventContent, UnstablePollAnswer,
+            PollConversionError, ReplacementUnstablePollStartEventContent, UnstablePollAnswer,
             UnstablePollStartContentBlock, UnstablePollStartEventContent,
         },
+        PollEndTime, PollFallbackFormatter, PollResponseData, PollResponseValidationError,
     },
-    relation::Reference,
+    relation::{InReplyTo, Reference, Replacement},
     room::message::{Relation, RelationWithoutReplacement},
     AnyMessageLikeEvent, MessageLikeEvent,
 };
@@ -460,6 +463,90 @@ fn replacement_unstable_start_content_serialization() {
     );
 }
 
+#[test]
+fn replacement_unstable_start_content_with_outer_fallback_serialization() {
+    let replaces = owned_event_id!("$replacedevent");
+    let poll_start = UnstablePollStartContentBlock::new(
+        "How's the weather?",
+        vec![
+            UnstablePollAnswer::new("not-bad", "Not bad…"),
+            UnstablePollAnswer::new("fine", "Fine."),
+        ]
+        .try_into()
+        .unwrap(),
+    );
+    let event_content =
+        ReplacementUnstablePollStartEventContent::new(poll_start.clone(), replaces.clone())
+            .with_outer_fallback(poll_start);
+
+    let poll_start_json = json!({
+        "kind": "org.matrix.msc3381.poll.undisclosed",
+        "max_selections": 1,
+        "question": { "org.matrix.msc1767.text": "How's the weather?" },
+        "answers": [
+            { "id": "not-bad", "org.matrix.msc1767.text": "Not bad…" },
+            { "id": "fine", "org.matrix.msc1767.text":  "Fine." },
+        ],
+    });
+    assert_eq!(
+        to_json_value(&event_content).unwrap(),
+        json!({
+            "org.matrix.msc3381.poll.start": poll_start_json,
+            "m.new_content": {
+                "org.matrix.msc3381.poll.start": poll_start_json,
+            },
+            "m.relates_to": {
+                "rel_type": "m.replace",
+                "event_id": replaces,
+            },
+        })
+    );
+}
+
+#[test]
+fn replacement_unstable_start_content_roundtrip() {
+    let poll_start = UnstablePollStartContentBlock::new(
+        "How's the weather?",
+        vec![
+            UnstablePollAnswer::new("not-bad", "Not bad…"),
+            UnstablePollAnswer::new("fine", "Fine."),
+        ]
+        .try_into()
+        .unwrap(),
+    );
+
+    // Without fallback.
+    let event_content = ReplacementUnstablePollStartEventContent::plain_text(
+        "How's the weather?",
+        poll_start.clone(),
+        owned_event_id!("$replacedevent"),
+    );
+    let json = to_json_value(&event_content).unwrap();
+    assert_matches!(
+        from_json_value::<UnstablePollStartEventContent>(json).unwrap(),
+        UnstablePollStartEventContent::Replacement(content)
+    );
+    assert_matches!(content.poll_start, None);
+    assert_matches!(content.text, None);
+    assert_eq!(content.relates_to.event_id, "$replacedevent");
+    assert_eq!(content.relates_to.new_content.text.as_deref(), Some("How's the weather?"));
+    assert_eq!(content.relates_to.new_content.poll_start.answers.len(), 2);
+
+    // With fallback.
+    let mut event_content = event_content.with_outer_fallback(poll_start);
+    event_content.text = Some("How's the weather? (edited)".to_owned());
+    let json = to_json_value(&event_content).unwrap();
+    assert_matches!(
+        from_json_value::<UnstablePollStartEventContent>(json).unwrap(),
+        UnstablePollStartEventContent::Replacement(content)
+    );
+    assert_matches!(content.poll_start, Some(outer_poll_start));
+    assert_eq!(outer_poll_start.question.text, "How's the weather?");
+    assert_eq!(content.text.as_deref(), Some("How's the weather? (edited)"));
+    assert_eq!(content.relates_to.event_id, "$replacedevent");
+    assert_eq!(content.relates_to.new_content.text.as_deref(), Some("How's the weather?"));
+}
+
 #[test]
 fn unstable_start_event_content_deserialization_missing_poll_start() {
     let json_data = json!({
@@ -761,8 +848,11 @@ fn compute_results() {
     responses.extend(generate_poll_responses(10..15, &["italian"]));
     responses.extend(generate_poll_responses(15..20, &["wings"]));
 
-    let counted =
-        compile_poll_results(&poll.content.poll, responses.iter().map(|r| r.data()), None);
+    let counted = compile_poll_results(
+        &poll.content.poll,
+        responses.iter().map(|r| r.data()),
+        PollEndTime::Never,
+    );
     assert_eq!(counted.get("pizza").unwrap().len(), 5);
     assert_eq!(counted.get("poutine").unwrap().len(), 5);
     assert_eq!(counted.get("italian").unwrap().len(), 5);
@@ -804,8 +894,11 @@ fn compute_results() {
         ),
     ]);
 
-    let counted =
-        compile_poll_results(&poll.content.poll, responses.iter().map(|r| r.data()), None);
+    let counted = compile_poll_results(
+        &poll.content.poll,
+        responses.iter().map(|r| r.data()),
+        PollEndTime::Never,
+    );
     assert_eq!(counted.get("pizza").unwrap().len(), 5);
     assert_eq!(counted.get("poutine").unwrap().len(), 7);
     assert_eq!(counted.get("italian").unwrap().len(), 6);
@@ -844,8 +937,11 @@ fn compute_results() {
         ),
     ]);
 
-    let counted =
-        compile_poll_results(&poll.content.poll, responses.iter().map(|r| r.data()), None);
+    let counted = compile_poll_results(
+        &poll.content.poll,
+        responses.iter().map(|r| r.data()),
+        PollEndTime::Never,
+    );
     assert_eq!(counted.get("pizza").unwrap().len(), 6);
     assert_eq!(counted.get("poutine").unwrap().len(), 8);
     assert_eq!(counted.get("italian").unwrap().len(), 6);
@@ -868,8 +964,11 @@ fn compute_results() {
         new_poll_response("$valid_for_now_event_3", changing_user_3, uint!(4200), &["wings"]),
     ]);
 
-    let counted =
-        compile_poll_results(&poll.content.poll, responses.iter().map(|r| r.data()), None);
+    let counted = compile_poll_results(
+        &poll.content.poll,
+        responses.iter().map(|r| r.data()),
+        PollEndTime::Never,
+    );
     assert_eq!(counted.get("pizza").unwrap().len(), 6);
     assert_eq!(counted.get("poutine").unwrap().len(), 8);
     assert_eq!(counted.get("italian").unwrap().len(), 6);
@@ -901,8 +1000,11 @@ fn compute_results() {
         &["italian"],
     ));
 
-    let counted =
-        compile_poll_results(&poll.content.poll, responses.iter().map(|r| r.data()), None);
+    let counted = compile_poll_results(
+        &poll.content.poll,
+        responses.iter().map(|r| r.data()),
+        PollEndTime::Never,
+    );
     assert_eq!(counted.get("pizza").unwrap().len(), 6);
     assert_eq!(counted.get("poutine").unwrap().len(), 8);
     assert_eq!(counted.get("italian").unwrap().len(), 7);
@@ -923,8 +1025,11 @@ fn compute_results() {
         &[],
     ));
 
-    let counted =
-        compile_poll_results(&poll.content.poll, responses.iter().map(|r| r.data()), None);
+    let counted = compile_poll_results(
+        &poll.content.poll,
+        responses.iter().map(|r| r.data()),
+        PollEndTime::Never,
+    );
     assert_eq!(counted.get("pizza").unwrap().len(), 6);
     assert_eq!(counted.get("poutine").unwrap().len(), 8);
     assert_eq!(counted.get("italian").unwrap().len(), 6);
@@ -945,8 +1050,11 @@ fn compute_results() {
         &["indian"],
     ));
 
-    let counted =
-        compile_poll_results(&poll.content.poll, responses.iter().map(|r| r.data()), None);
+    let counted = compile_poll_results(
+        &poll.content.poll,
+        responses.iter().map(|r| r.data()),
+        PollEndTime::Never,
+    );
     assert_eq!(counted.get("pizza").unwrap().len(), 6);
     assert_eq!(counted.get("poutine").unwrap().len(), 8);
     assert_eq!(counted.get("italian").unwrap().len(), 6);
@@ -962,8 +1070,11 @@ fn compute_results() {
     // Response older than most recent one is ignored.
     responses.push(new_poll_response("$past_event", changing_user_3, uint!(1), &["pizza"]));
 
-    let counted =
-        compile_poll_results(&poll.content.poll, responses.iter().map(|r| r.data()), None);
+    let counted = compile_poll_results(
+        &poll.content.poll,
+        responses.iter().map(|r| r.data()),
+        PollEndTime::Never,
+    );
     assert_eq!(counted.get("pizza").unwrap().len(), 6);
     assert_eq!(counted.get("poutine").unwrap().len(), 8);
     assert_eq!(counted.get("italian").unwrap().len(), 6);
@@ -981,8 +1092,11 @@ fn compute_results() {
     let future_ts = now.0 + uint!(100_000);
     responses.push(new_poll_response("$future_event", changing_user_3, future_ts, &["pizza"]));
 
-    let counted =
-        compile_poll_results(&poll.content.poll, responses.iter().map(|r| r.data()), Some(now));
+    let counted = compile_poll_results(
+        &poll.content.poll,
+        responses.iter().map(|r| r.data()),
+        PollEndTime::At(now),
+    );
     assert_eq!(counted.get("pizza").unwrap().len(), 6);
     assert_eq!(counted.get("poutine").unwrap().len(), 8);
     assert_eq!(counted.get("italian").unwrap().len(), 6);
@@ -996,8 +1110,11 @@ fn compute_results() {
     assert_eq!(*results.get("wings").unwrap(), uint!(7));
 
     // Response in the future is not ignored if there is no end_timestamp.
-    let counted =
-        compile_poll_results(&poll.content.poll, responses.iter().map(|r| r.data()), None);
+    let counted = compile_poll_results(
+        &poll.content.poll,
+        responses.iter().map(|r| r.data()),
+        PollEndTime::Never,
+    );
     assert_eq!(counted.get("pizza").unwrap().len(), 7);
     assert_eq!(counted.get("poutine").unwrap().len(), 8);
     assert_eq!(counted.get("italian").unwrap().len(), 6);
@@ -1079,7 +1196,7 @@ fn compute_unstable_results() {
     let counted = compile_unstable_poll_results(
         poll.content.poll_start(),
         responses.iter().map(|r| r.data()),
-        None,
+        PollEndTime::Never,
     );
     assert_eq!(counted.get("pizza").unwrap().len(), 5);
     assert_eq!(counted.get("poutine").unwrap().len(), 1);
@@ -1095,3 +1212,455 @@ fn compute_unstable_results() {
     let poll_end = poll.compile_results(responses.iter().map(|r| r.data()));
     assert_eq!(poll_end.text, "The poll has closed. Top answer: Pizza 🍕");
 }
+
+#[test]
+fn compute_results_end_time() {
+    let poll: OriginalSyncPollStartEvent = from_json_value(json!({
+      "type": "m.poll.start",
+      "sender": "@alice:localhost",
+      "event_id": "$poll_start_event_id",
+      "origin_server_ts": 1,
+      "content": {
+        "m.text": [{ "body": "Pizza or poutine?" }],
+        "m.poll": {
+          "question": { "m.text": [{ "body": "Pizza or poutine?" }] },
+          "answers": [
+            { "m.id": "pizza", "m.text":  [{ "body": "Pizza 🍕" }] },
+            { "m.id": "poutine", "m.text":  [{ "body": "Poutine 🍟" }] },
+          ]
+        },
+      }
+    }))
+    .unwrap();
+
+    // Responses sent at 1000 to 1004 and 1005 to 1009.
+    let mut responses = generate_poll_responses(0..5, &["pizza"]);
+    responses.extend(generate_poll_responses(5..10, &["poutine"]));
+
+    let counted = compile_poll_results(
+        &poll.content.poll,
+        responses.iter().map(|r| r.data()),
+        PollEndTime::At(MilliSecondsSinceUnixEpoch(uint!(1004))),
+    );
+    assert_eq!(counted.get("pizza").unwrap().len(), 5);
+    assert_eq!(counted.get("poutine").unwrap().len(), 0);
+
+    let counted = compile_poll_results(
+        &poll.content.poll,
+        responses.iter().map(|r| r.data()),
+        PollEndTime::At(MilliSecondsSinceUnixEpoch(uint!(999))),
+    );
+    assert_eq!(counted.get("pizza").unwrap().len(), 0);
+    assert_eq!(counted.get("poutine").unwrap().len(), 0);
+
+    // A response from the far future is only counted without an end time.
+    responses.push(new_poll_response(
+        "$future_event",
+        "@future_user:localhost",
+        UInt::MAX,
+        &["poutine"],
+    ));
+
+    let counted = compile_poll_results(
+        &poll.content.poll,
+        responses.iter().map(|r| r.data()),
+        PollEndTime::Now,
+    );
+    assert_eq!(counted.get("pizza").unwrap().len(), 5);
+    assert_eq!(counted.get("poutine").unwrap().len(), 5);
+
+    let counted = compile_poll_results(
+        &poll.content.poll,
+        responses.iter().map(|r| r.data()),
+        PollEndTime::Never,
+    );
+    assert_eq!(counted.get("pizza").unwrap().len(), 5);
+    assert_eq!(counted.get("poutine").unwrap().len(), 6);
+}
+
+#[test]
+fn validate_poll_response() {
+    let poll: OriginalSyncPollStartEvent = from_json_value(json!({
+      "type": "m.poll.start",
+      "sender": "@alice:localhost",
+      "event_id": "$poll_start_event_id",
+      "origin_server_ts": 1000,
+      "content": {
+        "m.text": [{ "body": "Pizza or poutine?" }],
+        "m.poll": {
+          "question": { "m.text": [{ "body": "Pizza or poutine?" }] },
+          "answers": [
+            { "m.id": "pizza", "m.text":  [{ "body": "Pizza 🍕" }] },
+            { "m.id": "poutine", "m.text":  [{ "body": "Poutine 🍟" }] },
+          ]
+        },
+      }
+    }))
+    .unwrap();
+    let start_ts = poll.origin_server_ts;
+    let end_time = PollEndTime::At(MilliSecondsSinceUnixEpoch(uint!(2000)));
+    let sender = user_id!("@bob:localhost");
+
+    let pizza = ["pizza".to_owned()];
+    let both = ["pizza".to_owned(), "poutine".to_owned()];
+    let unknown = ["pizza".to_owned(), "sushi".to_owned()];
+    let response = |ts: u32, selections| PollResponseData {
+        sender,
+        origin_server_ts: MilliSecondsSinceUnixEpoch(ts.into()),
+        selections,
+    };
+
+    response(1500, &pizza).is_valid_for_poll(&poll.content.poll, start_ts, end_time).unwrap();
+    response(1000, &pizza).is_valid_for_poll(&poll.content.poll, start_ts, end_time).unwrap();
+    response(2000, &pizza).is_valid_for_poll(&poll.content.poll, start_ts, end_time).unwrap();
+
+    assert_eq!(
+        response(999, &pizza).is_valid_for_poll(&poll.content.poll, start_ts, end_time),
+        Err(PollResponseValidationError::TooEarly)
+    );
+    assert_eq!(
+        response(2001, &pizza).is_valid_for_poll(&poll.content.poll, start_ts, end_time),
+        Err(PollResponseValidationError::TooLate)
+    );
+    response(2001, &pizza)
+        .is_valid_for_poll(&poll.content.poll, start_ts, PollEndTime::Never)
+        .unwrap();
+    assert_eq!(
+        response(1500, &[]).is_valid_for_poll(&poll.content.poll, start_ts, end_time),
+        Err(PollResponseValidationError::NoValidSelections)
+    );
+    assert_eq!(
+        response(1500, &unknown).is_valid_for_poll(&poll.content.poll, start_ts, end_time),
+        Err(PollResponseValidationError::NoValidSelections)
+    );
+    assert_eq!(
+        response(1500, &both).is_valid_for_poll(&poll.content.poll, start_ts, end_time),
+        Err(PollResponseValidationError::TooManySelections)
+    );
+}
+
+#[test]
+fn validate_unstable_poll_response() {
+    let poll: OriginalSyncUnstablePollStartEvent = from_json_value(json!({
+        "type": "org.matrix.msc3381.poll.start",
+        "sender": "@alice:localhost",
+        "event_id": "$poll_start_event_id",
+        "origin_server_ts": 1000,
+        "content": {
+            "org.matrix.msc3381.poll.start": {
+                "question": { "org.matrix.msc1767.text": "Pizza or poutine?" },
+                "max_selections": 2,
+                "answers": [
+                    { "id": "pizza", "org.matrix.msc1767.text": "Pizza 🍕" },
+                    { "id": "poutine", "org.matrix.msc1767.text": "Poutine 🍟" },
+                ]
+            },
+        }
+    }))
+    .unwrap();
+    let poll_start = poll.content.poll_start();
+    let start_ts = poll.origin_server_ts;
+    let sender = user_id!("@bob:localhost");
+
+    let selections = vec!["pizza".to_owned(), "poutine".to_owned()];
+    let data = PollResponseData {
+        sender,
+        origin_server_ts: MilliSecondsSinceUnixEpoch(uint!(1500)),
+        selections: &selections,
+    };
+    data.is_valid_for_unstable_poll(poll_start, start_ts, PollEndTime::Never).unwrap();
+    assert_eq!(
+        data.is_valid_for_unstable_poll(
+            poll_start,
+            start_ts,
+            PollEndTime::At(MilliSecondsSinceUnixEpoch(uint!(1200)))
+        ),
+        Err(PollResponseValidationError::TooLate)
+    );
+
+    let selections = vec!["pizza".to_owned(), "poutine".to_owned(), "pizza".to_owned()];
+    let data = PollResponseData {
+        sender,
+        origin_server_ts: MilliSecondsSinceUnixEpoch(uint!(1500)),
+        selections: &selections,
+    };
+    assert_eq!(
+        data.is_valid_for_unstable_poll(poll_start, start_ts, PollEndTime::Never),
+        Err(PollResponseValidationError::TooManySelections)
+    );
+}
+
+struct CountFallbackFormatter;
+
+impl PollFallbackFormatter for CountFallbackFormatter {
+    fn format_results<'a>(
+        &self,
+        answers: &[(&'a str, &'a str)],
+        results: impl Iterator<Item = (&'a str, usize)>,
+    ) -> String {
+        let results = results
+            .map(|(id, count)| {
+                let text = answers.iter().find(|(a_id, _)| *a_id == id).unwrap().1;
+                format!("{text}: {count}")
+            })
+            .collect::<Vec<_>>();
+        format!("Résultats : {}", results.join(", "))
+    }
+}
+
+#[test]
+fn compute_results_with_formatter() {
+    let poll: OriginalSyncPollStartEvent = from_json_value(json!({
+        "type": "m.poll.start",
+        "sender": "@alice:localhost",
+        "event_id": "$poll_start_event_id",
+        "origin_server_ts": 1,
+        "content": {
+          "m.text": [{ "body": "Pizza or poutine?\n1. Pizza\n2. Poutine" }],
+          "m.poll": {
+            "question": { "m.text": [{ "body": "Pizza or poutine?" }] },
+            "answers": [
+              { "m.id": "pizza", "m.text": [{ "body": "Pizza" }] },
+              { "m.id": "poutine", "m.text": [{ "body": "Poutine" }] },
+            ]
+          },
+        }
+    }))
+    .unwrap();
+
+    let mut responses = generate_poll_responses(0..3, &["pizza"]);
+    responses.extend(generate_poll_responses(3..4, &["poutine"]));
+
+    let poll_end = poll.compile_results_with_formatter(
+        responses.iter().map(|r| r.data()),
+        &CountFallbackFormatter,
+    );
+    let results = poll_end.poll_results.unwrap();
+    assert_eq!(*results.get("pizza").unwrap(), uint!(3));
+    assert_eq!(*results.get("poutine").unwrap(), uint!(1));
+    assert_eq!(poll_end.text.find_plain(), Some("Résultats : Pizza: 3, Poutine: 1"));
+
+    let poll: OriginalSyncUnstablePollStartEvent = from_json_value(json!({
+        "type": "org.matrix.msc3381.poll.start",
+        "sender": "@alice:localhost",
+        "event_id": "$poll_start_event_id",
+        "origin_server_ts": 1,
+        "content": {
+          "org.matrix.msc3381.poll.start": {
+            "question": { "org.matrix.msc1767.text": "Pizza or poutine?" },
+            "answers": [
+              { "id": "pizza", "org.matrix.msc1767.text": "Pizza" },
+              { "id": "poutine", "org.matrix.msc1767.text": "Poutine" },
+            ]
+          },
+          "org.matrix.msc1767.text": "Pizza or poutine?\n1. Pizza\n2. Poutine",
+        }
+    }))
+    .unwrap();
+
+    let poll_end = poll.compile_results_with_formatter(
+        responses.iter().map(|r| r.data()),
+        &CountFallbackFormatter,
+    );
+    assert_eq!(poll_end.text, "Résultats : Pizza: 3, Poutine: 1");
+}
+
+#[test]
+fn start_content_new_or_replacement() {
+    let poll = PollContentBlock::new(
+        TextContentBlock::plain("How's the weather?"),
+        vec![
+            PollAnswer::new("not-bad".to_owned(), TextContentBlock::plain("Not bad…")),
+            PollAnswer::new("fine".to_owned(), TextContentBlock::plain("Fine.")),
+        ]
+        .try_into()
+        .unwrap(),
+    );
+    let mut event_content = PollStartEventContent::with_plain_text("How's the weather?", poll);
+
+    assert!(event_content.is_new());
+    assert!(!event_content.is_replacement());
+    assert!(event_content.as_replacement().is_none());
+
+    let new_content = PollStartEventContentWithoutRelation::from(event_content.clone());
+    event_content.relates_to = Some(Relation::Replacement(Replacement::new(
+        owned_event_id!("$replacedevent"),
+        new_content,
+    )));
+
+    assert!(!event_content.is_new());
+    assert!(event_content.is_replacement());
+    let replacement = event_content.as_replacement().unwrap();
+    assert_eq!(replacement.event_id, "$replacedevent");
+}
+
+#[test]
+fn unstable_start_content_new_or_replacement() {
+    let poll_start = UnstablePollStartContentBlock::new(
+        "How's the weather?",
+        vec![
+            UnstablePollAnswer::new("not-bad", "Not bad…"),
+            UnstablePollAnswer::new("fine", "Fine."),
+        ]
+        .try_into()
+        .unwrap(),
+    );
+
+    let event_content: UnstablePollStartEventContent =
+        NewUnstablePollStartEventContent::plain_text("How's the weather?", poll_start.clone())
+            .into();
+    assert!(event_content.is_new());
+    assert!(!event_content.is_replacement());
+    assert_eq!(event_content.as_new().unwrap().text.as_deref(), Some("How's the weather?"));
+    assert!(event_content.as_replacement().is_none());
+
+    let event_content: UnstablePollStartEventContent =
+        ReplacementUnstablePollStartEventContent::new(
+            poll_start,
+            owned_event_id!("$replacedevent"),
+        )
+        .into();
+    assert!(!event_content.is_new());
+    assert!(event_content.is_replacement());
+    assert!(event_content.as_new().is_none());
+    assert_eq!(event_content.as_replacement().unwrap().relates_to.event_id, "$replacedevent");
+}
+
+#[test]
+fn start_content_to_unstable() {
+    let poll = assign!(
+        PollContentBlock::new(
+            TextContentBlock::html("How's the weather?", "How's the <em>weather</em>?"),
+            vec![
+                PollAnswer::new("not-bad".to_owned(), TextContentBlock::plain("Not bad…")),
+                PollAnswer::new("fine".to_owned(), TextContentBlock::plain("Fine.")),
+            ]
+            .try_into()
+            .unwrap(),
+        ),
+        { kind: PollKind::Disclosed, max_selections: uint!(2) }
+    );
+    let content = PollStartEventContent::with_plain_text("How's the weather?", poll);
+
+    assert_matches!(content.to_unstable(), UnstablePollStartEventContent::New(unstable));
+    assert_eq!(unstable.text.as_deref(), Some("How's the weather?"));
+    assert_matches!(unstable.relates_to, None);
+    let poll_start = unstable.poll_start;
+    assert_eq!(poll_start.question.text, "How's the weather?");
+    assert_eq!(poll_start.kind, PollKind::Disclosed);
+    assert_eq!(poll_start.max_selections, uint!(2));
+    assert_eq!(poll_start.answers.len(), 2);
+    assert_eq!(poll_start.answers[0].id, "not-bad");
+    assert_eq!(poll_start.answers[0].text, "Not bad…");
+    assert_eq!(poll_start.answers[1].id, "fine");
+    assert_eq!(poll_start.answers[1].text, "Fine.");
+
+    let mut replacement = PollStartEventContent::with_plain_text(
+        "* How's the weather?",
+        PollContentBlock::new(
+            TextContentBlock::plain("How's the weather?"),
+            vec![PollAnswer::new("fine".to_owned(), TextContentBlock::plain("Fine."))]
+                .try_into()
+                .unwrap(),
+        ),
+    );
+    replacement.relates_to = Some(Relation::Replacement(Replacement::new(
+        owned_event_id!("$replacedevent"),
+        content.into(),
+    )));
+
+    assert_matches!(
+        replacement.to_unstable(),
+        UnstablePollStartEventContent::Replacement(unstable)
+    );
+    assert_eq!(unstable.text.as_deref(), Some("* How's the weather?"));
+    assert_eq!(unstable.poll_start.unwrap().answers.len(), 1);
+    assert_eq!(unstable.relates_to.event_id, "$replacedevent");
+    let new_content = unstable.relates_to.new_content;
+    assert_eq!(new_content.text.as_deref(), Some("How's the weather?"));
+    assert_eq!(new_content.poll_start.answers.len(), 2);
+}
+
+#[test]
+fn unstable_start_content_to_stable() {
+    let poll_start = assign!(
+        UnstablePollStartContentBlock::new(
+            "How's the weather?",
+            vec![
+                UnstablePollAnswer::new("not-bad", "Not bad…"),
+                UnstablePollAnswer::new("fine", "Fine."),
+            ]
+            .try_into()
+            .unwrap(),
+        ),
+        { kind: PollKind::Disclosed, max_selections: uint!(2) }
+    );
+
+    let mut unstable =
+        NewUnstablePollStartEventContent::plain_text("How's the weather?", poll_start.clone());
+    unstable.relates_to = Some(RelationWithoutReplacement::Reply {
+        in_reply_to: InReplyTo::new(owned_event_id!("$replied")),
+    });
+    let content = UnstablePollStartEventContent::from(unstable).to_stable().unwrap();
+
+    assert_eq!(content.text.find_plain(), Some("How's the weather?"));
+    assert_matches!(content.relates_to, Some(Relation::Reply { in_reply_to }));
+    assert_eq!(in_reply_to.event_id, "$replied");
+    let poll = content.poll;
+    assert_eq!(poll.question.text.find_plain(), Some("How's the weather?"));
+    assert_eq!(poll.kind, PollKind::Disclosed);
+    assert_eq!(poll.max_selections, uint!(2));
+    assert_eq!(poll.answers.len(), 2);
+    assert_eq!(poll.answers[0].id, "not-bad");
+    assert_eq!(poll.answers[0].text.find_plain(), Some("Not bad…"));
+    assert_eq!(poll.answers[1].id, "fine");
+    assert_eq!(poll.answers[1].text.find_plain(), Some("Fine."));
+
+    // Replacement without outer fallback uses the new content.
+    let unstable: UnstablePollStartEventContent =
+        ReplacementUnstablePollStartEventContent::plain_text(
+            "How's the weather?",
+            poll_start.clone(),
+            owned_event_id!("$replacedevent"),
+        )
+        .into();
+    let content = unstable.to_stable().unwrap();
+
+    assert_eq!(content.text.find_plain(), Some("How's the weather?"));
+    assert_eq!(content.poll.answers.len(), 2);
+    let replacement = content.as_replacement().unwrap();
+    assert_eq!(replacement.event_id, "$replacedevent");
+    assert_eq!(replacement.new_content.text.find_plain(), Some("How's the weather?"));
+    assert_eq!(replacement.new_content.poll.answers.len(), 2);
+
+    // Missing text fallback.
+    let unstable: UnstablePollStartEventContent =
+        NewUnstablePollStartEventContent::new(poll_start.clone()).into();
+    assert_eq!(unstable.to_stable().unwrap_err(), PollConversionError::MissingTextFallback);
+
+    let unstable: UnstablePollStartEventContent = ReplacementUnstablePollStartEventContent::new(
+        poll_start,
+        owned_event_id!("$replacedevent"),
+    )
+    .into();
+    assert_eq!(unstable.to_stable().unwrap_err(), PollConversionError::MissingTextFallback);
+}
+
+#[test]
+fn start_content_round_trip_through_unstable() {
+    let content = PollStartEventContent::with_plain_text(
+        "How's the weather?",
+        PollContentBlock::new(
+            TextContentBlock::plain("How's the weather?"),
+            vec![
+                PollAnswer::new("not-bad".to_owned(), TextContentBlock::plain("Not bad…")),
+                PollAnswer::new("fine".to_owned(), TextContentBlock::plain("Fine.")),
+            ]
+            .try_into()
+            .unwrap(),
+        ),
+    );
+
+    let round_trip = content.to_unstable().to_stable().unwrap();
+    assert_eq!(to_json_value(&round_trip).unwrap(), to_json_value(&content).unwrap());
+}
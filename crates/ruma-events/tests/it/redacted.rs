@@ -1,5 +1,6 @@
 use assert_matches2::assert_matches;
-use ruma_common::RoomVersionId;
+use js_int::uint;
+use ruma_common::{MilliSecondsSinceUnixEpoch, RoomVersionId};
 use ruma_events::{
     room::{
         aliases::RedactedRoomAliasesEventContent,
@@ -7,9 +8,9 @@ use ruma_events::{
         message::{RedactedRoomMessageEventContent, RoomMessageEventContent},
         redaction::RoomRedactionEventContent,
     },
-    AnyMessageLikeEvent, AnySyncMessageLikeEvent, AnySyncStateEvent, AnySyncTimelineEvent,
-    AnyTimelineEvent, EventContentFromType, MessageLikeEvent, RedactContent, SyncMessageLikeEvent,
-    SyncStateEvent,
+    AnyMessageLikeEvent, AnyStateEvent, AnySyncMessageLikeEvent, AnySyncStateEvent,
+    AnySyncTimelineEvent, AnyTimelineEvent, EventContentFromType, MessageLikeEvent, RedactContent,
+    StateEvent, SyncMessageLikeEvent, SyncStateEvent, UnsignedRoomRedactionEvent,
 };
 use serde_json::{
     from_value as from_json_value, json, to_value as to_json_value,
@@ -154,8 +155,75 @@ fn deserialize_redacted_custom_event() {
     assert_eq!(state_ev.event_id(), "$h29iv0s8:example.com");
 }
 
-/* #[test]
-fn redact_method_properly_redacts() {
+#[test]
+fn redacted_timeline_event_accessors() {
+    let redacted = json!({
+        "content": {},
+        "event_id": "$h29iv0s8:example.com",
+        "room_id": "!roomid:room.com",
+        "origin_server_ts": 1,
+        "sender": "@carl:example.com",
+        "unsigned": unsigned(),
+        "type": "m.room.message",
+    });
+
+    let ev = from_json_value::<AnyTimelineEvent>(redacted).unwrap();
+    assert!(ev.is_redacted());
+    assert_eq!(ev.redaction_reason(), Some("redacted because"));
+    assert_eq!(ev.unsigned_redacted_by().unwrap(), "$h29iv0s8:example.com");
+
+    let redacted_state = json!({
+        "content": {},
+        "event_id": "$h29iv0s8:example.com",
+        "room_id": "!roomid:room.com",
+        "origin_server_ts": 1,
+        "sender": "@carl:example.com",
+        "state_key": "",
+        "unsigned": {
+            "redacted_because": {
+                "type": "m.room.redaction",
+                "content": {},
+                "redacts": "$h29iv0s8:example.com",
+                "event_id": "$redaction:example.com",
+                "origin_server_ts": 1,
+                "sender": "@carl:example.com",
+            },
+        },
+        "type": "m.room.name",
+    });
+
+    let ev = from_json_value::<AnySyncTimelineEvent>(redacted_state).unwrap();
+    assert!(ev.is_redacted());
+    assert_eq!(ev.redaction_reason(), None);
+    assert_eq!(ev.unsigned_redacted_by().unwrap(), "$redaction:example.com");
+}
+
+#[test]
+fn original_timeline_event_accessors() {
+    let original = json!({
+        "content": {
+            "body": "test",
+            "msgtype": "m.text",
+        },
+        "event_id": "$h29iv0s8:example.com",
+        "room_id": "!roomid:room.com",
+        "origin_server_ts": 1,
+        "sender": "@carl:example.com",
+        "type": "m.room.message",
+    });
+
+    let ev = from_json_value::<AnyTimelineEvent>(original).unwrap();
+    assert!(!ev.is_redacted());
+    assert_eq!(ev.redaction_reason(), None);
+    assert_eq!(ev.unsigned_redacted_by(), None);
+}
+
+fn redacted_because() -> UnsignedRoomRedactionEvent {
+    from_json_value(unsigned()["redacted_because"].clone()).unwrap()
+}
+
+#[test]
+fn into_redacted_message_event() {
     let ev = json!({
         "type": "m.room.message",
         "event_id": "$143273582443PhrSn:example.com",
@@ -169,33 +237,125 @@ fn redact_method_properly_redacts() {
         },
     });
 
-    let redaction = OriginalSyncRoomRedactionEvent {
-        content: RoomRedactionEventContent::with_reason("redacted because".into()),
-        redacts: owned_event_id!("$143273582443PhrSn:example.com"),
-        event_id: owned_event_id!("$h29iv0s8:example.com"),
-        origin_server_ts: MilliSecondsSinceUnixEpoch(uint!(1)),
-        sender: owned_user_id!("@carl:example.com"),
-        unsigned: MessageLikeUnsigned::default(),
-    };
-
     let event: AnyMessageLikeEvent = from_json_value(ev).unwrap();
 
     assert_matches!(
-        event.redact(redaction, &RoomVersionId::V6),
-        AnyMessageLikeEvent::RoomMessage(MessageLikeEvent::Redacted(RedactedMessageLikeEvent {
-            content: RedactedRoomMessageEventContent { .. },
-            event_id,
-            room_id,
-            sender,
-            origin_server_ts,
-            unsigned,
-        })) if event_id == event_id!("$143273582443PhrSn:example.com")
-            && unsigned.redacted_because.is_some()
-            && room_id == room_id!("!roomid:room.com")
-            && sender == user_id!("@user:example.com")
-            && origin_server_ts == MilliSecondsSinceUnixEpoch(uint!(1))
+        event.into_redacted(redacted_because(), &RoomVersionId::V6),
+        AnyMessageLikeEvent::RoomMessage(MessageLikeEvent::Redacted(redacted))
+    );
+    assert_eq!(redacted.event_id, "$143273582443PhrSn:example.com");
+    assert_eq!(redacted.room_id, "!roomid:room.com");
+    assert_eq!(redacted.sender, "@user:example.com");
+    assert_eq!(redacted.origin_server_ts, MilliSecondsSinceUnixEpoch(uint!(1)));
+    assert_eq!(redacted.unsigned.redacted_because.event_id, "$h29iv0s8:example.com");
+    assert_eq!(to_json_value(redacted.content).unwrap(), json!({}));
+}
+
+#[test]
+fn into_redacted_room_message_with_reason() {
+    let ev = json!({
+        "type": "m.room.message",
+        "event_id": "$143273582443PhrSn:example.com",
+        "origin_server_ts": 1,
+        "room_id": "!roomid:room.com",
+        "sender": "@user:example.com",
+        "content": {
+            "body": "Hello *world*",
+            "format": "org.matrix.custom.html",
+            "formatted_body": "Hello <em>world</em>",
+            "msgtype": "m.text",
+        },
+    });
+
+    let event: SyncMessageLikeEvent<RoomMessageEventContent> = from_json_value(ev).unwrap();
+    let event = event.into_redacted(redacted_because(), &RoomVersionId::V11);
+    assert_eq!(
+        event.redacted_because().unwrap().content.reason.as_deref(),
+        Some("redacted because")
     );
-} */
+    assert_matches!(event, SyncMessageLikeEvent::Redacted(redacted));
+    assert_eq!(redacted.event_id, "$143273582443PhrSn:example.com");
+    assert_eq!(
+        redacted.unsigned.redacted_because.content.reason.as_deref(),
+        Some("redacted because")
+    );
+    assert_eq!(to_json_value(redacted.content).unwrap(), json!({}));
+}
+
+#[test]
+fn into_redacted_state_event() {
+    let ev = json!({
+        "type": "m.room.join_rules",
+        "event_id": "$143273582443PhrSn:example.com",
+        "origin_server_ts": 1,
+        "room_id": "!roomid:room.com",
+        "sender": "@user:example.com",
+        "state_key": "",
+        "content": {
+            "join_rule": "public",
+            "extra": "field",
+        },
+    });
+
+    let event: AnyStateEvent = from_json_value(ev).unwrap();
+    let event = event.into_redacted(redacted_because(), &RoomVersionId::V6);
+
+    assert!(event.redacted_because().is_some());
+    assert_matches!(event, AnyStateEvent::RoomJoinRules(StateEvent::Redacted(redacted)));
+    assert_eq!(redacted.event_id, "$143273582443PhrSn:example.com");
+    assert_eq!(redacted.room_id, "!roomid:room.com");
+    assert_eq!(redacted.state_key.as_ref(), "");
+    assert_eq!(to_json_value(redacted.content).unwrap(), json!({ "join_rule": "public" }));
+}
+
+#[test]
+fn into_redacted_custom_event() {
+    let ev = json!({
+        "type": "org.example.custom",
+        "event_id": "$143273582443PhrSn:example.com",
+        "origin_server_ts": 1,
+        "room_id": "!roomid:room.com",
+        "sender": "@user:example.com",
+        "content": {
+            "foo": "bar",
+        },
+    });
+
+    let event: AnyMessageLikeEvent = from_json_value(ev).unwrap();
+    let event = event.into_redacted(redacted_because(), &RoomVersionId::V6);
+
+    assert_eq!(event.event_type().to_string(), "org.example.custom");
+    assert_eq!(event.event_id(), "$143273582443PhrSn:example.com");
+    assert!(event.redacted_because().is_some());
+    assert!(event.original_content().is_none());
+}
+
+#[test]
+fn into_redacted_already_redacted() {
+    let redacted = json!({
+        "content": {},
+        "event_id": "$h29iv0s8:example.com",
+        "room_id": "!roomid:room.com",
+        "origin_server_ts": 1,
+        "sender": "@carl:example.com",
+        "unsigned": unsigned(),
+        "type": "m.room.message",
+    });
+
+    let event: AnyMessageLikeEvent = from_json_value(redacted).unwrap();
+    let other_redaction: UnsignedRoomRedactionEvent = from_json_value(json!({
+        "type": "m.room.redaction",
+        "content": {},
+        "redacts": "$h29iv0s8:example.com",
+        "event_id": "$other:example.com",
+        "origin_server_ts": 2,
+        "sender": "@carl:example.com",
+    }))
+    .unwrap();
+
+    let event = event.into_redacted(other_redaction, &RoomVersionId::V6);
+    assert_eq!(event.redacted_because().unwrap().event_id, "$h29iv0s8:example.com");
+}
 
 #[test]
 fn redact_message_content() {
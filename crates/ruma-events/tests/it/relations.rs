@@ -1,9 +1,12 @@
 use assert_matches2::assert_matches;
 use assign::assign;
-use ruma_common::owned_event_id;
+use ruma_common::{event_id, owned_event_id};
 use ruma_events::{
-    relation::{CustomRelation, InReplyTo, Replacement, Thread},
+    relation::{
+        latest_thread_reply, thread_reply_count, CustomRelation, InReplyTo, Replacement, Thread,
+    },
     room::message::{MessageType, Relation, RoomMessageEventContent},
+    AnyMessageLikeEvent,
 };
 use serde_json::{
     from_value as from_json_value, json, to_value as to_json_value, Value as JsonValue,
@@ -300,3 +303,63 @@ fn custom_serialize() {
         })
     );
 }
+
+fn message_event(event_id: &str, ts: u32, relates_to: Option<JsonValue>) -> AnyMessageLikeEvent {
+    let mut content = json!({
+        "msgtype": "m.text",
+        "body": "Message",
+    });
+    if let Some(relates_to) = relates_to {
+        content["m.relates_to"] = relates_to;
+    }
+
+    from_json_value(json!({
+        "type": "m.room.message",
+        "event_id": event_id,
+        "sender": "@alice:localhost",
+        "origin_server_ts": ts,
+        "room_id": "!room:localhost",
+        "content": content,
+    }))
+    .unwrap()
+}
+
+#[test]
+fn thread_replies_in_timeline() {
+    let thread = |latest: &str| {
+        json!({
+            "rel_type": "m.thread",
+            "event_id": "$root",
+            "is_falling_back": true,
+            "m.in_reply_to": { "event_id": latest },
+        })
+    };
+
+    let mut timeline = vec![
+        message_event("$root", 1, None),
+        // Not a thread relation.
+        message_event("$reply", 2, Some(json!({ "m.in_reply_to": { "event_id": "$root" } }))),
+        message_event(
+            "$other_thread",
+            3,
+            Some(json!({ "rel_type": "m.thread", "event_id": "$reply" })),
+        ),
+    ];
+
+    // No thread replies.
+    assert_eq!(thread_reply_count(event_id!("$root"), &timeline), 0);
+    assert!(latest_thread_reply(event_id!("$root"), &timeline).is_none());
+
+    // One thread reply.
+    timeline.push(message_event("$thread_1", 10, Some(thread("$root"))));
+    assert_eq!(thread_reply_count(event_id!("$root"), &timeline), 1);
+    assert_eq!(latest_thread_reply(event_id!("$root"), &timeline).unwrap().event_id(), "$thread_1");
+
+    // Multiple thread replies, not ordered by timestamp.
+    timeline.push(message_event("$thread_3", 30, Some(thread("$thread_1"))));
+    timeline.push(message_event("$thread_2", 20, Some(thread("$thread_3"))));
+    assert_eq!(thread_reply_count(event_id!("$root"), &timeline), 3);
+    assert_eq!(latest_thread_reply(event_id!("$root"), &timeline).unwrap().event_id(), "$thread_3");
+
+    assert_eq!(thread_reply_count(event_id!("$reply"), &timeline), 1);
+}
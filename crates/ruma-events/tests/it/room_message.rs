@@ -13,9 +13,9 @@ use ruma_events::{
         message::{
             AddMentions, AudioMessageEventContent, EmoteMessageEventContent,
             FileMessageEventContent, ForwardThread, ImageMessageEventContent,
-            KeyVerificationRequestEventContent, MessageType, OriginalRoomMessageEvent,
-            OriginalSyncRoomMessageEvent, Relation, ReplyWithinThread, RoomMessageEventContent,
-            TextMessageEventContent, VideoMessageEventContent,
+            KeyVerificationRequestEventContent, MessageType, NoticeMessageEventContent,
+            OriginalRoomMessageEvent, OriginalSyncRoomMessageEvent, Relation, ReplyWithinThread,
+            RoomMessageEventContent, TextMessageEventContent, VideoMessageEventContent,
         },
         EncryptedFileInit, JsonWebKeyInit, MediaSource,
     },
@@ -75,6 +75,165 @@ fn custom_msgtype_deserialization() {
     assert_eq!(custom_event.data(), Cow::Owned(expected_json_data));
 }
 
+#[test]
+fn msgtype_try_into_variant() {
+    let text = MessageType::text_plain("Hello");
+    let content = text.clone().try_into_text().unwrap();
+    assert_eq!(content.body, "Hello");
+
+    let text = text.try_into_image().unwrap_err();
+    let text = text.try_into_audio().unwrap_err();
+    let text = text.try_into_video().unwrap_err();
+    let text = text.try_into_file().unwrap_err();
+    let text = text.try_into_emote().unwrap_err();
+    let text = text.try_into_notice().unwrap_err();
+    assert_matches!(text, MessageType::Text(content));
+    assert_eq!(content.body, "Hello");
+
+    let image = MessageType::Image(ImageMessageEventContent::plain(
+        "image.png".to_owned(),
+        mxc_uri!("mxc://notareal.hs/file").to_owned(),
+    ));
+    let image = image.try_into_text().unwrap_err();
+    let content = image.try_into_image().unwrap();
+    assert_eq!(content.body, "image.png");
+
+    let content = MessageType::emote_plain("waves").try_into_emote().unwrap();
+    assert_eq!(content.body, "waves");
+    let content = MessageType::notice_plain("Beep").try_into_notice().unwrap();
+    assert_eq!(content.body, "Beep");
+}
+
+#[test]
+fn media_msgtype_effective_filename() {
+    let url = mxc_uri!("mxc://notareal.hs/file");
+
+    let file = FileMessageEventContent::plain("my_file.txt".to_owned(), url.to_owned());
+    assert_eq!(file.effective_filename(), "my_file.txt");
+    let mut file =
+        FileMessageEventContent::plain("Have a look at this file".to_owned(), url.to_owned());
+    file.set_filename("my_file.txt");
+    assert_eq!(file.effective_filename(), "my_file.txt");
+    assert_eq!(file.body, "Have a look at this file");
+
+    let mut image = ImageMessageEventContent::plain("image.png".to_owned(), url.to_owned());
+    assert_eq!(image.effective_filename(), "image.png");
+    image.set_filename("other_image.png");
+    assert_eq!(image.effective_filename(), "other_image.png");
+
+    let mut audio = AudioMessageEventContent::plain("audio.ogg".to_owned(), url.to_owned());
+    assert_eq!(audio.effective_filename(), "audio.ogg");
+    audio.set_filename("other_audio.ogg");
+    assert_eq!(audio.effective_filename(), "other_audio.ogg");
+
+    let mut video = VideoMessageEventContent::plain("video.mp4".to_owned(), url.to_owned());
+    assert_eq!(video.effective_filename(), "video.mp4");
+    video.set_filename("other_video.mp4");
+    assert_eq!(video.effective_filename(), "other_video.mp4");
+}
+
+#[test]
+fn file_msgtype_filename_serde() {
+    let url = mxc_uri!("mxc://notareal.hs/file");
+    let file = FileMessageEventContent::plain("my_file.txt".to_owned(), url.to_owned());
+
+    let json = to_json_value(RoomMessageEventContent::new(MessageType::File(file))).unwrap();
+    assert_eq!(
+        json,
+        json!({
+            "body": "my_file.txt",
+            "msgtype": "m.file",
+            "url": "mxc://notareal.hs/file",
+        })
+    );
+    let content = from_json_value::<RoomMessageEventContent>(json).unwrap();
+    assert_matches!(content.msgtype, MessageType::File(file_content));
+    assert_eq!(file_content.filename, None);
+    assert_eq!(file_content.effective_filename(), "my_file.txt");
+
+    let mut file =
+        FileMessageEventContent::plain("Have a look at this file".to_owned(), url.to_owned());
+    file.set_filename("my_file.txt");
+
+    let json = to_json_value(RoomMessageEventContent::new(MessageType::File(file))).unwrap();
+    assert_eq!(
+        json,
+        json!({
+            "body": "Have a look at this file",
+            "filename": "my_file.txt",
+            "msgtype": "m.file",
+            "url": "mxc://notareal.hs/file",
+        })
+    );
+    let content = from_json_value::<RoomMessageEventContent>(json).unwrap();
+    assert_matches!(content.msgtype, MessageType::File(file_content));
+    assert_eq!(file_content.filename.as_deref(), Some("my_file.txt"));
+    assert_eq!(file_content.effective_filename(), "my_file.txt");
+}
+
+#[test]
+fn emote_and_notice_constructors() {
+    let content = RoomMessageEventContent::emote_plain("waves");
+    assert_matches!(
+        content.msgtype,
+        MessageType::Emote(EmoteMessageEventContent { body, formatted, .. })
+    );
+    assert_eq!(body, "waves");
+    assert!(formatted.is_none());
+
+    let content = RoomMessageEventContent::emote_html("waves", "<em>waves</em>");
+    assert_matches!(
+        content.msgtype,
+        MessageType::Emote(EmoteMessageEventContent { body, formatted, .. })
+    );
+    assert_eq!(body, "waves");
+    assert_eq!(formatted.unwrap().body, "<em>waves</em>");
+
+    let content = RoomMessageEventContent::notice_plain("Beep");
+    assert_matches!(
+        content.msgtype,
+        MessageType::Notice(NoticeMessageEventContent { body, formatted, .. })
+    );
+    assert_eq!(body, "Beep");
+    assert!(formatted.is_none());
+
+    let content = RoomMessageEventContent::notice_html("Beep", "<strong>Beep</strong>");
+    assert_matches!(
+        content.msgtype,
+        MessageType::Notice(NoticeMessageEventContent { body, formatted, .. })
+    );
+    assert_eq!(body, "Beep");
+    assert_eq!(formatted.unwrap().body, "<strong>Beep</strong>");
+
+    let content = EmoteMessageEventContent::html("waves", "<em>waves</em>");
+    assert_eq!(content.body, "waves");
+    assert_eq!(content.formatted.unwrap().body, "<em>waves</em>");
+    assert!(EmoteMessageEventContent::plain("waves").formatted.is_none());
+}
+
+#[test]
+fn verification_request_msgtype_methods() {
+    let request = |methods| {
+        KeyVerificationRequestEventContent::new(
+            "@example:localhost is requesting to verify your key, ...".to_owned(),
+            methods,
+            "XOWLHHFSWM".into(),
+            owned_user_id!("@example2:localhost"),
+        )
+    };
+
+    let sas = request(vec![VerificationMethod::SasV1]);
+    let qr = request(vec![VerificationMethod::QrCodeScanV1]);
+    let both = request(vec![VerificationMethod::QrCodeScanV1, VerificationMethod::SasV1]);
+
+    assert!(sas.methods_include(&VerificationMethod::SasV1));
+    assert!(!sas.methods_include(&VerificationMethod::QrCodeScanV1));
+    assert!(!request(vec![]).methods_include(&VerificationMethod::SasV1));
+
+    assert_eq!(sas.common_methods(&both).collect::<Vec<_>>(), [&VerificationMethod::SasV1]);
+    assert_eq!(sas.common_methods(&qr).count(), 0);
+}
+
 #[test]
 fn text_msgtype_formatted_body_serialization() {
     let message_event_content =
@@ -350,6 +509,46 @@ fn escape_tags_in_plain_reply_body() {
     );
 }
 
+#[test]
+fn body_for_display() {
+    let reply: RoomMessageEventContent = from_json_value(json!({
+        "msgtype": "m.text",
+        "body": "> <@user:example.org> Replied to on\n> two lines\n\nThis is my reply",
+        "format": "org.matrix.custom.html",
+        "formatted_body": "<mx-reply><blockquote>\
+            <a href=\"https://matrix.to/#/!testroomid:example.org/$event:example.org\">In reply to</a> \
+            <a href=\"https://matrix.to/#/@user:example.org\">@user:example.org</a>\
+            <br>Replied to on<br>two lines</blockquote></mx-reply>This is <em>my</em> reply",
+        "m.relates_to": {
+            "m.in_reply_to": {
+                "event_id": "$event:example.org",
+            },
+        },
+    }))
+    .unwrap();
+
+    assert_eq!(reply.body_for_display(), "This is my reply");
+    assert_eq!(reply.formatted_body_for_display(), Some("This is <em>my</em> reply"));
+
+    let quote: RoomMessageEventContent = from_json_value(json!({
+        "msgtype": "m.text",
+        "body": "> Not a reply\n\nJust a quote",
+        "format": "org.matrix.custom.html",
+        "formatted_body": "<blockquote>Not a reply</blockquote>Just a quote",
+    }))
+    .unwrap();
+
+    assert_eq!(quote.body_for_display(), "> Not a reply\n\nJust a quote");
+    assert_eq!(
+        quote.formatted_body_for_display(),
+        Some("<blockquote>Not a reply</blockquote>Just a quote")
+    );
+
+    let plain = RoomMessageEventContent::text_plain("Plain message");
+    assert_eq!(plain.body_for_display(), "Plain message");
+    assert_eq!(plain.formatted_body_for_display(), None);
+}
+
 #[test]
 #[cfg(feature = "html")]
 fn reply_sanitize() {
@@ -1287,3 +1486,60 @@ fn invalid_replacement() {
     assert_matches!(&data, Cow::Borrowed(_)); // data is stored in JSON form because it's invalid
     assert_eq!(JsonValue::Object(data.into_owned()), relation);
 }
+
+#[test]
+fn reply_to_event_id() {
+    fn message_event(relates_to: Option<JsonValue>) -> OriginalSyncRoomMessageEvent {
+        let mut content = json!({
+            "body": "Hello",
+            "msgtype": "m.text",
+        });
+        if let Some(relates_to) = relates_to {
+            content["m.relates_to"] = relates_to;
+        }
+
+        from_json_value(json!({
+            "content": content,
+            "event_id": "$143273582443PhrSn:example.org",
+            "origin_server_ts": 134_829_848,
+            "sender": "@user:example.org",
+            "type": "m.room.message",
+        }))
+        .unwrap()
+    }
+
+    // Not in a thread, not a reply.
+    let ev = message_event(None);
+    assert_eq!(ev.reply_to_event_id(), None);
+    assert_eq!(ev.in_reply_to_event_id(), None);
+
+    // Not in a thread, genuine reply.
+    let ev = message_event(Some(json!({
+        "m.in_reply_to": { "event_id": "$replied_to" },
+    })));
+    assert_eq!(ev.reply_to_event_id().unwrap(), "$replied_to");
+    assert_eq!(ev.in_reply_to_event_id().unwrap(), "$replied_to");
+
+    // In a thread, reply fallback.
+    let ev = message_event(Some(json!({
+        "rel_type": "m.thread",
+        "event_id": "$thread_root",
+        "m.in_reply_to": { "event_id": "$latest_event" },
+        "is_falling_back": true,
+    })));
+    assert_eq!(ev.reply_to_event_id(), None);
+    assert_eq!(ev.in_reply_to_event_id().unwrap(), "$latest_event");
+
+    // In a thread, genuine reply.
+    let ev = message_event(Some(json!({
+        "rel_type": "m.thread",
+        "event_id": "$thread_root",
+        "m.in_reply_to": { "event_id": "$replied_to" },
+    })));
+    assert_eq!(ev.reply_to_event_id().unwrap(), "$replied_to");
+    assert_eq!(ev.in_reply_to_event_id().unwrap(), "$replied_to");
+
+    let ev = ev.into_full_event(owned_room_id!("!room:example.org"));
+    assert_eq!(ev.reply_to_event_id().unwrap(), "$replied_to");
+    assert_eq!(ev.in_reply_to_event_id().unwrap(), "$replied_to");
+}
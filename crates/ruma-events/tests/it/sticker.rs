@@ -1,10 +1,10 @@
 use assert_matches2::assert_matches;
 use assign::assign;
 use js_int::{uint, UInt};
-use ruma_common::{mxc_uri, serde::CanBeEmpty, MilliSecondsSinceUnixEpoch};
+use ruma_common::{mxc_uri, serde::CanBeEmpty, MilliSecondsSinceUnixEpoch, OwnedMxcUri};
 use ruma_events::{
     room::{ImageInfo, MediaSource, ThumbnailInfo},
-    sticker::{StickerEventContent, StickerMediaSource},
+    sticker::{StickerEventContent, StickerMediaSource, StickerValidationError},
     AnyMessageLikeEvent, MessageLikeEvent,
 };
 use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
@@ -174,3 +174,66 @@ fn event_deserialization() {
     assert_eq!(thumbnail_info.mimetype.as_deref(), Some("image/png"));
     assert_eq!(thumbnail_info.size, Some(uint!(82595)));
 }
+
+#[test]
+fn content_validation() {
+    let url = mxc_uri!("mxc://notareal.hs/file");
+    let info =
+        |width: Option<UInt>, height: Option<UInt>| assign!(ImageInfo::new(), { width, height });
+
+    let content = StickerEventContent::try_new(
+        "Sticker".to_owned(),
+        info(Some(uint!(256)), Some(uint!(128))),
+        url.to_owned(),
+    )
+    .unwrap();
+    content.validate().unwrap();
+
+    assert_eq!(
+        StickerEventContent::try_new(
+            "Sticker".to_owned(),
+            info(None, Some(uint!(128))),
+            url.to_owned()
+        )
+        .unwrap_err(),
+        StickerValidationError::MissingWidth
+    );
+    assert_eq!(
+        StickerEventContent::new(
+            "Sticker".to_owned(),
+            info(Some(uint!(0)), Some(uint!(128))),
+            url.to_owned()
+        )
+        .validate()
+        .unwrap_err(),
+        StickerValidationError::MissingWidth
+    );
+    assert_eq!(
+        StickerEventContent::try_new(
+            "Sticker".to_owned(),
+            info(Some(uint!(256)), None),
+            url.to_owned()
+        )
+        .unwrap_err(),
+        StickerValidationError::MissingHeight
+    );
+    assert_eq!(
+        StickerEventContent::new(
+            "Sticker".to_owned(),
+            info(Some(uint!(256)), Some(uint!(0))),
+            url.to_owned()
+        )
+        .validate()
+        .unwrap_err(),
+        StickerValidationError::MissingHeight
+    );
+    assert_eq!(
+        StickerEventContent::try_new(
+            "Sticker".to_owned(),
+            info(Some(uint!(256)), Some(uint!(128))),
+            OwnedMxcUri::from("not-an-mxc-uri"),
+        )
+        .unwrap_err(),
+        StickerValidationError::InvalidUrl
+    );
+}
@@ -1,6 +1,11 @@
-use ruma_common::{owned_room_id, EventEncryptionAlgorithm};
-use ruma_events::room_key::ToDeviceRoomKeyEventContent;
-use serde_json::{json, to_value as to_json_value};
+use assert_matches2::assert_matches;
+use ruma_common::{owned_room_id, room_id, serde::Raw, user_id, EventEncryptionAlgorithm};
+use ruma_events::{
+    room_key::ToDeviceRoomKeyEventContent,
+    room_key_request::{Action, KeyRequestValidationError, ToDeviceRoomKeyRequestEventContent},
+    AnyToDeviceEvent, ToDeviceEventType,
+};
+use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
 
 #[test]
 fn serialization() {
@@ -21,3 +26,95 @@ fn serialization() {
         })
     );
 }
+
+#[test]
+fn deserialize_any_to_device_room_key() {
+    let json = json!({
+        "content": {
+            "algorithm": "m.megolm.v1.aes-sha2",
+            "room_id": "!testroomid:example.org",
+            "session_id": "SessId",
+            "session_key": "SessKey",
+        },
+        "sender": "@alice:example.org",
+        "type": "m.room_key",
+    });
+    let raw: Raw<AnyToDeviceEvent> = from_json_value(json).unwrap();
+
+    assert_matches!(raw.deserialize().unwrap(), AnyToDeviceEvent::RoomKey(event));
+    assert_eq!(event.sender, user_id!("@alice:example.org"));
+    assert_eq!(event.content.room_id, room_id!("!testroomid:example.org"));
+    assert_eq!(event.content.session_id, "SessId");
+}
+
+#[test]
+fn deserialize_any_to_device_room_key_request() {
+    let json = json!({
+        "content": {
+            "action": "request_cancellation",
+            "request_id": "1495474790150.19",
+            "requesting_device_id": "RJYKSTBOIE",
+        },
+        "sender": "@alice:example.org",
+        "type": "m.room_key_request",
+    });
+    let raw: Raw<AnyToDeviceEvent> = from_json_value(json).unwrap();
+
+    assert_matches!(raw.deserialize().unwrap(), AnyToDeviceEvent::RoomKeyRequest(event));
+    assert_eq!(event.content.action, Action::CancelRequest);
+    assert_eq!(event.content.requesting_device_id, "RJYKSTBOIE");
+    assert!(event.content.body.is_none());
+}
+
+#[test]
+fn room_key_request_validation() {
+    let json = json!({
+        "action": "request",
+        "body": {
+            "algorithm": "m.megolm.v1.aes-sha2",
+            "room_id": "!testroomid:example.org",
+            "sender_key": "RF3s+E7RkTQTGF2d8Deol0FkQvgII2aJDf3/Jp5mxVU",
+            "session_id": "X3lUlvLELLYxeTx4yOVu6UDpasGEVO0Jbu+QFnm0cKQ",
+        },
+        "request_id": "1495474790150.19",
+        "requesting_device_id": "RJYKSTBOIE",
+    });
+    let content = from_json_value::<ToDeviceRoomKeyRequestEventContent>(json).unwrap();
+    assert_eq!(content.action, Action::Request);
+    assert_eq!(content.body.as_ref().unwrap().room_id, "!testroomid:example.org");
+    content.validate().unwrap();
+
+    let json = json!({
+        "action": "request_cancellation",
+        "request_id": "1495474790150.19",
+        "requesting_device_id": "RJYKSTBOIE",
+    });
+    let content = from_json_value::<ToDeviceRoomKeyRequestEventContent>(json).unwrap();
+    assert_eq!(content.action, Action::CancelRequest);
+    content.validate().unwrap();
+
+    let json = json!({
+        "action": "request",
+        "request_id": "1495474790150.19",
+        "requesting_device_id": "RJYKSTBOIE",
+    });
+    let err = from_json_value::<ToDeviceRoomKeyRequestEventContent>(json).unwrap_err();
+    assert_eq!(err.to_string(), KeyRequestValidationError::MissingBody.to_string());
+}
+
+#[test]
+fn deserialize_any_to_device_custom() {
+    let json = json!({
+        "content": {
+            "foo": "bar",
+        },
+        "sender": "@alice:example.org",
+        "type": "org.example.custom",
+    });
+    let raw: Raw<AnyToDeviceEvent> = from_json_value(json).unwrap();
+
+    let event = raw.deserialize().unwrap();
+    assert_eq!(event.event_type(), ToDeviceEventType::from("org.example.custom"));
+    assert_eq!(event.sender(), user_id!("@alice:example.org"));
+    assert_matches!(event, AnyToDeviceEvent::_Custom(_));
+}
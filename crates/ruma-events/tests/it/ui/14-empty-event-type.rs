@@ -0,0 +1,22 @@
+use ruma_macros::EventContent;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize, EventContent)]
+#[ruma_event(type = "", kind = MessageLike)]
+pub struct EmptyTypeTest {
+    pub test: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, EventContent)]
+#[ruma_event(type = "m.macro test", kind = MessageLike)]
+pub struct WhitespaceTypeTest {
+    pub test: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, EventContent)]
+#[ruma_event(type = "m.macro.test", alias = " ", kind = MessageLike)]
+pub struct WhitespaceAliasTest {
+    pub test: String,
+}
+
+fn main() {}
@@ -4,6 +4,7 @@ use std::collections::BTreeMap;
 
 use ruma_common::{
     serde::Base64, MilliSecondsSinceUnixEpoch, OwnedServerName, OwnedServerSigningKeyId,
+    ServerSigningKeyId,
 };
 use serde::{Deserialize, Serialize};
 
@@ -86,4 +87,81 @@ impl ServerSigningKeys {
             valid_until_ts,
         }
     }
+
+    /// Whether these keys should be refreshed at the given time, according to `valid_until_ts`.
+    pub fn is_expired(&self, now: MilliSecondsSinceUnixEpoch) -> bool {
+        self.valid_until_ts <= now
+    }
+
+    /// Get the current verify key with the given ID, if these keys are not expired at the given
+    /// time.
+    ///
+    /// Keys in `old_verify_keys` are never returned, since they should only be used to verify
+    /// signatures made before their `expired_ts`.
+    pub fn trusted_key_for(
+        &self,
+        key_id: &ServerSigningKeyId,
+        now: MilliSecondsSinceUnixEpoch,
+    ) -> Option<&Base64> {
+        if self.is_expired(now) {
+            return None;
+        }
+
+        self.verify_keys.get(key_id).map(|verify_key| &verify_key.key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ruma_common::{server_signing_key_id, MilliSecondsSinceUnixEpoch};
+    use serde_json::{from_value as from_json_value, json};
+
+    use super::ServerSigningKeys;
+
+    fn keys() -> ServerSigningKeys {
+        from_json_value(json!({
+            "old_verify_keys": {
+                "ed25519:0ldk3y": {
+                    "expired_ts": 1_532_645_052_628_u64,
+                    "key": "VGhpcyBzaG91bGQgYmUgeW91ciBvbGQga2V5J3MgZWQyNTUxOSBwYXlsb2FkLg",
+                },
+            },
+            "server_name": "example.org",
+            "signatures": {
+                "example.org": {
+                    "ed25519:auto2": "VGhpcyBzaG91bGQgYWN0dWFsbHkgYmUgYSBzaWduYXR1cmU",
+                },
+            },
+            "valid_until_ts": 1_652_262_000_000_u64,
+            "verify_keys": {
+                "ed25519:abc123": {
+                    "key": "VGhpcyBzaG91bGQgYmUgYSByZWFsIGVkMjU1MTkgcGF5bG9hZA",
+                },
+            },
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn valid_keys() {
+        let keys = keys();
+        let now = MilliSecondsSinceUnixEpoch(1_652_000_000_000_u64.try_into().unwrap());
+
+        assert!(!keys.is_expired(now));
+        assert_eq!(
+            keys.trusted_key_for(server_signing_key_id!("ed25519:abc123"), now).unwrap().encode(),
+            "VGhpcyBzaG91bGQgYmUgYSByZWFsIGVkMjU1MTkgcGF5bG9hZA"
+        );
+        assert_eq!(keys.trusted_key_for(server_signing_key_id!("ed25519:0ldk3y"), now), None);
+        assert_eq!(keys.trusted_key_for(server_signing_key_id!("ed25519:unknown"), now), None);
+    }
+
+    #[test]
+    fn expired_keys() {
+        let keys = keys();
+        let now = MilliSecondsSinceUnixEpoch(1_652_262_000_000_u64.try_into().unwrap());
+
+        assert!(keys.is_expired(now));
+        assert_eq!(keys.trusted_key_for(server_signing_key_id!("ed25519:abc123"), now), None);
+    }
 }
@@ -91,3 +91,100 @@ pub mod v1 {
         *val == default_limit()
     }
 }
+
+#[cfg(all(test, feature = "client"))]
+mod tests {
+    use js_int::uint;
+    use ruma_common::{
+        api::{IncomingResponse, MatrixVersion, OutgoingRequest, SendAccessToken},
+        owned_event_id, owned_room_id,
+    };
+    use serde_json::{
+        from_slice as from_json_slice, json, to_value as to_json_value, to_vec as to_json_vec,
+        Value as JsonValue,
+    };
+
+    use super::v1::{Request, Response};
+
+    #[test]
+    fn serialize_request() {
+        let mut req = Request::new(
+            owned_room_id!("!room:example.org"),
+            vec![owned_event_id!("$earliest:example.org")],
+            vec![owned_event_id!("$latest:example.org")],
+        );
+
+        let http_req = req
+            .clone()
+            .try_into_http_request::<Vec<u8>>(
+                "https://example.org",
+                SendAccessToken::IfRequired("auth_tok"),
+                &[MatrixVersion::V1_1],
+            )
+            .unwrap();
+        assert_eq!(
+            http_req.uri(),
+            "https://example.org/_matrix/federation/v1/get_missing_events/!room:example.org"
+        );
+        assert_eq!(
+            from_json_slice::<JsonValue>(http_req.body()).unwrap(),
+            json!({
+                "earliest_events": ["$earliest:example.org"],
+                "latest_events": ["$latest:example.org"],
+            })
+        );
+
+        req.limit = uint!(20);
+        req.min_depth = uint!(5);
+        let http_req = req
+            .try_into_http_request::<Vec<u8>>(
+                "https://example.org",
+                SendAccessToken::IfRequired("auth_tok"),
+                &[MatrixVersion::V1_1],
+            )
+            .unwrap();
+        assert_eq!(
+            from_json_slice::<JsonValue>(http_req.body()).unwrap(),
+            json!({
+                "earliest_events": ["$earliest:example.org"],
+                "latest_events": ["$latest:example.org"],
+                "limit": 20,
+                "min_depth": 5,
+            })
+        );
+    }
+
+    #[test]
+    fn deserialize_response() {
+        let pdu = json!({
+            "auth_events": ["$create:example.org", "$power_levels:example.org"],
+            "content": {
+                "body": "Hello",
+                "msgtype": "m.text",
+            },
+            "depth": 12,
+            "hashes": {
+                "sha256": "thishashcoversallfieldsincasethisisredacted",
+            },
+            "origin_server_ts": 1_404_838_188_000_u64,
+            "prev_events": ["$earliest:example.org"],
+            "room_id": "!room:example.org",
+            "sender": "@alice:example.org",
+            "signatures": {
+                "example.org": {
+                    "ed25519:key_version": "these86bytesofbase64signaturecoveressentialfieldsincludinghashessocancheckredactedpdus",
+                },
+            },
+            "type": "m.room.message",
+        });
+        let body = json!({ "events": [pdu] });
+
+        let res = Response::try_from_http_response(
+            http::Response::builder().body(to_json_vec(&body).unwrap()).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(res.events.len(), 1);
+        assert_eq!(to_json_value(&res.events[0]).unwrap(), pdu);
+    }
+}
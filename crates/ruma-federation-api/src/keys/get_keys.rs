@@ -67,3 +67,132 @@ pub mod v1 {
         }
     }
 }
+
+#[cfg(all(test, feature = "client"))]
+mod tests {
+    use ruma_common::{
+        api::IncomingResponse, encryption::KeyUsage, owned_device_id, owned_user_id, user_id,
+        DeviceKeyAlgorithm, DeviceKeyId,
+    };
+    use serde_json::{json, to_vec as to_json_vec};
+
+    use super::v1::Response;
+
+    #[test]
+    fn deserialize_response() {
+        let body = json!({
+            "device_keys": {
+                "@alice:example.org": {
+                    "JLAFKJWSCS": {
+                        "algorithms": ["m.olm.v1.curve25519-aes-sha2", "m.megolm.v1.aes-sha2"],
+                        "device_id": "JLAFKJWSCS",
+                        "keys": {
+                            "curve25519:JLAFKJWSCS": "3C5BFWi2Y8MaVvjM8M22DBmh24PmgR0nPvJOIArzgyI",
+                            "ed25519:JLAFKJWSCS": "lEuiRJBit0IG6nUf5pUzWTUEsRVVe/HJkoKuEww9ULI",
+                        },
+                        "signatures": {
+                            "@alice:example.org": {
+                                "ed25519:JLAFKJWSCS": "dSO80A01XiigH3uBiDVx/EjzaoycHcjq9lfQX0uWsqxl2giMIiSPR8a4d291W1ihKJL/a+myXS367WT6NAIcBA",
+                            },
+                        },
+                        "unsigned": {
+                            "device_display_name": "Alice's mobile phone",
+                        },
+                        "user_id": "@alice:example.org",
+                    },
+                    "QBUAZIFURK": {
+                        "algorithms": ["m.olm.v1.curve25519-aes-sha2", "m.megolm.v1.aes-sha2"],
+                        "device_id": "QBUAZIFURK",
+                        "keys": {
+                            "curve25519:QBUAZIFURK": "jvOAzp1xEvvAjzgeePZZv8EyrgWgbHLS1UkvALXC4Ts",
+                            "ed25519:QBUAZIFURK": "L9Qk1DAx9WdKmBTwjY0xlOq4OHIxhWs0lmYqHtAdv6c",
+                        },
+                        "signatures": {
+                            "@alice:example.org": {
+                                "ed25519:QBUAZIFURK": "ICHqBe9kvJpsVOPs9VqmK4thk0fhWT7Vl5omNbwSiQP2nUtnBCd5jxnNnzJoUL0xOQHwHXDLFz1lYuZqS3q2Cw",
+                            },
+                        },
+                        "user_id": "@alice:example.org",
+                    },
+                },
+                "@bob:example.org": {
+                    "BOBDEVICE": {
+                        "algorithms": ["m.olm.v1.curve25519-aes-sha2", "m.megolm.v1.aes-sha2"],
+                        "device_id": "BOBDEVICE",
+                        "keys": {
+                            "curve25519:BOBDEVICE": "wjLpTLRqbqBzLs63aYaEv2Boi6cFEbbM/sSRQ2oAKk4",
+                            "ed25519:BOBDEVICE": "nE6W2fCblxDcOFmeEtCHNl8/l8bXcu7GKyAswA4r3mM",
+                        },
+                        "signatures": {
+                            "@bob:example.org": {
+                                "ed25519:BOBDEVICE": "m53Wkbh2HXkc3vFApZvCrfXcX3AI51GsDHustMhKwlv3TuOJMj4wistcOTM8q2+e/Ro7rWFUb9ZfnNbwptSUBA",
+                            },
+                        },
+                        "user_id": "@bob:example.org",
+                    },
+                },
+            },
+            "master_keys": {
+                "@alice:example.org": {
+                    "keys": {
+                        "ed25519:base64+master+public+key": "base64+master+public+key",
+                    },
+                    "usage": ["master"],
+                    "user_id": "@alice:example.org",
+                },
+            },
+            "self_signing_keys": {
+                "@alice:example.org": {
+                    "keys": {
+                        "ed25519:base64+self+signing+public+key": "base64+self+signing+public+key",
+                    },
+                    "signatures": {
+                        "@alice:example.org": {
+                            "ed25519:base64+master+public+key": "signature+of+self+signing+key",
+                        },
+                    },
+                    "usage": ["self_signing"],
+                    "user_id": "@alice:example.org",
+                },
+            },
+        });
+        let response = Response::try_from_http_response(
+            http::Response::builder().body(to_json_vec(&body).unwrap()).unwrap(),
+        )
+        .unwrap();
+
+        let alice = user_id!("@alice:example.org");
+        let bob = user_id!("@bob:example.org");
+
+        assert_eq!(response.device_keys.len(), 2);
+        let alice_devices = &response.device_keys[alice];
+        assert_eq!(alice_devices.len(), 2);
+        let device_keys = alice_devices[&owned_device_id!("JLAFKJWSCS")].deserialize().unwrap();
+        assert_eq!(device_keys.user_id, alice);
+        assert_eq!(device_keys.device_id, "JLAFKJWSCS");
+        assert_eq!(
+            device_keys.keys
+                [&DeviceKeyId::from_parts(DeviceKeyAlgorithm::Curve25519, "JLAFKJWSCS".into())],
+            "3C5BFWi2Y8MaVvjM8M22DBmh24PmgR0nPvJOIArzgyI"
+        );
+        assert_eq!(
+            device_keys.unsigned.device_display_name.as_deref(),
+            Some("Alice's mobile phone")
+        );
+        let device_keys = alice_devices[&owned_device_id!("QBUAZIFURK")].deserialize().unwrap();
+        assert_eq!(device_keys.device_id, "QBUAZIFURK");
+
+        let bob_devices = &response.device_keys[bob];
+        assert_eq!(bob_devices.len(), 1);
+        let device_keys = bob_devices[&owned_device_id!("BOBDEVICE")].deserialize().unwrap();
+        assert_eq!(device_keys.user_id, bob);
+
+        assert_eq!(response.master_keys.len(), 1);
+        let master_key = response.master_keys[alice].deserialize().unwrap();
+        assert_eq!(master_key.usage, [KeyUsage::Master]);
+        assert_eq!(response.self_signing_keys.len(), 1);
+        let self_signing_key = response.self_signing_keys[alice].deserialize().unwrap();
+        assert_eq!(self_signing_key.usage, [KeyUsage::SelfSigning]);
+        assert!(!response.self_signing_keys.contains_key(&owned_user_id!("@bob:example.org")));
+    }
+}
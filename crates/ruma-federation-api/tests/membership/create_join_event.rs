@@ -17,3 +17,96 @@ mod v1 {
         );
     }
 }
+
+#[cfg(all(feature = "client", feature = "server", not(feature = "unstable-unspecified")))]
+mod v2 {
+    use ruma_common::{
+        api::{
+            IncomingRequest, IncomingResponse, MatrixVersion, OutgoingRequest, OutgoingResponse,
+            SendAccessToken,
+        },
+        owned_event_id, owned_room_id,
+    };
+    use ruma_federation_api::membership::create_join_event::v2::{Request, Response, RoomState};
+    use serde_json::{
+        from_slice as from_json_slice, json, to_value as to_json_value,
+        value::to_raw_value as to_raw_json_value, Value as JsonValue,
+    };
+
+    fn pdu() -> JsonValue {
+        json!({
+            "content": {
+                "see_room_version_spec": "The event format changes depending on the room version.",
+            },
+            "room_id": "!somewhere:example.org",
+            "type": "m.room.minimal_pdu",
+        })
+    }
+
+    #[test]
+    fn request_round_trip() {
+        let mut req = Request::new(
+            owned_room_id!("!somewhere:example.org"),
+            owned_event_id!("$abc123:example.org"),
+            to_raw_json_value(&pdu()).unwrap(),
+        );
+        req.omit_members = true;
+
+        let http_req = req
+            .try_into_http_request::<Vec<u8>>(
+                "https://example.org",
+                SendAccessToken::IfRequired("auth_tok"),
+                &[MatrixVersion::V1_1],
+            )
+            .unwrap();
+        assert_eq!(
+            http_req.uri(),
+            "https://example.org/_matrix/federation/v2/send_join/!somewhere:example.org/$abc123:example.org?omit_members=true"
+        );
+        assert_eq!(from_json_slice::<JsonValue>(http_req.body()).unwrap(), pdu());
+
+        let req = Request::try_from_http_request(
+            http_req,
+            &["!somewhere:example.org", "$abc123:example.org"],
+        )
+        .unwrap();
+        assert_eq!(req.room_id, "!somewhere:example.org");
+        assert_eq!(req.event_id, "$abc123:example.org");
+        assert_eq!(to_json_value(&req.pdu).unwrap(), pdu());
+        assert!(req.omit_members);
+    }
+
+    #[test]
+    fn response_round_trip() {
+        let mut room_state = RoomState::new("matrix.org".to_owned());
+        room_state.auth_chain = vec![to_raw_json_value(&pdu()).unwrap()];
+        room_state.state = vec![to_raw_json_value(&pdu()).unwrap()];
+        room_state.event = Some(to_raw_json_value(&pdu()).unwrap());
+        room_state.members_omitted = true;
+        room_state.servers_in_room = Some(vec!["matrix.org".to_owned(), "example.com".to_owned()]);
+
+        let http_res = Response::new(room_state).try_into_http_response::<Vec<u8>>().unwrap();
+        assert_eq!(
+            from_json_slice::<JsonValue>(http_res.body()).unwrap(),
+            json!({
+                "auth_chain": [pdu()],
+                "event": pdu(),
+                "members_omitted": true,
+                "origin": "matrix.org",
+                "servers_in_room": ["matrix.org", "example.com"],
+                "state": [pdu()],
+            })
+        );
+
+        let room_state = Response::try_from_http_response(http_res).unwrap().room_state;
+        assert_eq!(room_state.origin, "matrix.org");
+        assert!(room_state.members_omitted);
+        assert_eq!(room_state.auth_chain.len(), 1);
+        assert_eq!(to_json_value(&room_state.state).unwrap(), json!([pdu()]));
+        assert_eq!(to_json_value(&room_state.event).unwrap(), pdu());
+        assert_eq!(
+            room_state.servers_in_room.as_deref(),
+            Some(&["matrix.org".to_owned(), "example.com".to_owned()][..])
+        );
+    }
+}
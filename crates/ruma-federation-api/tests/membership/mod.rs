@@ -1 +1,2 @@
 mod create_join_event;
+mod prepare_join_event;
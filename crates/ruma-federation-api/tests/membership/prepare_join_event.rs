@@ -0,0 +1,93 @@
+#[cfg(all(feature = "client", feature = "server"))]
+mod v1 {
+    use ruma_common::{
+        api::{
+            IncomingRequest, IncomingResponse, MatrixVersion, OutgoingRequest, OutgoingResponse,
+            SendAccessToken,
+        },
+        owned_room_id, owned_user_id, RoomVersionId,
+    };
+    use ruma_federation_api::membership::prepare_join_event::v1::{Request, Response};
+    use serde_json::{
+        from_slice as from_json_slice, json, to_value as to_json_value,
+        value::to_raw_value as to_raw_json_value, Value as JsonValue,
+    };
+
+    #[test]
+    fn request_round_trip() {
+        let mut req = Request::new(
+            owned_room_id!("!somewhere:example.org"),
+            owned_user_id!("@someone:example.org"),
+        );
+        req.ver = vec![RoomVersionId::V2, RoomVersionId::V10];
+
+        let http_req = req
+            .try_into_http_request::<Vec<u8>>(
+                "https://example.org",
+                SendAccessToken::IfRequired("auth_tok"),
+                &[MatrixVersion::V1_1],
+            )
+            .unwrap();
+        assert_eq!(
+            http_req.uri(),
+            "https://example.org/_matrix/federation/v1/make_join/!somewhere:example.org/@someone:example.org?ver=2&ver=10"
+        );
+
+        let req = Request::try_from_http_request(
+            http_req,
+            &["!somewhere:example.org", "@someone:example.org"],
+        )
+        .unwrap();
+        assert_eq!(req.room_id, "!somewhere:example.org");
+        assert_eq!(req.user_id, "@someone:example.org");
+        assert_eq!(req.ver, [RoomVersionId::V2, RoomVersionId::V10]);
+    }
+
+    #[test]
+    fn request_default_ver() {
+        let http_req = http::Request::builder()
+            .method("GET")
+            .uri("https://example.org/_matrix/federation/v1/make_join/!somewhere:example.org/@someone:example.org")
+            .body(&[] as &[u8])
+            .unwrap();
+
+        let req = Request::try_from_http_request(
+            http_req,
+            &["!somewhere:example.org", "@someone:example.org"],
+        )
+        .unwrap();
+        assert_eq!(req.ver, [RoomVersionId::V1]);
+    }
+
+    #[test]
+    fn response_round_trip() {
+        let event = json!({
+            "content": {
+                "join_authorised_via_users_server": "@anyone:resident.example.org",
+                "membership": "join",
+            },
+            "origin": "example.org",
+            "origin_server_ts": 1_549_041_175_876_u64,
+            "room_id": "!somewhere:example.org",
+            "sender": "@someone:example.org",
+            "state_key": "@someone:example.org",
+            "type": "m.room.member",
+        });
+
+        let mut res = Response::new(to_raw_json_value(&event).unwrap());
+        res.room_version = Some(RoomVersionId::V2);
+
+        let http_res = res.try_into_http_response::<Vec<u8>>().unwrap();
+        assert_eq!(
+            from_json_slice::<JsonValue>(http_res.body()).unwrap(),
+            json!({
+                "event": event,
+                "room_version": "2",
+            })
+        );
+
+        let res = Response::try_from_http_response(http_res).unwrap();
+        assert_eq!(res.room_version, Some(RoomVersionId::V2));
+        assert_eq!(to_json_value(&res.event).unwrap(), event);
+    }
+}
@@ -29,8 +29,11 @@ pub fn localpart_is_fully_conforming(localpart: &str) -> Result<bool, Error> {
         // for historical user IDs, or is empty. If that's the case, return an error.
         // See https://spec.matrix.org/latest/appendices/#historical-user-ids
         #[cfg(not(feature = "compat-user-id"))]
-        let is_invalid =
-            localpart.is_empty() || localpart.bytes().any(|b| b < 0x21 || b == b':' || b > 0x7E);
+        if localpart.is_empty() {
+            return Err(Error::Empty);
+        }
+        #[cfg(not(feature = "compat-user-id"))]
+        let is_invalid = localpart.bytes().any(|b| b < 0x21 || b == b':' || b > 0x7E);
 
         // In compat mode, allow anything except `:` to match Synapse. The `:` check is only needed
         // because this function can be called through `UserId::parse_with_servername`, otherwise
@@ -1,7 +1,7 @@
 //! Implementations of the EventContent derive macro.
 #![allow(clippy::too_many_arguments)] // FIXME
 
-use std::{borrow::Cow, fmt};
+use std::{borrow::Cow, fmt, iter};
 
 use proc_macro2::{Span, TokenStream};
 use quote::{format_ident, quote, ToTokens};
@@ -199,7 +199,7 @@ impl TryFrom<ContentMeta> for ContentAttrs {
         let event_type = event_type.ok_or_else(|| {
             syn::Error::new(
                 Span::call_site(),
-                "no event type attribute found, \
+                "`#[ruma_event(type = \"…\")]` is required, \
                 add `#[ruma_event(type = \"any.room.event\", kind = Kind)]` \
                 below the event content derive",
             )
@@ -227,6 +227,18 @@ impl TryFrom<ContentMeta> for ContentAttrs {
 
         let unsigned_type = unsigned_type.map(|ty| quote! { #ty });
 
+        for ty in iter::once(&event_type).chain(&aliases) {
+            let ty_s = ty.value();
+
+            if ty_s.is_empty() {
+                return Err(syn::Error::new_spanned(ty, "event type must not be empty"));
+            }
+
+            if ty_s.contains(char::is_whitespace) {
+                return Err(syn::Error::new_spanned(ty, "event type must not contain whitespace"));
+            }
+        }
+
         let event_type_s = event_type.value();
         let prefix = event_type_s.strip_suffix(".*");
 
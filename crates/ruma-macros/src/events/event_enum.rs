@@ -650,6 +650,50 @@ fn expand_accessor_methods(
         }
     });
 
+    let redacted_because_accessor = maybe_redacted.then(|| {
+        let variants = variants.iter().map(|v| v.match_arm(quote! { Self }));
+
+        quote! {
+            /// Returns the redaction event from inside `unsigned`, if this event was redacted.
+            pub fn redacted_because(&self) -> Option<&#ruma_events::UnsignedRoomRedactionEvent> {
+                match self {
+                    #( #variants(event) => event.redacted_because(), )*
+                    Self::_Custom(event) => event.redacted_because(),
+                }
+            }
+        }
+    });
+
+    let into_redacted = maybe_redacted.then(|| {
+        let variants = variants.iter().map(|v| v.match_arm(quote! { Self }));
+
+        quote! {
+            /// Transform `self` into its redacted form, removing the fields of the content that
+            /// are not preserved by redaction.
+            ///
+            /// If `self` is already redacted, it is returned unmodified.
+            ///
+            /// A small number of events have room-version specific redaction behavior, so a
+            /// version has to be specified.
+            pub fn into_redacted(
+                self,
+                redacted_because: #ruma_events::UnsignedRoomRedactionEvent,
+                version: &#ruma_common::RoomVersionId,
+            ) -> Self {
+                match self {
+                    #(
+                        #variants(event) => {
+                            #variants(event.into_redacted(redacted_because, version))
+                        }
+                    )*
+                    Self::_Custom(event) => {
+                        Self::_Custom(event.into_redacted(redacted_because, version))
+                    }
+                }
+            }
+        }
+    });
+
     Ok(quote! {
         #[automatically_derived]
         impl #ident {
@@ -663,6 +707,8 @@ fn expand_accessor_methods(
             #relations_accessor
             #state_key_accessor
             #maybe_redacted_accessors
+            #redacted_because_accessor
+            #into_redacted
         }
     })
 }
@@ -46,6 +46,7 @@ use self::{
         enum_as_ref_str::expand_enum_as_ref_str,
         enum_from_string::expand_enum_from_string,
         eq_as_ref_str::expand_partial_eq_as_ref_str,
+        from_str_as_from_string::expand_from_str_as_from_string,
         ord_as_ref_str::{expand_ord_as_ref_str, expand_partial_ord_as_ref_str},
         serialize_as_ref_str::expand_serialize_as_ref_str,
     },
@@ -341,6 +342,9 @@ pub fn derive_partial_eq_as_ref_str(input: TokenStream) -> TokenStream {
 
 /// Shorthand for the derives `AsRefStr`, `FromString`, `DisplayAsRefStr`, `DebugAsRefStr`,
 /// `SerializeAsRefStr` and `DeserializeFromCowStr`.
+///
+/// Also implements `FromStr` using the `From` implementation generated by `FromString`, with
+/// `Infallible` as the error type since unknown strings are stored in the custom variant.
 #[proc_macro_derive(StringEnum, attributes(ruma_enum))]
 pub fn derive_string_enum(input: TokenStream) -> TokenStream {
     fn expand_all(input: ItemEnum) -> syn::Result<proc_macro2::TokenStream> {
@@ -348,6 +352,7 @@ pub fn derive_string_enum(input: TokenStream) -> TokenStream {
         let from_string_impl = expand_enum_from_string(&input)?;
         let as_str_impl = expand_as_str_as_ref_str(&input.ident)?;
         let display_impl = expand_display_as_ref_str(&input.ident)?;
+        let from_str_impl = expand_from_str_as_from_string(&input.ident)?;
         let debug_impl = expand_debug_as_ref_str(&input.ident)?;
         let serialize_impl = expand_serialize_as_ref_str(&input.ident)?;
         let deserialize_impl = expand_deserialize_from_cow_str(&input.ident)?;
@@ -357,6 +362,7 @@ pub fn derive_string_enum(input: TokenStream) -> TokenStream {
             #from_string_impl
             #as_str_impl
             #display_impl
+            #from_str_impl
             #debug_impl
             #serialize_impl
             #deserialize_impl
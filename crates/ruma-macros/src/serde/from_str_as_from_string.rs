@@ -0,0 +1,16 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+pub fn expand_from_str_as_from_string(ident: &Ident) -> syn::Result<TokenStream> {
+    Ok(quote! {
+        #[automatically_derived]
+        #[allow(deprecated)]
+        impl ::std::str::FromStr for #ident {
+            type Err = ::std::convert::Infallible;
+
+            fn from_str(s: &::std::primitive::str) -> ::std::result::Result<Self, Self::Err> {
+                ::std::result::Result::Ok(::std::convert::From::from(s))
+            }
+        }
+    })
+}
@@ -1,7 +1,7 @@
 use ruma_common::{
     canonical_json::{JsonType, RedactionError},
     serde::Base64DecodeError,
-    EventId, OwnedEventId, OwnedServerName, RoomVersionId,
+    EventId, MilliSecondsSinceUnixEpoch, OwnedEventId, OwnedServerName, RoomVersionId,
 };
 use thiserror::Error;
 
@@ -153,6 +153,19 @@ pub enum VerificationError {
     /// For when [`ed25519_dalek`] cannot verify a signature.
     #[error("Could not verify signature: {0}")]
     Signature(#[source] ed25519_dalek::SignatureError),
+
+    /// For when the server name of a server keys response doesn't match the expected one.
+    #[error("Expected server keys for {expected:?}, found keys for {found:?}")]
+    ServerNameMismatch {
+        /// The server name that was expected.
+        expected: OwnedServerName,
+        /// The server name that was found instead.
+        found: String,
+    },
+
+    /// For when the keys of a server keys response have expired.
+    #[error("Server keys expired at {0:?}")]
+    KeysExpired(MilliSecondsSinceUnixEpoch),
 }
 
 impl VerificationError {
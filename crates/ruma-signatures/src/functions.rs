@@ -7,10 +7,12 @@ use std::{
 };
 
 use base64::{alphabet, Engine};
+use js_int::UInt;
 use ruma_common::{
     canonical_json::{redact, JsonType},
     serde::{base64::Standard, Base64},
-    CanonicalJsonObject, CanonicalJsonValue, OwnedEventId, OwnedServerName, RoomVersionId, UserId,
+    CanonicalJsonObject, CanonicalJsonValue, MilliSecondsSinceUnixEpoch, OwnedEventId,
+    OwnedServerName, RoomVersionId, ServerName, UserId,
 };
 use serde_json::{from_str as from_json_str, to_string as to_json_string};
 use sha2::{digest::Digest, Sha256};
@@ -346,6 +348,38 @@ pub fn reference_hash(
     Ok(base64_engine.encode(hash))
 }
 
+/// Gets the event ID of a PDU.
+///
+/// For room versions 1 and 2, the event ID is the `event_id` field of the PDU. For later room
+/// versions, the event ID is computed from the [reference hash] of the PDU, prefixed with `$`.
+///
+/// # Parameters
+///
+/// * object: The JSON object of the PDU.
+/// * version: The room version of the room that the PDU belongs to.
+///
+/// # Errors
+///
+/// Returns an error if the `event_id` field is missing or invalid for room versions 1 and 2, or if
+/// the reference hash could not be computed.
+///
+/// [reference hash]: reference_hash
+pub fn event_id(
+    object: &CanonicalJsonObject,
+    version: &RoomVersionId,
+) -> Result<OwnedEventId, Error> {
+    let raw_event_id = match version {
+        RoomVersionId::V1 | RoomVersionId::V2 => match object.get("event_id") {
+            Some(CanonicalJsonValue::String(raw_event_id)) => raw_event_id.clone(),
+            Some(_) => return Err(JsonError::not_of_type("event_id", JsonType::String)),
+            None => return Err(JsonError::field_missing_from_object("event_id")),
+        },
+        _ => format!("${}", reference_hash(object, version)?),
+    };
+
+    OwnedEventId::try_from(raw_event_id).map_err(|e| ParseError::EventId(e).into())
+}
+
 /// Hashes and signs an event and adds the hash and signature to objects under the keys `hashes` and
 /// `signatures`, respectively.
 ///
@@ -628,6 +662,121 @@ pub fn verify_event(
     Ok(Verified::Signatures)
 }
 
+/// Verifies the response of a homeserver to a request for its signing keys.
+///
+/// This is meant to be used with the object returned by `GET /_matrix/key/v2/server`. It checks
+/// that:
+///
+/// * The `server_name` of the object matches the server the keys were requested from.
+/// * The keys have not expired, according to `valid_until_ts`.
+/// * The object is signed by the server with at least one of its own `verify_keys`.
+///
+/// Signatures from other entities, like notary servers, are ignored.
+///
+/// # Parameters
+///
+/// * server_name: The homeserver the keys were requested from.
+/// * object: The JSON object of the response.
+///
+/// # Errors
+///
+/// Returns an error if:
+///
+/// * `object` is missing one of the required fields, or one of them has the wrong type.
+/// * The `server_name` doesn't match the one that was expected.
+/// * The keys have expired.
+/// * Verification of the signatures fails.
+pub fn verify_server_keys_response(
+    server_name: &ServerName,
+    object: &CanonicalJsonObject,
+) -> Result<(), Error> {
+    match object.get("server_name") {
+        Some(CanonicalJsonValue::String(found)) if found == server_name.as_str() => {}
+        Some(CanonicalJsonValue::String(found)) => {
+            return Err(VerificationError::ServerNameMismatch {
+                expected: server_name.to_owned(),
+                found: found.clone(),
+            }
+            .into())
+        }
+        Some(_) => return Err(JsonError::not_of_type("server_name", JsonType::String)),
+        None => return Err(JsonError::field_missing_from_object("server_name")),
+    }
+
+    let valid_until_ts = match object.get("valid_until_ts") {
+        Some(CanonicalJsonValue::Integer(ts)) => UInt::try_from(i64::from(*ts))
+            .map(MilliSecondsSinceUnixEpoch)
+            .map_err(|_| JsonError::not_of_type("valid_until_ts", JsonType::Integer))?,
+        Some(_) => return Err(JsonError::not_of_type("valid_until_ts", JsonType::Integer)),
+        None => return Err(JsonError::field_missing_from_object("valid_until_ts")),
+    };
+
+    if valid_until_ts < MilliSecondsSinceUnixEpoch::now() {
+        return Err(VerificationError::KeysExpired(valid_until_ts).into());
+    }
+
+    let verify_keys = match object.get("verify_keys") {
+        Some(CanonicalJsonValue::Object(keys)) => keys,
+        Some(_) => return Err(JsonError::not_of_type("verify_keys", JsonType::Object)),
+        None => return Err(JsonError::field_missing_from_object("verify_keys")),
+    };
+
+    let signature_map = match object.get("signatures") {
+        Some(CanonicalJsonValue::Object(signatures)) => signatures,
+        Some(_) => return Err(JsonError::not_of_type("signatures", JsonType::Object)),
+        None => return Err(JsonError::field_missing_from_object("signatures")),
+    };
+
+    let signature_set = match signature_map.get(server_name.as_str()) {
+        Some(CanonicalJsonValue::Object(set)) => set,
+        Some(_) => {
+            return Err(JsonError::not_multiples_of_type("signature sets", JsonType::Object))
+        }
+        None => return Err(VerificationError::signature_not_found(server_name.to_owned())),
+    };
+
+    let mut checked = false;
+    for (key_id, signature) in signature_set {
+        // Since only ed25519 is supported right now, we don't actually need to check what the
+        // algorithm is. If it split successfully, it's ed25519.
+        if split_id(key_id).is_err() {
+            continue;
+        }
+
+        let public_key = match verify_keys.get(key_id) {
+            Some(CanonicalJsonValue::Object(verify_key)) => match verify_key.get("key") {
+                Some(CanonicalJsonValue::String(key)) => key,
+                Some(_) => return Err(JsonError::not_of_type("key", JsonType::String)),
+                None => return Err(JsonError::field_missing_from_object("key")),
+            },
+            Some(_) => {
+                return Err(JsonError::not_multiples_of_type("verify_keys", JsonType::Object))
+            }
+            None => return Err(VerificationError::UnknownPublicKeysForSignature.into()),
+        };
+
+        let public_key = Base64::<Standard>::parse(public_key)
+            .map_err(|e| ParseError::base64("public key", public_key, e))?;
+
+        let signature = match signature {
+            CanonicalJsonValue::String(signature) => signature,
+            _ => return Err(JsonError::not_of_type("signature", JsonType::String)),
+        };
+
+        let signature = Base64::<Standard>::parse(signature)
+            .map_err(|e| ParseError::base64("signature", signature, e))?;
+
+        verify_json_with(&Ed25519Verifier, public_key.as_bytes(), signature.as_bytes(), object)?;
+        checked = true;
+    }
+
+    if !checked {
+        return Err(VerificationError::UnknownPublicKeysForSignature.into());
+    }
+
+    Ok(())
+}
+
 /// Internal implementation detail of the canonical JSON algorithm.
 ///
 /// Allows customization of the fields that will be removed before serializing.
@@ -727,14 +876,16 @@ mod tests {
 
     use assert_matches2::assert_matches;
     use ruma_common::{
-        serde::Base64, CanonicalJsonValue, RoomVersionId, ServerSigningKeyId, SigningKeyAlgorithm,
+        serde::{base64::Standard, Base64},
+        server_name, CanonicalJsonObject, CanonicalJsonValue, RoomVersionId, ServerSigningKeyId,
+        SigningKeyAlgorithm,
     };
     use serde_json::json;
 
-    use super::canonical_json;
+    use super::{canonical_json, event_id, reference_hash};
     use crate::{
-        sign_json, verify_event, Ed25519KeyPair, Error, PublicKeyMap, PublicKeySet,
-        VerificationError, Verified,
+        sign_json, verify_event, verify_server_keys_response, Ed25519KeyPair, Error, PublicKeyMap,
+        PublicKeySet, VerificationError, Verified,
     };
 
     #[test]
@@ -1151,6 +1302,148 @@ mod tests {
         );
     }
 
+    #[test]
+    fn verify_server_keys_response_with_valid_keys() {
+        let response = serde_json::from_str(
+            r#"{
+                "old_verify_keys": {},
+                "server_name": "domain",
+                "signatures": {
+                    "domain": {
+                        "ed25519:1": "n733IOwoEgnujQ/cr6v4b13CPI11xnU/3yiEmTs8vDkw1JblvaDeqF7Euwh68aCPSCpPdQ4tHREBc6koEnj4Dw"
+                    }
+                },
+                "valid_until_ts": 4102444800000,
+                "verify_keys": {
+                    "ed25519:1": {
+                        "key": "3TPraTczVkDPTRaX4K+AfUuyx7Mzq1UafTXypnl0t2k"
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        verify_server_keys_response(server_name!("domain"), &response).unwrap();
+
+        assert_matches!(
+            verify_server_keys_response(server_name!("other.domain"), &response),
+            Err(Error::Verification(VerificationError::ServerNameMismatch { expected, found }))
+        );
+        assert_eq!(expected, "other.domain");
+        assert_eq!(found, "domain");
+    }
+
+    #[test]
+    fn verify_server_keys_response_with_expired_keys() {
+        let key_pair = generate_key_pair("1");
+        let mut response = serde_json::from_value(json!({
+            "old_verify_keys": {},
+            "server_name": "domain",
+            "valid_until_ts": 1_000_000,
+            "verify_keys": {
+                "ed25519:1": {
+                    "key": Base64::<Standard>::new(key_pair.public_key().to_vec()),
+                },
+            },
+        }))
+        .unwrap();
+        sign_json("domain", &key_pair, &mut response).unwrap();
+
+        assert_matches!(
+            verify_server_keys_response(server_name!("domain"), &response),
+            Err(Error::Verification(VerificationError::KeysExpired(valid_until_ts)))
+        );
+        assert_eq!(valid_until_ts.get(), 1_000_000_u32.into());
+    }
+
+    #[test]
+    fn verify_server_keys_response_with_invalid_signature() {
+        let key_pair = generate_key_pair("1");
+        let other_key_pair = generate_key_pair("1");
+        let mut response = serde_json::from_value(json!({
+            "old_verify_keys": {},
+            "server_name": "domain",
+            "valid_until_ts": 4_102_444_800_000_u64,
+            "verify_keys": {
+                "ed25519:1": {
+                    "key": Base64::<Standard>::new(key_pair.public_key().to_vec()),
+                },
+            },
+        }))
+        .unwrap();
+        sign_json("domain", &other_key_pair, &mut response).unwrap();
+
+        assert_matches!(
+            verify_server_keys_response(server_name!("domain"), &response),
+            Err(Error::Verification(VerificationError::Signature(_)))
+        );
+    }
+
+    fn pdu_object(extra: serde_json::Value) -> CanonicalJsonObject {
+        let mut pdu = json!({
+            "auth_events": [],
+            "content": {
+                "creator": "@alice:example.org",
+                "room_version": "10",
+            },
+            "depth": 1,
+            "hashes": {
+                "sha256": "ThisHashCoversAllFieldsInCaseThisIsRedacted",
+            },
+            "origin": "example.org",
+            "origin_server_ts": 1_000_000,
+            "prev_events": [],
+            "room_id": "!room:example.org",
+            "sender": "@alice:example.org",
+            "signatures": {},
+            "state_key": "",
+            "type": "m.room.create",
+            "unsigned": {
+                "age": 4612,
+            },
+        });
+        pdu.as_object_mut().unwrap().extend(extra.as_object().unwrap().clone());
+
+        assert_matches!(CanonicalJsonValue::try_from(pdu), Ok(CanonicalJsonValue::Object(object)));
+        object
+    }
+
+    #[test]
+    fn event_id_from_field() {
+        let object = pdu_object(json!({ "event_id": "$event:example.org" }));
+        let v1_event_id = event_id(&object, &RoomVersionId::V1).unwrap();
+        assert_eq!(v1_event_id, "$event:example.org");
+
+        let object = pdu_object(json!({}));
+        assert_matches!(event_id(&object, &RoomVersionId::V2), Err(Error::Json(_)));
+    }
+
+    #[test]
+    fn event_id_from_reference_hash() {
+        let object = pdu_object(json!({}));
+
+        let v3_event_id = event_id(&object, &RoomVersionId::V3).unwrap();
+        assert_eq!(
+            v3_event_id,
+            format!("${}", reference_hash(&object, &RoomVersionId::V3).unwrap())
+        );
+        assert_eq!(v3_event_id.as_str().len(), 44);
+        assert_eq!(v3_event_id.server_name(), None);
+
+        // Room version 4 uses the URL-safe alphabet, with the same redaction algorithm.
+        let v4_event_id = event_id(&object, &RoomVersionId::V4).unwrap();
+        assert_eq!(v4_event_id, v3_event_id.as_str().replace('+', "-").replace('/', "_"));
+
+        // Fields removed by the redaction algorithm don't change the event ID.
+        let v10_event_id = event_id(&object, &RoomVersionId::V10).unwrap();
+        let object = pdu_object(json!({ "unsigned": { "age": 1 }, "not_a_pdu_field": true }));
+        assert_eq!(event_id(&object, &RoomVersionId::V10).unwrap(), v10_event_id);
+
+        // But other fields do.
+        let object = pdu_object(json!({ "depth": 2 }));
+        assert_ne!(event_id(&object, &RoomVersionId::V10).unwrap(), v10_event_id);
+    }
+
     fn generate_key_pair(name: &str) -> Ed25519KeyPair {
         let key_content = Ed25519KeyPair::generate().unwrap();
         Ed25519KeyPair::from_der(&key_content, name.to_owned())
@@ -0,0 +1,69 @@
+use ruma_events::StateEventType;
+use serde::Serialize;
+
+/// Information about how the state was resolved by [`resolve_with_diagnostics`].
+///
+/// [`resolve_with_diagnostics`]: crate::resolve_with_diagnostics
+#[derive(Clone, Debug, Serialize)]
+#[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+pub struct StateResolutionDiagnostics<Id> {
+    /// The state keys that were in conflict, sorted by event type and state key.
+    pub conflicts: Vec<ConflictDiagnostics<Id>>,
+
+    /// The events of the full conflicted set that failed the authorization checks.
+    ///
+    /// They are in the order in which they were checked.
+    pub rejected: Vec<Id>,
+}
+
+impl<Id> Default for StateResolutionDiagnostics<Id> {
+    fn default() -> Self {
+        Self { conflicts: Vec::new(), rejected: Vec::new() }
+    }
+}
+
+/// Information about how a single conflicted state key was resolved.
+#[derive(Clone, Debug, Serialize)]
+#[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+pub struct ConflictDiagnostics<Id> {
+    /// The event type of the conflicted state.
+    pub event_type: StateEventType,
+
+    /// The state key of the conflicted state.
+    pub state_key: String,
+
+    /// The events that the state sets disagreed on for this key, in the order of the state sets.
+    pub candidates: Vec<Id>,
+
+    /// The event that ended up in the resolved state, if any.
+    ///
+    /// This is not necessarily one of the `candidates`, since events from the auth chain
+    /// difference are also part of the full conflicted set.
+    pub winner: Option<Id>,
+
+    /// The step of the algorithm that determined the `winner`.
+    pub reason: ResolutionReason,
+}
+
+/// The step of the state resolution algorithm that resolved a conflicted state key.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+#[serde(rename_all = "snake_case")]
+pub enum ResolutionReason {
+    /// The winner is a power event.
+    ///
+    /// Power events are ordered by their sender's power level, then by their origin server
+    /// timestamp and finally by their event ID, and the last one to pass the authorization checks
+    /// wins.
+    PowerEvents,
+
+    /// The winner is not a power event.
+    ///
+    /// The remaining events are ordered by their position relative to the mainline of the resolved
+    /// power levels, then by their origin server timestamp and finally by their event ID, and the
+    /// last one to pass the authorization checks wins.
+    Mainline,
+
+    /// None of the events for this key passed the authorization checks.
+    NotAuthorized,
+}
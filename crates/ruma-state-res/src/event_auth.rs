@@ -3,7 +3,7 @@ use std::{borrow::Borrow, collections::BTreeSet};
 use js_int::{int, Int};
 use ruma_common::{
     serde::{Base64, Raw},
-    OwnedUserId, RoomVersionId, UserId,
+    EventId, OwnedUserId, RoomVersionId, UserId,
 };
 use ruma_events::room::{
     create::RoomCreateEventContent,
@@ -25,7 +25,7 @@ use crate::{
         deserialize_power_levels_content_invite, deserialize_power_levels_content_redact,
     },
     room_version::RoomVersion,
-    Error, Event, Result, StateEventType, TimelineEventType,
+    Error, Event, EventTypeExt, Result, StateEventType, StateMap, TimelineEventType,
 };
 
 // FIXME: field extracting could be bundled for `content`
@@ -438,6 +438,76 @@ pub fn auth_check<E: Event>(
     Ok(true)
 }
 
+/// Whether the given event should be [soft failed].
+///
+/// An event is soft failed when it passes the authorization rules based on the state before the
+/// event, as given by its `auth_events`, but fails them based on the `current_state` of the room.
+/// Such an event is still accepted in the DAG, but should not be sent to clients nor be referenced
+/// by new events.
+///
+/// This returns `false` if the event doesn't pass the authorization rules based on its
+/// `auth_events`, because it should then be rejected rather than soft failed.
+///
+/// ## Arguments
+///
+/// * `current_state` - The event IDs of the current state of the room.
+///
+/// * `fetch_event` - Returns the event with the given ID, used to get the `auth_events` of
+///   `incoming_event` and the events of `current_state`.
+///
+/// [soft failed]: https://spec.matrix.org/latest/server-server-api/#soft-failure
+pub fn is_soft_failed<E: Event>(
+    room_version: &RoomVersion,
+    incoming_event: &E,
+    current_state: &StateMap<E::Id>,
+    fetch_event: impl Fn(&EventId) -> Option<E>,
+) -> Result<bool> {
+    let mut auth_events = StateMap::new();
+    for auth_event_id in incoming_event.auth_events() {
+        if let Some(event) = fetch_event(auth_event_id.borrow()) {
+            let state_key = event
+                .state_key()
+                .ok_or_else(|| Error::InvalidPdu("State event had no state key".to_owned()))?;
+            auth_events.insert(event.event_type().with_state_key(state_key), event);
+        } else {
+            warn!("auth event {auth_event_id} is missing for {}", incoming_event.event_id());
+        }
+    }
+
+    if !auth_check_with_state(room_version, incoming_event, &auth_events)? {
+        debug!("event {} fails the auth check for its auth events", incoming_event.event_id());
+        return Ok(false);
+    }
+
+    let mut state_events = StateMap::new();
+    for key in auth_types_for_event(
+        incoming_event.event_type(),
+        incoming_event.sender(),
+        incoming_event.state_key(),
+        incoming_event.content(),
+    )? {
+        if let Some(event) = current_state.get(&key).and_then(|id| fetch_event(id.borrow())) {
+            state_events.insert(key, event);
+        }
+    }
+
+    Ok(!auth_check_with_state(room_version, incoming_event, &state_events)?)
+}
+
+/// Run the `auth_check` of `incoming_event` against the given state events.
+fn auth_check_with_state<E: Event>(
+    room_version: &RoomVersion,
+    incoming_event: &E,
+    state: &StateMap<E>,
+) -> Result<bool> {
+    let current_third_party_invite =
+        state.values().find(|event| *event.event_type() == TimelineEventType::RoomThirdPartyInvite);
+
+    auth_check(room_version, incoming_event, current_third_party_invite, |ty, key| {
+        state.get(&ty.with_state_key(key))
+    })
+}
+
 // TODO deserializing the member, power, join_rules event contents is done in conduit
 // just before this is called. Could they be passed in?
 /// Does the user who sent this member event have required power levels to do so.
@@ -1010,6 +1080,7 @@ fn verify_third_party_invite(
 mod tests {
     use std::sync::Arc;
 
+    use ruma_common::EventId;
     use ruma_events::{
         room::{
             join_rules::{
@@ -1019,12 +1090,12 @@ mod tests {
         },
         StateEventType, TimelineEventType,
     };
-    use serde_json::value::to_raw_value as to_raw_json_value;
+    use serde_json::{json, value::to_raw_value as to_raw_json_value};
 
     use crate::{
-        event_auth::valid_membership_change,
+        event_auth::{is_soft_failed, valid_membership_change},
         test_utils::{
-            alice, charlie, ella, event_id, member_content_ban, member_content_join, room_id,
+            alice, bob, charlie, ella, event_id, member_content_ban, member_content_join, room_id,
             to_pdu_event, PduEvent, INITIAL_EVENTS, INITIAL_EVENTS_CREATE_ROOM,
         },
         Event, EventTypeExt, RoomVersion, StateMap,
@@ -1323,4 +1394,76 @@ mod tests {
         )
         .unwrap());
     }
+
+    #[test]
+    fn soft_fail_after_power_levels_change() {
+        let _ =
+            tracing::subscriber::set_default(tracing_subscriber::fmt().with_test_writer().finish());
+        let mut events = INITIAL_EVENTS();
+
+        // Alice gives Bob enough power to change the topic, then takes it back.
+        for event in [
+            to_pdu_event(
+                "PA",
+                alice(),
+                TimelineEventType::RoomPowerLevels,
+                Some(""),
+                to_raw_json_value(&json!({ "users": { alice(): 100, bob(): 50 } })).unwrap(),
+                &["CREATE", "IMA", "IPOWER"],
+                &["IMC"],
+            ),
+            to_pdu_event(
+                "PB",
+                alice(),
+                TimelineEventType::RoomPowerLevels,
+                Some(""),
+                to_raw_json_value(&json!({ "users": { alice(): 100 } })).unwrap(),
+                &["CREATE", "IMA", "PA"],
+                &["PA"],
+            ),
+        ] {
+            events.insert(event.event_id().to_owned(), event);
+        }
+
+        let topic = |id: &str, auth_events: &[&str]| {
+            to_pdu_event(
+                id,
+                bob(),
+                TimelineEventType::RoomTopic,
+                Some(""),
+                to_raw_json_value(&json!({ "topic": "Bob's room" })).unwrap(),
+                auth_events,
+                &["PA"],
+            )
+        };
+        let state_with = |ids: &[&str]| {
+            ids.iter()
+                .map(|id| {
+                    let ev = &events[&event_id(id)];
+                    (ev.event_type().with_state_key(ev.state_key().unwrap()), event_id(id))
+                })
+                .collect::<StateMap<_>>()
+        };
+        let fetch_event = |id: &EventId| events.get(id).cloned();
+
+        let topic_event = topic("TOPIC", &["CREATE", "IMB", "PA"]);
+
+        // Bob still has enough power in the current state.
+        let current_state = state_with(&["CREATE", "IMA", "PA", "IJR", "IMB", "IMC"]);
+        assert!(
+            !is_soft_failed(&RoomVersion::V6, &topic_event, &current_state, fetch_event).unwrap()
+        );
+
+        // Bob's power was removed in the current state.
+        let current_state = state_with(&["CREATE", "IMA", "PB", "IJR", "IMB", "IMC"]);
+        assert!(
+            is_soft_failed(&RoomVersion::V6, &topic_event, &current_state, fetch_event).unwrap()
+        );
+
+        // Bob never had enough power according to the auth events, the event is rejected instead.
+        let topic_event = topic("TOPIC2", &["CREATE", "IMB", "IPOWER"]);
+        assert!(
+            !is_soft_failed(&RoomVersion::V6, &topic_event, &current_state, fetch_event).unwrap()
+        );
+    }
 }
@@ -1,13 +1,13 @@
 use std::{
     borrow::Borrow,
     cmp::Reverse,
-    collections::{BinaryHeap, HashMap, HashSet},
+    collections::{BTreeSet, BinaryHeap, HashMap, HashSet},
     hash::Hash,
 };
 
 use itertools::Itertools;
 use js_int::{int, Int};
-use ruma_common::{EventId, MilliSecondsSinceUnixEpoch, RoomVersionId};
+use ruma_common::{EventId, MilliSecondsSinceUnixEpoch, OwnedEventId, RoomVersionId};
 use ruma_events::{
     room::member::{MembershipState, RoomMemberEventContent},
     StateEventType, TimelineEventType,
@@ -15,6 +15,7 @@ use ruma_events::{
 use serde_json::from_str as from_json_str;
 use tracing::{debug, info, trace, warn};
 
+mod diagnostics;
 mod error;
 pub mod event_auth;
 mod power_levels;
@@ -23,8 +24,9 @@ mod state_event;
 #[cfg(test)]
 mod test_utils;
 
+pub use diagnostics::{ConflictDiagnostics, ResolutionReason, StateResolutionDiagnostics};
 pub use error::{Error, Result};
-pub use event_auth::{auth_check, auth_types_for_event};
+pub use event_auth::{auth_check, auth_types_for_event, is_soft_failed};
 use power_levels::PowerLevelsContentFields;
 pub use room_version::RoomVersion;
 pub use state_event::Event;
@@ -58,6 +60,50 @@ pub fn resolve<'a, E, SetIter>(
     auth_chain_sets: Vec<HashSet<E::Id>>,
     fetch_event: impl Fn(&EventId) -> Option<E>,
 ) -> Result<StateMap<E::Id>>
+where
+    E: Event + Clone,
+    E::Id: 'a,
+    SetIter: Iterator<Item = &'a StateMap<E::Id>> + Clone,
+{
+    resolve_inner(room_version, state_sets, auth_chain_sets, fetch_event, None)
+}
+
+/// Resolve sets of state events as they come in, and explain how the conflicts were resolved.
+///
+/// This is the same as [`resolve`], but also returns [`StateResolutionDiagnostics`] about the
+/// conflicted state keys and the events that were rejected.
+#[allow(clippy::type_complexity)]
+pub fn resolve_with_diagnostics<'a, E, SetIter>(
+    room_version: &RoomVersionId,
+    state_sets: impl IntoIterator<IntoIter = SetIter>,
+    auth_chain_sets: Vec<HashSet<E::Id>>,
+    fetch_event: impl Fn(&EventId) -> Option<E>,
+) -> Result<(StateMap<E::Id>, StateResolutionDiagnostics<E::Id>)>
+where
+    E: Event + Clone,
+    E::Id: 'a,
+    SetIter: Iterator<Item = &'a StateMap<E::Id>> + Clone,
+{
+    let mut diagnostics = StateResolutionDiagnostics::default();
+    let state = resolve_inner(
+        room_version,
+        state_sets,
+        auth_chain_sets,
+        fetch_event,
+        Some(&mut diagnostics),
+    )?;
+
+    Ok((state, diagnostics))
+}
+
+/// Resolve sets of state events, filling the `diagnostics` if they are provided.
+fn resolve_inner<'a, E, SetIter>(
+    room_version: &RoomVersionId,
+    state_sets: impl IntoIterator<IntoIter = SetIter>,
+    auth_chain_sets: Vec<HashSet<E::Id>>,
+    fetch_event: impl Fn(&EventId) -> Option<E>,
+    mut diagnostics: Option<&mut StateResolutionDiagnostics<E::Id>>,
+) -> Result<StateMap<E::Id>>
 where
     E: Event + Clone,
     E::Id: 'a,
@@ -79,6 +125,16 @@ where
     info!("conflicting events: {}", conflicting.len());
     debug!("{conflicting:?}");
 
+    // Only needed for the diagnostics, since `conflicting` is consumed below.
+    let conflicted_keys = diagnostics.is_some().then(|| {
+        let mut conflicted_keys = conflicting
+            .iter()
+            .map(|(key, candidates)| (key.clone(), candidates.clone()))
+            .collect::<Vec<_>>();
+        conflicted_keys.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+        conflicted_keys
+    });
+
     // `all_conflicted` contains unique items
     // synapse says `full_set = {eid for eid in full_conflicted_set if eid in event_map}`
     let all_conflicted: HashSet<_> = get_auth_chain_diff(auth_chain_sets)
@@ -109,8 +165,13 @@ where
 
     let room_version = RoomVersion::new(room_version)?;
     // Sequentially auth check each control event.
-    let resolved_control =
-        iterative_auth_check(&room_version, &sorted_control_levels, clean.clone(), &fetch_event)?;
+    let resolved_control = iterative_auth_check(
+        &room_version,
+        &sorted_control_levels,
+        clean.clone(),
+        diagnostics.as_deref_mut().map(|diagnostics| &mut diagnostics.rejected),
+        &fetch_event,
+    )?;
 
     debug!("resolved control events: {}", resolved_control.len());
     trace!("{resolved_control:?}");
@@ -143,12 +204,35 @@ where
         &room_version,
         &sorted_left_events,
         resolved_control, // The control events are added to the final resolved state
+        diagnostics.as_deref_mut().map(|diagnostics| &mut diagnostics.rejected),
         &fetch_event,
     )?;
 
     // Add unconflicted state to the resolved state
     // We priorities the unconflicting state
     resolved_state.extend(clean);
+
+    if let Some((diagnostics, conflicted_keys)) = diagnostics.zip(conflicted_keys) {
+        diagnostics.conflicts = conflicted_keys
+            .into_iter()
+            .map(|(key, candidates)| {
+                let winner = resolved_state.get(&key).cloned();
+                // Conflicted keys are not in the unconflicted state, so the winner was either
+                // checked with the control events or with the remaining events.
+                let reason = match &winner {
+                    None => ResolutionReason::NotAuthorized,
+                    Some(id) if deduped_power_ev.contains(id.borrow()) => {
+                        ResolutionReason::PowerEvents
+                    }
+                    Some(_) => ResolutionReason::Mainline,
+                };
+                let (event_type, state_key) = key;
+
+                ConflictDiagnostics { event_type, state_key, candidates, winner, reason }
+            })
+            .collect();
+    }
+
     Ok(resolved_state)
 }
 
@@ -202,6 +286,57 @@ where
     id_counts.into_iter().filter_map(move |(id, count)| (count < num_sets).then_some(id))
 }
 
+/// Computes the auth chain difference between two sets of events.
+///
+/// The full auth chain of each set is the union of the full auth chains of its events, i.e. their
+/// `auth_events`, the `auth_events` of those and so on recursively. The returned set contains the
+/// events that appear in one of the two full auth chains but not in the other.
+///
+/// ## Arguments
+///
+/// * `a`, `b` - The two sets of events to compare, usually the event IDs of two state sets.
+///
+/// * `auth_events` - Returns the `auth_events` of the given event. It is called at most once for
+///   every event in both auth chains.
+pub fn auth_chain_difference(
+    a: &BTreeSet<OwnedEventId>,
+    b: &BTreeSet<OwnedEventId>,
+    auth_events: impl Fn(&EventId) -> BTreeSet<OwnedEventId>,
+) -> BTreeSet<OwnedEventId> {
+    let mut cache = HashMap::new();
+    let auth_chain_sets = [a, b]
+        .into_iter()
+        .map(|events| full_auth_chain(events, &mut cache, &auth_events))
+        .collect();
+
+    get_auth_chain_diff(auth_chain_sets).collect()
+}
+
+/// Returns the full auth chain of the given events, not including the events themselves.
+///
+/// The `auth_events` of every visited event are stored in `cache`, so they can be reused for other
+/// chains.
+fn full_auth_chain(
+    events: &BTreeSet<OwnedEventId>,
+    cache: &mut HashMap<OwnedEventId, BTreeSet<OwnedEventId>>,
+    auth_events: &impl Fn(&EventId) -> BTreeSet<OwnedEventId>,
+) -> HashSet<OwnedEventId> {
+    let mut chain = HashSet::new();
+    let mut stack = events.iter().cloned().collect::<Vec<_>>();
+
+    while let Some(event_id) = stack.pop() {
+        let parents = cache.entry(event_id).or_insert_with_key(|id| auth_events(id));
+
+        for parent in parents.iter() {
+            if chain.insert(parent.clone()) {
+                stack.push(parent.clone());
+            }
+        }
+    }
+
+    chain
+}
+
 /// Events are sorted from "earliest" to "latest".
 ///
 /// They are compared using the negative power level (reverse topological ordering), the origin
@@ -377,7 +512,8 @@ fn get_power_level_for_sender<E: Event>(
 /// ## Returns
 ///
 /// The `unconflicted_state` combined with the newly auth'ed events. So any event that fails the
-/// `event_auth::auth_check` will be excluded from the returned state map.
+/// `event_auth::auth_check` will be excluded from the returned state map, and added to `rejected`
+/// if it is provided.
 ///
 /// For each `events_to_check` event we gather the events needed to auth it from the the
 /// `fetch_event` closure and verify each event using the `event_auth::auth_check` function.
@@ -385,6 +521,7 @@ fn iterative_auth_check<E: Event + Clone>(
     room_version: &RoomVersion,
     events_to_check: &[E::Id],
     unconflicted_state: StateMap<E::Id>,
+    mut rejected: Option<&mut Vec<E::Id>>,
     fetch_event: impl Fn(&EventId) -> Option<E>,
 ) -> Result<StateMap<E::Id>> {
     info!("starting iterative auth check");
@@ -446,6 +583,9 @@ fn iterative_auth_check<E: Event + Clone>(
         } else {
             // synapse passes here on AuthError. We do not add this event to resolved_state.
             warn!("event {event_id} failed the authentication check");
+            if let Some(rejected) = rejected.as_deref_mut() {
+                rejected.push(event_id.clone());
+            }
         }
 
         // TODO: if these functions are ever made async here
@@ -637,14 +777,15 @@ where
 #[cfg(test)]
 mod tests {
     use std::{
-        collections::{HashMap, HashSet},
+        cell::Cell,
+        collections::{BTreeSet, HashMap, HashSet},
         sync::Arc,
     };
 
     use js_int::{int, uint};
-    use maplit::{hashmap, hashset};
+    use maplit::{btreeset, hashmap, hashset};
     use rand::seq::SliceRandom;
-    use ruma_common::{MilliSecondsSinceUnixEpoch, OwnedEventId, RoomVersionId};
+    use ruma_common::{EventId, MilliSecondsSinceUnixEpoch, OwnedEventId, RoomVersionId};
     use ruma_events::{
         room::join_rules::{JoinRule, RoomJoinRulesEventContent},
         StateEventType, TimelineEventType,
@@ -659,7 +800,7 @@ mod tests {
             alice, bob, charlie, do_check, ella, event_id, member_content_ban, member_content_join,
             room_id, to_init_pdu_event, to_pdu_event, zara, PduEvent, TestStore, INITIAL_EVENTS,
         },
-        Event, EventTypeExt, StateMap,
+        Event, EventTypeExt, ResolutionReason, StateMap,
     };
 
     fn test_event_sort() {
@@ -690,6 +831,7 @@ mod tests {
             &RoomVersion::V6,
             &sorted_power_events,
             HashMap::new(), // unconflicted events
+            None,
             |id| events.get(id).cloned(),
         )
         .expect("iterative auth check failed on resolved events");
@@ -1180,6 +1322,72 @@ mod tests {
         assert_eq!(expected.len(), resolved.len());
     }
 
+    #[test]
+    fn ban_with_diagnostics() {
+        let init = INITIAL_EVENTS();
+        let ban = BAN_STATE_SET();
+
+        let mut inner = init.clone();
+        inner.extend(ban);
+        let store = TestStore(inner.clone());
+
+        let state_set = |ids: &[&str]| {
+            ids.iter()
+                .map(|id| {
+                    let ev = inner.get(&event_id(id)).unwrap();
+                    (ev.event_type().with_state_key(ev.state_key().unwrap()), ev.event_id.clone())
+                })
+                .collect::<StateMap<_>>()
+        };
+        let state_set_a = state_set(&["CREATE", "IJR", "IMA", "IMB", "IMC", "MB", "PA"]);
+        let state_set_b = state_set(&["CREATE", "IJR", "IMA", "IMB", "IMC", "IME", "PA"]);
+
+        let ev_map = &store.0;
+        let state_sets = [state_set_a, state_set_b];
+        let (resolved, diagnostics) = crate::resolve_with_diagnostics(
+            &RoomVersionId::V6,
+            &state_sets,
+            state_sets
+                .iter()
+                .map(|map| {
+                    store.auth_event_ids(room_id(), map.values().cloned().collect()).unwrap()
+                })
+                .collect(),
+            |id| ev_map.get(id).cloned(),
+        )
+        .unwrap();
+
+        let ella_key = (StateEventType::RoomMember, ella().to_string());
+        assert_eq!(resolved.get(&ella_key), Some(&event_id("MB")));
+
+        // Only Ella's membership is in conflict, the ban wins as a power event.
+        assert_eq!(diagnostics.conflicts.len(), 1);
+        let conflict = &diagnostics.conflicts[0];
+        assert_eq!((&conflict.event_type, &conflict.state_key), (&ella_key.0, &ella_key.1));
+        assert_eq!(conflict.candidates, vec![event_id("MB"), event_id("IME")]);
+        assert_eq!(conflict.winner, Some(event_id("MB")));
+        assert_eq!(conflict.reason, ResolutionReason::PowerEvents);
+
+        // Ella's join is not allowed after the ban.
+        assert_eq!(diagnostics.rejected, vec![event_id("IME")]);
+
+        assert_eq!(
+            serde_json::to_value(&diagnostics).unwrap(),
+            json!({
+                "conflicts": [
+                    {
+                        "event_type": "m.room.member",
+                        "state_key": ella(),
+                        "candidates": ["$MB:foo", "$IME:foo"],
+                        "winner": "$MB:foo",
+                        "reason": "power_events",
+                    },
+                ],
+                "rejected": ["$IME:foo"],
+            })
+        );
+    }
+
     #[test]
     fn join_rule_with_auth_chain() {
         let join_rule = JOIN_RULE();
@@ -1194,6 +1402,49 @@ mod tests {
         do_check(&join_rule.values().cloned().collect::<Vec<_>>(), edges, expected_state_ids);
     }
 
+    #[test]
+    fn auth_chain_difference() {
+        let create = event_id("CREATE");
+        let ima = event_id("IMA");
+        let ipower = event_id("IPOWER");
+        let ijr = event_id("IJR");
+        let imb = event_id("IMB");
+        let pa = event_id("PA");
+
+        // CREATE <- IMA <- IPOWER <- IJR, and two branches on top of IJR: Bob joining and Alice
+        // changing the power levels.
+        let auth_events_map: HashMap<OwnedEventId, BTreeSet<OwnedEventId>> = hashmap! {
+            create.clone() => btreeset![],
+            ima.clone() => btreeset![create.clone()],
+            ipower.clone() => btreeset![create.clone(), ima.clone()],
+            ijr.clone() => btreeset![create.clone(), ima.clone(), ipower.clone()],
+            imb.clone() => btreeset![create.clone(), ijr.clone(), ipower.clone()],
+            pa.clone() => btreeset![create.clone(), ima.clone(), ipower.clone()],
+        };
+
+        let calls = Cell::new(0);
+        let auth_events = |id: &EventId| {
+            calls.set(calls.get() + 1);
+            auth_events_map[id].clone()
+        };
+
+        let state_a = btreeset![create.clone(), ima.clone(), ijr.clone(), imb.clone()];
+        let state_b = btreeset![create.clone(), ima.clone(), ijr.clone(), pa.clone()];
+
+        // The chain of A contains IJR through IMB, the one of B doesn't.
+        let difference = crate::auth_chain_difference(&state_a, &state_b, auth_events);
+        assert_eq!(difference, btreeset![ijr]);
+
+        // Each event's auth events are only fetched once.
+        assert_eq!(calls.get(), 6);
+
+        // Identical sets have no difference.
+        let difference = crate::auth_chain_difference(&state_a, &state_a, |id: &EventId| {
+            auth_events_map[id].clone()
+        });
+        assert!(difference.is_empty());
+    }
+
     #[allow(non_snake_case)]
     fn BAN_STATE_SET() -> HashMap<OwnedEventId, Arc<PduEvent>> {
         vec![